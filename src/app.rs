@@ -1,7 +1,17 @@
+use crate::config::settings::Settings;
 use crate::events::actions::{Action, Effect};
-use crate::gitlab::{Job, JobStatus, MergeRequest, Note, Pipeline};
-use std::collections::HashMap;
-use std::time::Instant;
+use crate::events::keymap::Keymap;
+use crate::gitlab::{
+    Approvals, DiffFile, Job, JobStatus, MergeRequest, MrStateFilter, Note, Pipeline, PipelineStatus,
+};
+use crate::hooks::{HookEvent, PipelineEvent};
+use crate::log_cache::LruLogCache;
+use crate::state::TrackedState;
+use crate::ui::layout::ClickAreas;
+use crate::ui::theme::Theme;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TimestampDisplayMode {
@@ -10,20 +20,308 @@ pub enum TimestampDisplayMode {
     Full,        // Show full timestamp (e.g., "2024-01-15 10:30:45")
 }
 
+impl TimestampDisplayMode {
+    /// Look up a mode by its config name (`ui.default_timestamp_mode`); `Settings::validate`
+    /// rejects unknown names
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "hidden" => Some(Self::Hidden),
+            "date" => Some(Self::DateOnly),
+            "full" => Some(Self::Full),
+            _ => None,
+        }
+    }
+}
+
+/// Order of the MR tabs; `selected_mr_index` always indexes `tracked_mrs` itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MrSort {
+    #[default]
+    LoadOrder,      // Order of the last refreshed list, MRs added by IID last
+    UpdatedAt,      // Most recently updated (MR or latest pipeline) first
+    PipelineStatus, // Failed first, then running, pending, passed; no pipeline last
+}
+
+impl MrSort {
+    pub fn next(self) -> Self {
+        match self {
+            MrSort::LoadOrder => MrSort::UpdatedAt,
+            MrSort::UpdatedAt => MrSort::PipelineStatus,
+            MrSort::PipelineStatus => MrSort::LoadOrder,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MrSort::LoadOrder => "load order",
+            MrSort::UpdatedAt => "last update",
+            MrSort::PipelineStatus => "pipeline status",
+        }
+    }
+}
+
+/// Order of a pipeline's jobs in the jobs table, from `ui.job_sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JobSort {
+    #[default]
+    Status, // Failed first, then running, pending, ...; passed near the end
+    Stage,  // Stages in pipeline order (build → test → deploy), then by name
+    Name,
+}
+
+impl JobSort {
+    /// Look up an order by its config name; `Settings::validate` rejects unknown names
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "status" => Some(JobSort::Status),
+            "stage" => Some(JobSort::Stage),
+            "name" => Some(JobSort::Name),
+            _ => None,
+        }
+    }
+
+    /// Sort a pipeline's jobs. The sort is stable, so ties keep the server's order.
+    pub fn sort(self, jobs: &mut [Job]) {
+        match self {
+            JobSort::Status => jobs.sort_by_key(|job| job_status_rank(&job.status)),
+            JobSort::Stage => {
+                // Jobs are created stage by stage, so a stage's lowest job ID gives its
+                // place in the pipeline; retried jobs get higher IDs but keep their stage's
+                let mut stage_rank: HashMap<String, u64> = HashMap::new();
+                for job in jobs.iter() {
+                    let rank = stage_rank.entry(job.stage.clone()).or_insert(job.id);
+                    *rank = (*rank).min(job.id);
+                }
+                jobs.sort_by(|a, b| {
+                    (stage_rank[&a.stage], &a.name).cmp(&(stage_rank[&b.stage], &b.name))
+                });
+            }
+            JobSort::Name => jobs.sort_by(|a, b| a.name.cmp(&b.name)),
+        }
+    }
+}
+
+/// Rank of a job for `JobSort::Status`, lowest first
+fn job_status_rank(status: &JobStatus) -> u8 {
+    match status {
+        JobStatus::Failed => 0,
+        JobStatus::Running => 1,
+        JobStatus::Pending => 2,
+        JobStatus::Canceled => 3,
+        JobStatus::Created => 4,
+        JobStatus::Manual => 5,
+        JobStatus::Success => 6,
+        JobStatus::Skipped => 7,
+    }
+}
+
+/// Rank of an MR's latest pipeline for `MrSort::PipelineStatus`, lowest first
+fn pipeline_sort_rank(pipeline: Option<&Pipeline>) -> u8 {
+    match pipeline.map(|p| &p.status) {
+        Some(PipelineStatus::Failed) => 0,
+        Some(PipelineStatus::Running) => 1,
+        Some(
+            PipelineStatus::Pending
+            | PipelineStatus::Created
+            | PipelineStatus::Preparing
+            | PipelineStatus::WaitingForResource,
+        ) => 2,
+        Some(PipelineStatus::Manual) => 3,
+        Some(PipelineStatus::Success) => 4,
+        Some(PipelineStatus::Canceled | PipelineStatus::Skipped) => 5,
+        None => 6,
+    }
+}
+
+/// Jobs that GitLab reruns when retrying a pipeline.
+///
+/// The pipeline retry endpoint only retries failed and canceled jobs; successful,
+/// skipped and manual jobs are left alone.
+pub fn retry_preview(jobs: &[Job]) -> Vec<&Job> {
+    jobs.iter()
+        .filter(|job| matches!(job.status, JobStatus::Failed | JobStatus::Canceled))
+        .collect()
+}
+
+/// Columns the log moves per left/right scroll step
+const LOG_H_SCROLL_STEP: usize = 8;
+
+/// Status shown when `n` / `N` step past the last / first search result
+const SEARCH_WRAPPED_TO_TOP: &str = "Search wrapped to top";
+const SEARCH_WRAPPED_TO_BOTTOM: &str = "Search wrapped to bottom";
+
+/// Statuses the `F` job filter steps through, after showing all jobs
+const JOB_STATUS_FILTER_CYCLE: [JobStatus; 5] = [
+    JobStatus::Failed,
+    JobStatus::Running,
+    JobStatus::Pending,
+    JobStatus::Manual,
+    JobStatus::Success,
+];
+
+/// The job filter after `current`, wrapping back to no filter
+pub fn next_job_status_filter(current: Option<&JobStatus>) -> Option<JobStatus> {
+    match current {
+        None => Some(JOB_STATUS_FILTER_CYCLE[0].clone()),
+        Some(status) => JOB_STATUS_FILTER_CYCLE
+            .iter()
+            .position(|s| s == status)
+            .and_then(|i| JOB_STATUS_FILTER_CYCLE.get(i + 1))
+            .cloned(),
+    }
+}
+
+/// File name for a job's downloaded artifacts, e.g. `job-200-unit_tests.zip`
+pub fn artifacts_file_name(job_id: u64, job_name: &str) -> String {
+    let name: String = job_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    format!("job-{}-{}.zip", job_id, name)
+}
+
+/// A line matching the global search in the log of one MR's failed job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalSearchResult {
+    pub mr_index: usize, // Index into `tracked_mrs`
+    pub mr_iid: u64,
+    pub job_id: u64,
+    pub job_name: String,
+    pub line_number: usize, // Index of the line in the log
+    pub line: String,       // The matching line as plain text
+}
+
+/// Failed jobs of each MR's latest pipeline, with the index of the MR they belong to
+fn latest_failed_jobs(mrs: &[TrackedMergeRequest]) -> impl Iterator<Item = (usize, &Job)> {
+    mrs.iter().enumerate().flat_map(|(mr_index, tmr)| {
+        tmr.pipelines
+            .first()
+            .and_then(|pipeline| tmr.jobs.get(&pipeline.id))
+            .into_iter()
+            .flatten()
+            .filter(|job| job.status == JobStatus::Failed)
+            .map(move |job| (mr_index, job))
+    })
+}
+
+/// Matches of `query` in the cached logs of every MR's latest failed jobs, in tab
+/// then job order; jobs whose log isn't cached yet are skipped
+pub fn global_search_results(
+    mrs: &[TrackedMergeRequest],
+    query: &str,
+    case_sensitive: bool,
+) -> Vec<GlobalSearchResult> {
+    let mut results = Vec::new();
+    for (mr_index, job) in latest_failed_jobs(mrs) {
+        let Some(log) = mrs[mr_index].job_logs_cache.peek(job.id) else {
+            continue;
+        };
+        // Match the text as the log viewer shows it, so the log search finds the same lines
+        let lines = crate::log_processor::process_log_content(log, &TimestampDisplayMode::Hidden);
+        for line_number in crate::log_processor::find_matching_display_lines(&lines, query, case_sensitive) {
+            results.push(GlobalSearchResult {
+                mr_index,
+                mr_iid: mrs[mr_index].mr.iid,
+                job_id: job.id,
+                job_name: job.name.clone(),
+                line_number,
+                line: lines[line_number].to_string().trim().to_string(),
+            });
+        }
+    }
+    results
+}
+
+/// A row of the jobs table
+#[derive(Debug, Clone)]
+pub enum JobRow<'a> {
+    Job(&'a Job),
+    Passed { stage: &'a str, count: usize }, // Collapsed successful jobs of one stage
+}
+
+/// Time left until the next auto-refresh; zero once it's due
+pub fn time_until_refresh(last: Instant, interval: Duration) -> Duration {
+    interval.saturating_sub(last.elapsed())
+}
+
+/// Notes added since the previous load: IDs above the highest one seen before.
+///
+/// On the first load (`previous_max_id` is `None`) nothing counts as new.
+pub fn detect_new_notes(previous_max_id: Option<u64>, notes: &[Note]) -> HashSet<u64> {
+    match previous_max_id {
+        Some(max_id) => notes
+            .iter()
+            .filter(|note| note.id > max_id)
+            .map(|note| note.id)
+            .collect(),
+        None => HashSet::new(),
+    }
+}
+
+/// Log lines shown per failed job in the failure summary
+pub const FAILURE_SUMMARY_TAIL_LINES: usize = 15;
+
+/// The last `count` lines of a trace that have visible text once prefixes,
+/// timestamps and ANSI codes are stripped, oldest first
+pub fn tail_non_empty_lines(trace: &str, count: usize) -> Vec<&str> {
+    let mut tail: Vec<&str> = trace
+        .lines()
+        .rev()
+        .filter(|line| !crate::log_processor::plain_log_line(line).is_empty())
+        .take(count)
+        .collect();
+    tail.reverse();
+    tail
+}
+
+/// Whether the note at `index` answers the one before it in the same thread
+pub fn is_thread_reply(notes: &[&Note], index: usize) -> bool {
+    index > 0
+        && notes[index].discussion_id.is_some()
+        && notes[index].discussion_id == notes[index - 1].discussion_id
+}
+
+/// Indices of the notes that start a thread; standalone comments are threads of one
+fn thread_starts(notes: &[&Note]) -> Vec<usize> {
+    (0..notes.len()).filter(|&index| !is_thread_reply(notes, index)).collect()
+}
+
 pub struct App {
     // UI State
     pub should_quit: bool,
     pub selected_mr_index: usize,
-    pub selected_job_index: usize,
+    pub selected_job_index: usize, // Index into the visible (filtered) jobs
+    pub job_status_filter: Option<JobStatus>, // Only list jobs with this status
+    pub collapse_passed_jobs: bool, // Hide successful jobs behind one "+N passed" row per stage
 
     // Data State
     pub tracked_mrs: Vec<TrackedMergeRequest>,
+    pub mr_sort: MrSort, // How the tabs are ordered; see `mr_display_order`
+    pub mr_state: MrStateFilter, // State of the MRs a refresh lists, cycled with `M`
+    pub job_sort: JobSort, // How each pipeline's jobs are ordered when they load
     pub project_id: u64,
+    pub group_id: Option<u64>, // Query MRs across a whole group instead of one project
     pub current_branch: Option<String>,
     pub focus_current_branch: bool,
+    pub default_branch: Option<String>, // The project's; on it, branch focus lists all MRs
+    pub branch_from_cli: bool, // `--branch` was given, so it's focused even if it's the default branch
 
     // UI Modes
     pub mode: AppMode,
+    pub mode_before_quit_confirm: AppMode, // Restored when the quit prompt is dismissed
+    pub in_flight_fetches: usize, // API requests dispatched whose response hasn't arrived yet
+    pub refresh_in_flight: bool, // A RefreshAll is waiting for its MR list; further refreshes are dropped
+    pub refresh_stale: bool, // That RefreshAll went out before a config reload changed the connection
+    pub compact_comments: bool, // Collapse all but the selected comment
+    pub show_system_notes: bool, // Include system notes (label changes, approvals, ...) in comments
+    pub description_scroll: u16, // Scroll offset (in lines) of the MR description view
+    pub highlight_authors: Vec<String>, // Usernames whose notes/MRs are highlighted
+    pub relative_timestamps: bool, // "5m ago" in the pipeline and comment lists, else local date and time
+    pub label_filter: Vec<String>, // `--label`s; the lists already filter on them, MRs fetched by IID don't
+    pub user_filter: bool, // The lists only hold MRs assigned to or reviewed by someone
+    pub artifacts_dir: PathBuf, // Where `DownloadArtifacts` saves job artifacts
+    pub log_cache_max_entries: usize, // Limits of each tracked MR's job log cache
+    pub log_cache_max_bytes: usize,
 
     // Log Viewer State
     pub log_content: Option<String>,
@@ -31,83 +329,401 @@ pub struct App {
     pub log_scroll_offset: usize,
     pub log_viewport_height: usize, // Height of visible log area (set by renderer)
     pub log_job_name: Option<String>,
+    pub log_job_id: Option<u64>, // Job of the open log, whose position is saved on close
     pub timestamp_mode: TimestampDisplayMode,
+    pub raw_log: bool, // Show log lines unprocessed apart from ANSI colors (kept across logs)
+    pub log_wrap: bool, // Wrap long log lines; when off the log scrolls horizontally instead
+    pub log_h_scroll: usize, // Columns scrolled to the right while wrapping is off
     pub search_query: String,
     pub search_results: Vec<usize>, // Line numbers where matches are found
     pub current_search_result: usize, // Index into search_results
     pub is_searching: bool, // Whether in search input mode
+    pub search_case_sensitive: bool, // Match case exactly instead of ignoring it
+
+    // Global search over the failed jobs' logs of all tracked MRs
+    pub global_search_query: String,
+    pub is_global_searching: bool, // Typing the query
+    pub global_search_results: Vec<GlobalSearchResult>,
+    pub selected_global_search_result: usize,
+    pub global_search_pending: usize, // Traces still loading for the current search
+    pub seen_log_lengths: HashMap<u64, usize>, // job_id -> trace length when last viewed (kept across refreshes)
+    pub log_new_content_line: Option<usize>, // First line not present the last time this log was viewed
+    pub log_delta_mode: bool, // Jump to and highlight only the new output
+    pub diff_files: Vec<DiffFile>, // Files shown in the diff viewer, rendered into `log_processed_lines`
+    pub failure_summary_jobs: Vec<Job>, // Failed jobs shown in the failure summary, rendered into `log_processed_lines`
+    pub failure_summary_pending: usize, // Their traces still loading
+    pub failure_summary_errors: HashMap<u64, String>, // job_id -> why its trace could not be fetched
+    pub project_pipelines: Option<ProjectPipelines>, // While the project pipelines view is open
+
+    // Comment composition
+    pub is_composing_note: bool, // Whether the comment input is open
+    pub note_draft: String,      // Kept until posted so a failed post can be retried
+
+    // Key remappings from [keybindings]
+    pub keymap: Keymap,
+    pub theme: Theme,
+    pub click_areas: ClickAreas, // Tabs and job rows of the last frame, for mouse clicks
+
+    // Events for user hooks, drained by the main loop after each update
+    pub pending_hook_events: Vec<HookEvent>,
+
+    // Tracked/removed MRs persisted across restarts, saved by the main loop when dirty
+    pub tracked_state: TrackedState,
+    pub tracked_state_dirty: bool,
+    pub tracked_state_restored: bool, // Whether saved MRs missing from the first load were requested
 
     // Status
     pub status_message: Option<String>,
+    pub pending_yank: bool, // `y` was pressed; the next key picks what to copy
     pub error_message: Option<String>,
     pub last_refresh: Option<chrono::DateTime<chrono::Utc>>,
 
     // Auto-refresh
     pub last_auto_refresh: Instant,
     pub auto_refresh_interval_minutes: u64,
-    pub refetch_notes_after_refresh: bool, // Flag to refetch notes after refresh completes
-    pub selected_note_id_before_refresh: Option<u64>, // Track selected note ID to restore after refresh
+    pub auto_refresh_paused: bool, // Freeze the dashboard; manual refresh still works
+    pub auto_refresh_paused_at: Instant, // When the pause started; the countdown stays there
+    // (MR iid, selected note ID) of the comments being read when a refresh started: that
+    // MR's notes are refetched and the selection restored once they load
+    pub pending_note_restore: Option<(u64, Option<u64>)>,
+}
+
+/// Where a job log was left when closed, restored when it is reopened from the cache
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogPosition {
+    pub scroll_offset: usize,
+    pub search_query: String,
+    pub current_search_result: usize,
 }
 
 #[derive(Debug, Clone)]
 pub struct TrackedMergeRequest {
     pub mr: MergeRequest,
-    pub pipelines: Vec<Pipeline>,
+    pub pipelines: Vec<Pipeline>, // Newest first; `first()` is the latest pipeline
     pub jobs: HashMap<u64, Vec<Job>>, // pipeline_id -> jobs
-    pub job_logs_cache: HashMap<u64, String>, // job_id -> cached log content
+    pub job_logs_cache: LruLogCache, // job_id -> cached log content
+    pub log_positions: HashMap<u64, LogPosition>, // job_id -> where its cached log was left
     pub notes: Vec<Note>,              // MR comments/notes
     pub notes_loaded: bool,            // Track if notes have been fetched
     pub selected_pipeline_index: usize,
     pub selected_note_index: usize,    // Track selected comment for navigation
+    pub comments_scroll_offset: usize, // First comment shown in the comments viewport
+    pub max_seen_note_id: Option<u64>, // Highest note ID from the last load, kept across refreshes
+    pub new_note_ids: HashSet<u64>,    // Notes added since the previous load, until navigated past
+    pub approvals: Option<Approvals>,  // Fetched alongside pipelines
+    pub loading: bool,
+    pub error: Option<String>,         // Last failed request about this MR, until its pipelines reload
+}
+
+impl TrackedMergeRequest {
+    /// When the MR or its latest pipeline last changed, whichever is later
+    pub fn last_activity(&self) -> chrono::DateTime<chrono::Utc> {
+        self.pipelines
+            .first()
+            .map_or(self.mr.updated_at, |pipeline| pipeline.updated_at.max(self.mr.updated_at))
+    }
+
+    /// Drop the cached job logs and where they were left
+    pub fn clear_log_cache(&mut self) {
+        self.job_logs_cache.clear();
+        self.log_positions.clear();
+    }
+
+    /// Notes shown in the comments view; navigation and rendering both index into this list
+    pub fn visible_notes(&self, show_system_notes: bool) -> Vec<&Note> {
+        self.notes
+            .iter()
+            .filter(|note| show_system_notes || !note.system)
+            .collect()
+    }
+
+    /// Start of the thread after (`forward`) or before the selected note's, wrapping around
+    fn adjacent_thread_start(&self, show_system_notes: bool, forward: bool) -> Option<usize> {
+        let starts = thread_starts(&self.visible_notes(show_system_notes));
+        let selected = self.selected_note_index;
+        if forward {
+            starts.iter().copied().find(|&start| start > selected).or(starts.first().copied())
+        } else {
+            let current = starts.iter().copied().rfind(|&start| start <= selected)?;
+            starts.iter().copied().rfind(|&start| start < current).or(starts.last().copied())
+        }
+    }
+
+    /// Clear the "new" mark of the selected note once the selection moves off it
+    fn mark_selected_note_seen(&mut self, show_system_notes: bool) {
+        let selected_id = self
+            .visible_notes(show_system_notes)
+            .get(self.selected_note_index)
+            .map(|note| note.id);
+        if let Some(id) = selected_id {
+            self.new_note_ids.remove(&id);
+        }
+    }
+
+    /// Project the MR belongs to; group-scoped MRs can come from any project in the group
+    pub fn project_id_or(&self, default_project_id: u64) -> u64 {
+        self.mr.project_id.unwrap_or(default_project_id)
+    }
+
+    pub fn new(mr: MergeRequest) -> Self {
+        Self {
+            mr,
+            pipelines: Vec::new(),
+            jobs: HashMap::new(),
+            job_logs_cache: LruLogCache::default(),
+            log_positions: HashMap::new(),
+            notes: Vec::new(),
+            notes_loaded: false,
+            selected_pipeline_index: 0,
+            selected_note_index: 0,
+            comments_scroll_offset: 0,
+            max_seen_note_id: None,
+            new_note_ids: HashSet::new(),
+            approvals: None,
+            loading: true,
+            error: None,
+        }
+    }
+}
+
+/// The project's own pipelines (`g`), shown instead of the selected MR's. The jobs table
+/// and the log viewer work on the selected one as on an MR's pipeline.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectPipelines {
+    pub ref_name: Option<String>, // Only this branch's pipelines, else those of every ref
+    pub pipelines: Vec<Pipeline>, // Newest first
+    pub jobs: HashMap<u64, Vec<Job>>, // pipeline_id -> jobs
+    pub selected_pipeline_index: usize,
     pub loading: bool,
-    #[allow(dead_code)]
-    pub error: Option<String>,         // Reserved for future per-MR error tracking
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppMode {
     Normal,           // Viewing MRs and jobs
     ViewingComments,  // Viewing MR comments instead of jobs
+    ViewingDescription, // Viewing MR title/author/description instead of jobs
     ViewingLog,       // Viewing job log internally
+    ViewingDiff,      // Viewing the MR's changes; shares the log viewer's lines and scrolling
+    ViewingFailureSummary, // Log tails of the selected pipeline's failed jobs; shares the log viewer's lines too
+    ViewingNote,      // One comment full-screen over the comments list; shares the log viewer's lines too
+    ViewingProjectPipelines, // The project's pipelines and their jobs instead of the MRs'
+    GlobalSearch,     // Searching the failed jobs' logs of all tracked MRs
+    ConfirmingRetry,  // Confirm pipeline retry with a preview of the jobs that rerun
+    ConfirmingQuit,   // Quit pressed while fetches are still in flight
     SelectingMr,      // MR selection dialog
     ShowingHelp,      // Help popup visible
 }
 
 impl App {
     pub fn new(project_id: u64, current_branch: Option<String>, focus_current_branch: bool, auto_refresh_interval_minutes: u64) -> Self {
-        let status_message = if focus_current_branch && current_branch.is_some() {
-            Some(format!("Loading MR for branch '{}'...", current_branch.as_ref().unwrap()))
-        } else {
-            Some("Loading merge requests...".to_string())
+        let status_message = match &current_branch {
+            Some(branch) if focus_current_branch => {
+                Some(format!("Loading MR for branch '{}'...", branch))
+            }
+            _ => Some("Loading merge requests...".to_string()),
         };
 
         Self {
             should_quit: false,
             selected_mr_index: 0,
             selected_job_index: 0,
+            job_status_filter: None,
+            collapse_passed_jobs: false,
             tracked_mrs: Vec::new(),
+            mr_sort: MrSort::default(),
+            mr_state: MrStateFilter::default(),
+            job_sort: JobSort::default(),
             project_id,
+            group_id: None,
             current_branch,
             focus_current_branch,
+            default_branch: None,
+            branch_from_cli: false,
             mode: AppMode::Normal,
+            mode_before_quit_confirm: AppMode::Normal,
+            in_flight_fetches: 0,
+            refresh_in_flight: false,
+            refresh_stale: false,
+            compact_comments: false,
+            show_system_notes: false,
+            description_scroll: 0,
+            highlight_authors: Vec::new(),
+            relative_timestamps: true,
+            label_filter: Vec::new(),
+            user_filter: false,
+            artifacts_dir: crate::config::settings::AppConfig::default().resolved_artifacts_dir(),
+            log_cache_max_entries: crate::log_cache::DEFAULT_MAX_ENTRIES,
+            log_cache_max_bytes: crate::log_cache::DEFAULT_MAX_BYTES,
             log_content: None,
             log_processed_lines: Vec::new(),
             log_scroll_offset: 0,
             log_viewport_height: 30, // Default, will be updated by renderer
             log_job_name: None,
+            log_job_id: None,
             timestamp_mode: TimestampDisplayMode::Hidden,
+            raw_log: false,
+            log_wrap: true,
+            log_h_scroll: 0,
             search_query: String::new(),
             search_results: Vec::new(),
             current_search_result: 0,
             is_searching: false,
+            search_case_sensitive: false,
+            global_search_query: String::new(),
+            is_global_searching: false,
+            global_search_results: Vec::new(),
+            selected_global_search_result: 0,
+            global_search_pending: 0,
+            seen_log_lengths: HashMap::new(),
+            log_new_content_line: None,
+            log_delta_mode: false,
+            diff_files: Vec::new(),
+            failure_summary_jobs: Vec::new(),
+            failure_summary_pending: 0,
+            failure_summary_errors: HashMap::new(),
+            project_pipelines: None,
+            is_composing_note: false,
+            note_draft: String::new(),
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            click_areas: ClickAreas::default(),
+            pending_hook_events: Vec::new(),
+            tracked_state: TrackedState::default(),
+            tracked_state_dirty: false,
+            tracked_state_restored: false,
             status_message,
+            pending_yank: false,
             error_message: None,
             last_refresh: None,
             last_auto_refresh: Instant::now(),
             auto_refresh_interval_minutes,
-            refetch_notes_after_refresh: false,
-            selected_note_id_before_refresh: None,
+            auto_refresh_paused: false,
+            auto_refresh_paused_at: Instant::now(),
+            pending_note_restore: None,
+        }
+    }
+
+    /// Indices into `tracked_mrs` in tab order under `mr_sort`. Ties keep load order.
+    pub fn mr_display_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.tracked_mrs.len()).collect();
+        match self.mr_sort {
+            MrSort::LoadOrder => {}
+            MrSort::UpdatedAt => {
+                order.sort_by_key(|&i| std::cmp::Reverse(self.tracked_mrs[i].last_activity()));
+            }
+            MrSort::PipelineStatus => {
+                order.sort_by_key(|&i| pipeline_sort_rank(self.tracked_mrs[i].pipelines.first()));
+            }
+        }
+        order
+    }
+
+    /// Add the MRs of a list not tracked yet, then fetch every MR's pipelines. A refresh's
+    /// list (`from_refresh`) also sets the order of the MRs it includes. Only MRs the user
+    /// added by IID are saved to come back on restart; the list brings back the others.
+    fn load_merge_requests(&mut self, mrs: Vec<MergeRequest>, from_refresh: bool) -> Option<Effect> {
+        // Keep the selection on the same MR rather than the same index
+        let selected_id = self.get_selected_mr().map(|tmr| tmr.mr.id);
+
+        let listed: Vec<u64> = mrs.iter().map(|mr| mr.id).collect();
+
+        // Initialize tracked MRs with the loaded data
+        for mr in mrs {
+            // Compare global IDs: IIDs are only unique within a project. An MR already
+            // tracked keeps its tab and state but takes the fresh title, update time, etc.
+            if let Some(tracked) = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr.id) {
+                tracked.mr = mr;
+                continue;
+            }
+            let project_id = mr.project_id.unwrap_or(self.project_id);
+            if self.tracked_state.is_removed(project_id, mr.iid) || !mr.has_labels(&self.label_filter) {
+                continue;
+            }
+            self.tracked_mrs.push(TrackedMergeRequest {
+                job_logs_cache: LruLogCache::new(self.log_cache_max_entries, self.log_cache_max_bytes),
+                ..TrackedMergeRequest::new(mr)
+            });
+        }
+        // Follow the order of a refresh's list; MRs it doesn't include (added by IID) go last
+        if from_refresh {
+            self.tracked_mrs
+                .sort_by_key(|tmr| listed.iter().position(|&id| id == tmr.mr.id).unwrap_or(listed.len()));
+        }
+        self.selected_mr_index = selected_id
+            .and_then(|id| self.tracked_mrs.iter().position(|tmr| tmr.mr.id == id))
+            .unwrap_or(0);
+
+        self.status_message = Some(format!("Loaded {} merge requests", self.tracked_mrs.len()));
+        // A successful refresh supersedes the last error shown in the status bar
+        self.error_message = None;
+        self.pending_hook_events.push(HookEvent::Refresh {
+            project_id: self.project_id,
+            mr_count: self.tracked_mrs.len(),
+        });
+
+        // Bring back saved MRs the list didn't include before fetching pipelines
+        if !self.tracked_state_restored {
+            self.tracked_state_restored = true;
+            let iids = self.missing_tracked_iids();
+            if !iids.is_empty() {
+                return Some(Effect::FetchMergeRequestsByIids {
+                    project_id: self.project_id,
+                    iids,
+                });
+            }
+        }
+
+        // Fetch pipelines for every MR so all tabs fill in, not just the selected one
+        let effects: Vec<Effect> = self
+            .tracked_mrs
+            .iter()
+            .map(|tmr| Effect::FetchPipelines {
+                mr_id: tmr.mr.id,
+                project_id: tmr.project_id_or(self.project_id),
+                mr_iid: tmr.mr.iid,
+            })
+            .collect();
+        Effect::batch(effects)
+    }
+
+    /// Track an MR of `project_id` from now on and fetch it unless it has a tab already
+    fn add_mr(&mut self, project_id: u64, iid: u64) -> Option<Effect> {
+        if self.tracked_state.mark_tracked(project_id, iid) {
+            self.tracked_state_dirty = true;
+        }
+        let already_tracked = self
+            .tracked_mrs
+            .iter()
+            .any(|tmr| tmr.mr.iid == iid && tmr.project_id_or(self.project_id) == project_id);
+        if already_tracked {
+            return None;
+        }
+        Some(Effect::FetchMergeRequestsByIids { project_id, iids: vec![iid] })
+    }
+
+    /// Reload the MR list with the current project, branch focus and state
+    fn refresh_all(&self) -> Effect {
+        Effect::RefreshAll {
+            project_id: self.project_id,
+            group_id: self.group_id,
+            source_branch: self.refresh_source_branch(),
+            state: self.mr_state,
+        }
+    }
+
+    /// Select the next (or previous) MR tab in display order, wrapping around
+    fn move_mr_selection(&mut self, forward: bool) {
+        let order = self.mr_display_order();
+        if order.is_empty() {
+            return;
         }
+        let position = order.iter().position(|&i| i == self.selected_mr_index).unwrap_or(0);
+        let next = if forward {
+            (position + 1) % order.len()
+        } else {
+            position.checked_sub(1).unwrap_or(order.len() - 1)
+        };
+        self.selected_mr_index = order[next];
     }
 
     pub fn get_selected_mr(&self) -> Option<&TrackedMergeRequest> {
@@ -118,12 +734,114 @@ impl App {
         self.tracked_mrs.get_mut(self.selected_mr_index)
     }
 
+    /// Project ID for API calls about the jobs on screen: the project's own while its
+    /// pipelines are shown, else the selected MR's
+    fn jobs_project_id(&self) -> u64 {
+        if self.project_pipelines.is_some() {
+            self.project_id
+        } else {
+            self.mr_project_id(self.selected_mr_index)
+        }
+    }
+
+    /// Mode that closing a viewer or popup returns to
+    fn home_mode(&self) -> AppMode {
+        if self.project_pipelines.is_some() {
+            AppMode::ViewingProjectPipelines
+        } else {
+            AppMode::Normal
+        }
+    }
+
+    /// Fetch the project pipelines view's list again
+    fn fetch_project_pipelines(&mut self) -> Option<Effect> {
+        let view = self.project_pipelines.as_mut()?;
+        view.loading = true;
+        Some(Effect::FetchProjectPipelines {
+            project_id: self.project_id,
+            ref_name: view.ref_name.clone(),
+        })
+    }
+
+    /// Select the next (`forward`) or previous project pipeline, fetching its jobs if needed
+    fn select_project_pipeline(&mut self, forward: bool) -> Option<Effect> {
+        let view = self.project_pipelines.as_mut()?;
+        let count = view.pipelines.len();
+        if count == 0 {
+            return None;
+        }
+        view.selected_pipeline_index = if forward {
+            (view.selected_pipeline_index + 1) % count
+        } else {
+            view.selected_pipeline_index.checked_sub(1).unwrap_or(count - 1)
+        };
+        self.selected_job_index = 0;
+
+        let pipeline_id = view.pipelines[view.selected_pipeline_index].id;
+        (!view.jobs.contains_key(&pipeline_id)).then_some(Effect::FetchProjectJobs {
+            project_id: self.project_id,
+            pipeline_id,
+        })
+    }
+
+    /// Current position in `tracked_mrs` of the MR with this global ID. Replies to a
+    /// request about an MR look it up again: the list may be re-sorted, shrunk or
+    /// cleared while the request is in flight.
+    fn mr_index_of(&self, mr_id: u64) -> Option<usize> {
+        self.tracked_mrs.iter().position(|tmr| tmr.mr.id == mr_id)
+    }
+
+    /// Project ID to use for API calls about the MR at `mr_index`
+    pub fn mr_project_id(&self, mr_index: usize) -> u64 {
+        self.tracked_mrs
+            .get(mr_index)
+            .map(|tmr| tmr.project_id_or(self.project_id))
+            .unwrap_or(self.project_id)
+    }
+
+    /// Take the hook events queued since the last call
+    pub fn take_hook_events(&mut self) -> Vec<HookEvent> {
+        std::mem::take(&mut self.pending_hook_events)
+    }
+
+    /// The tracked state to save, if it changed since the last call
+    pub fn take_tracked_state_update(&mut self) -> Option<TrackedState> {
+        if !self.tracked_state_dirty {
+            return None;
+        }
+        self.tracked_state_dirty = false;
+        Some(self.tracked_state.clone())
+    }
+
+    /// Saved MR IIDs of this project that the first load didn't return. Only
+    /// restored when showing all of a project's MRs, not in group or branch mode nor
+    /// with an assignee/reviewer filter, which MRs saved in other sessions may not match.
+    fn missing_tracked_iids(&self) -> Vec<u64> {
+        if self.group_id.is_some() || self.refresh_source_branch().is_some() || self.user_filter {
+            return Vec::new();
+        }
+        self.tracked_state
+            .tracked_iids(self.project_id)
+            .iter()
+            .copied()
+            .filter(|iid| !self.tracked_mrs.iter().any(|tmr| tmr.mr.iid == *iid))
+            .collect()
+    }
+
+    /// The selected project pipeline while that view is open, else the selected MR's pipeline
     pub fn get_selected_pipeline(&self) -> Option<&Pipeline> {
+        if let Some(view) = &self.project_pipelines {
+            return view.pipelines.get(view.selected_pipeline_index);
+        }
         self.get_selected_mr()
             .and_then(|mr| mr.pipelines.get(mr.selected_pipeline_index))
     }
 
     pub fn get_selected_jobs(&self) -> Option<&[Job]> {
+        if let Some(view) = &self.project_pipelines {
+            let pipeline = view.pipelines.get(view.selected_pipeline_index)?;
+            return view.jobs.get(&pipeline.id).map(|jobs| jobs.as_slice());
+        }
         if let Some(mr) = self.get_selected_mr() {
             if let Some(pipeline) = mr.pipelines.get(mr.selected_pipeline_index) {
                 return mr.jobs.get(&pipeline.id).map(|jobs| jobs.as_slice());
@@ -132,20 +850,302 @@ impl App {
         None
     }
 
+    /// Jobs of the selected pipeline after `job_status_filter`, passed ones included
+    fn get_filtered_jobs(&self) -> impl Iterator<Item = &Job> {
+        self.get_selected_jobs()
+            .unwrap_or_default()
+            .iter()
+            .filter(|job| self.job_status_filter.as_ref().is_none_or(|status| job.status == *status))
+    }
+
+    /// Jobs of the selected pipeline shown in the jobs table, after `job_status_filter`
+    /// and without the successful ones while they're collapsed
+    pub fn get_visible_jobs(&self) -> Vec<&Job> {
+        self.get_filtered_jobs()
+            .filter(|job| !(self.collapse_passed_jobs && job.status == JobStatus::Success))
+            .collect()
+    }
+
+    /// Rows of the jobs table: the visible jobs, then while passed jobs are collapsed one
+    /// summary row per stage that had any, in the order the stages first appear
+    pub fn get_job_rows(&self) -> Vec<JobRow<'_>> {
+        let mut rows: Vec<JobRow> = self.get_visible_jobs().into_iter().map(JobRow::Job).collect();
+        if !self.collapse_passed_jobs {
+            return rows;
+        }
+
+        let mut passed: Vec<(&str, usize)> = Vec::new();
+        for job in self.get_filtered_jobs().filter(|job| job.status == JobStatus::Success) {
+            match passed.iter_mut().find(|(stage, _)| *stage == job.stage) {
+                Some((_, count)) => *count += 1,
+                None => passed.push((&job.stage, 1)),
+            }
+        }
+        rows.extend(passed.into_iter().map(|(stage, count)| JobRow::Passed { stage, count }));
+        rows
+    }
+
+    /// Jobs of the selected pipeline that a pipeline retry would rerun
+    pub fn get_retry_preview(&self) -> Vec<&Job> {
+        self.get_selected_jobs().map(retry_preview).unwrap_or_default()
+    }
+
     pub fn get_selected_notes(&self) -> Option<&[Note]> {
         self.get_selected_mr()
             .map(|mr| mr.notes.as_slice())
     }
 
+    pub fn get_visible_notes(&self) -> Vec<&Note> {
+        self.get_selected_mr()
+            .map(|mr| mr.visible_notes(self.show_system_notes))
+            .unwrap_or_default()
+    }
+
+    /// What to reload and reselect after a refresh: the comments being read, if any
+    fn note_restore_point(&self) -> Option<(u64, Option<u64>)> {
+        if !matches!(self.mode, AppMode::ViewingComments | AppMode::ViewingNote) {
+            return None;
+        }
+        Some((self.get_selected_mr()?.mr.iid, self.get_selected_note_id()))
+    }
+
     pub fn get_selected_note_id(&self) -> Option<u64> {
         self.get_selected_mr().and_then(|mr| {
-            let user_notes: Vec<_> = mr.notes.iter().filter(|n| !n.system).collect();
-            user_notes.get(mr.selected_note_index).map(|note| note.id)
+            mr.visible_notes(self.show_system_notes)
+                .get(mr.selected_note_index)
+                .map(|note| note.id)
         })
     }
 
+    /// Whether notes/MRs by this author should stand out (case-insensitive username match)
+    pub fn is_highlighted_author(&self, username: &str) -> bool {
+        self.highlight_authors
+            .iter()
+            .any(|author| author.eq_ignore_ascii_case(username))
+    }
+
+    /// Whether the comments list is on screen, also behind a comment opened full-screen
     pub fn is_viewing_comments(&self) -> bool {
-        self.mode == AppMode::ViewingComments
+        matches!(self.mode, AppMode::ViewingComments | AppMode::ViewingNote)
+    }
+
+    pub fn is_viewing_description(&self) -> bool {
+        self.mode == AppMode::ViewingDescription
+    }
+
+    /// Whether a full-screen line viewer (job log, diff, failure summary or comment) is
+    /// open; all scroll `log_processed_lines`
+    pub fn is_viewing_lines(&self) -> bool {
+        matches!(
+            self.mode,
+            AppMode::ViewingLog | AppMode::ViewingDiff | AppMode::ViewingFailureSummary | AppMode::ViewingNote
+        )
+    }
+
+    /// Branch used to filter MRs on refresh when focusing the current branch.
+    ///
+    /// Once the MR for the local branch is tracked, GitLab's `source_branch` for it is
+    /// used so the refresh keeps following that MR; the local git branch is the fallback.
+    /// On the project's default branch there is no MR to focus on, so nothing is filtered.
+    fn refresh_source_branch(&self) -> Option<String> {
+        if !self.focus_current_branch {
+            return None;
+        }
+        let local_branch = self.current_branch.as_ref()?;
+        if !self.branch_from_cli && self.default_branch.as_ref() == Some(local_branch) {
+            return None;
+        }
+
+        // GitLab branch names are case-sensitive: send the MR's spelling, preferring an
+        // exact match over one differing only in case
+        let branches = || self.tracked_mrs.iter().map(|tmr| &tmr.mr.source_branch);
+        let matched = branches().find(|branch| *branch == local_branch).or_else(|| {
+            branches().find(|branch| !branch.is_empty() && branch.eq_ignore_ascii_case(local_branch))
+        });
+        Some(matched.unwrap_or(local_branch).clone())
+    }
+
+    /// Render the failure summary: each failed job's name, then the tail of its log
+    /// once loaded
+    fn rebuild_failure_summary(&mut self) {
+        use ratatui::style::{Color, Modifier, Style};
+        use ratatui::text::Line;
+
+        let mut lines = Vec::new();
+        let Some(mr) = self.get_selected_mr() else {
+            self.log_processed_lines = lines;
+            return;
+        };
+        for (index, job) in self.failure_summary_jobs.iter().enumerate() {
+            if index > 0 {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::styled(
+                format!("{} {} ({})", job.status.symbol(), job.name, job.stage),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+            match mr.job_logs_cache.peek(job.id) {
+                Some(trace) => {
+                    let tail = tail_non_empty_lines(trace, FAILURE_SUMMARY_TAIL_LINES).join("\n");
+                    if tail.is_empty() {
+                        lines.push(Line::styled("(empty log)", Style::default().fg(Color::DarkGray)));
+                    }
+                    lines.extend(self.process_log(&tail));
+                }
+                None => match self.failure_summary_errors.get(&job.id) {
+                    Some(error) => lines.push(Line::styled(
+                        format!("failed to load log: {}", error),
+                        Style::default().fg(Color::Red),
+                    )),
+                    None => lines.push(Line::styled("loading…", Style::default().fg(Color::DarkGray))),
+                },
+            }
+        }
+        self.log_processed_lines = lines;
+    }
+
+    /// Rendered lines of a job log under the current raw/timestamp settings
+    fn process_log(&self, content: &str) -> Vec<ratatui::text::Line<'static>> {
+        if self.raw_log {
+            crate::log_processor::process_raw_log_content(content)
+        } else {
+            crate::log_processor::process_log_content(content, &self.timestamp_mode)
+        }
+    }
+
+    /// Lines of the open log matching the search query, as displayed, so the indices
+    /// are those of `log_processed_lines`
+    fn matching_log_lines(&self) -> Vec<usize> {
+        if self.log_content.is_none() {
+            return Vec::new();
+        }
+        crate::log_processor::find_matching_display_lines(
+            &self.log_processed_lines,
+            &self.search_query,
+            self.search_case_sensitive,
+        )
+    }
+
+    /// Process the open log again after a display option changed, keeping an executed
+    /// search in step with the new text
+    fn reprocess_log(&mut self) {
+        let Some(content) = &self.log_content else {
+            return;
+        };
+        self.log_processed_lines = self.process_log(content);
+        if !self.is_searching && !self.search_query.is_empty() {
+            self.search_results = self.matching_log_lines();
+            self.current_search_result = self
+                .current_search_result
+                .min(self.search_results.len().saturating_sub(1));
+        }
+    }
+
+    /// Find the lines matching the search query and jump to the first one
+    fn run_search(&mut self) {
+        self.search_results = self.matching_log_lines();
+        self.current_search_result = 0;
+
+        // Jump to first result if any, centered in viewport
+        if let Some(&first) = self.search_results.first() {
+            self.center_log_line(first);
+        }
+    }
+
+    /// Show `message` when stepping through search results wrapped around, and drop an
+    /// earlier wrap message otherwise
+    fn set_search_wrap_message(&mut self, wrapped: bool, message: &str) {
+        if wrapped {
+            self.status_message = Some(message.to_string());
+        } else if matches!(self.status_message.as_deref(), Some(SEARCH_WRAPPED_TO_TOP | SEARCH_WRAPPED_TO_BOTTOM)) {
+            self.status_message = None;
+        }
+    }
+
+    /// Remember where the open job log was left, if it is one cached in the selected MR
+    fn save_log_position(&mut self) {
+        if self.mode != AppMode::ViewingLog || self.project_pipelines.is_some() {
+            return;
+        }
+        let Some(job_id) = self.log_job_id else {
+            return;
+        };
+        let position = LogPosition {
+            scroll_offset: self.log_scroll_offset,
+            search_query: self.search_query.clone(),
+            current_search_result: self.current_search_result,
+        };
+        if let Some(mr) = self.get_selected_mr_mut() {
+            mr.log_positions.insert(job_id, position);
+        }
+    }
+
+    /// Put a reopened cached log back where it was left, search included
+    fn restore_log_position(&mut self, job_id: u64) {
+        let Some(position) = self
+            .get_selected_mr()
+            .and_then(|mr| mr.log_positions.get(&job_id))
+            .cloned()
+        else {
+            return;
+        };
+        if !position.search_query.is_empty() {
+            self.search_query = position.search_query;
+            self.run_search();
+            self.current_search_result = position
+                .current_search_result
+                .min(self.search_results.len().saturating_sub(1));
+        }
+        self.log_scroll_offset = position.scroll_offset;
+    }
+
+    /// Recompute the global search results from the logs cached so far
+    fn refresh_global_search_results(&mut self) {
+        self.global_search_results =
+            global_search_results(&self.tracked_mrs, &self.global_search_query, self.search_case_sensitive);
+        self.selected_global_search_result = self
+            .selected_global_search_result
+            .min(self.global_search_results.len().saturating_sub(1));
+    }
+
+    /// Furthest the log may scroll right: the width of the longest line on screen,
+    /// less one column so some of it stays visible
+    fn max_log_h_scroll(&self) -> usize {
+        let start = self.log_scroll_offset.min(self.log_processed_lines.len());
+        let end = (start + self.log_viewport_height).min(self.log_processed_lines.len());
+        self.log_processed_lines[start..end]
+            .iter()
+            .map(|line| line.width())
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1)
+    }
+
+    /// Apply the settings that take effect without a restart, at startup and again on
+    /// `ReloadConfig`
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.auto_refresh_interval_minutes = settings.app.auto_refresh_interval_minutes;
+        self.highlight_authors = settings.ui.highlight_authors.clone();
+        self.relative_timestamps = settings.ui.relative_timestamps;
+        self.job_sort = JobSort::from_name(&settings.ui.job_sort).unwrap_or_default();
+        self.artifacts_dir = settings.app.resolved_artifacts_dir();
+        self.theme = Theme::from_name(&settings.ui.theme).unwrap_or_default();
+    }
+
+    pub fn auto_refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.auto_refresh_interval_minutes * 60)
+    }
+
+    /// Frozen at its value when auto-refresh was paused, or the full interval after a
+    /// manual refresh during the pause
+    pub fn time_until_auto_refresh(&self) -> Duration {
+        if self.auto_refresh_paused {
+            let elapsed = self
+                .auto_refresh_paused_at
+                .saturating_duration_since(self.last_auto_refresh);
+            return self.auto_refresh_interval().saturating_sub(elapsed);
+        }
+        time_until_refresh(self.last_auto_refresh, self.auto_refresh_interval())
     }
 
     /// Center a line in the log viewer viewport
@@ -171,56 +1171,273 @@ impl App {
         self.log_scroll_offset = self.log_scroll_offset.min(max_offset);
     }
 
+    /// Apply `action`, keeping `in_flight_fetches` in step with the API requests
+    /// the returned effect starts and the responses that come back
     pub fn update(&mut self, action: Action) -> Option<Effect> {
+        if action.is_api_response() {
+            self.in_flight_fetches = self.in_flight_fetches.saturating_sub(1);
+        }
+        let effect = self.handle_action(action);
+        if let Some(effect) = &effect {
+            self.in_flight_fetches += effect.api_request_count();
+        }
+        effect
+    }
+
+    fn handle_action(&mut self, action: Action) -> Option<Effect> {
         match action {
-            Action::Quit => {
+            Action::Quit if self.in_flight_fetches > 0 && self.mode != AppMode::ConfirmingQuit => {
+                self.mode_before_quit_confirm = self.mode.clone();
+                self.mode = AppMode::ConfirmingQuit;
+                None
+            }
+
+            Action::Quit | Action::ForceQuit => {
                 self.should_quit = true;
+                self.tracked_state_dirty = true;
                 None
             }
 
-            Action::NextMr => {
-                if !self.tracked_mrs.is_empty() {
-                    self.selected_mr_index = (self.selected_mr_index + 1) % self.tracked_mrs.len();
-                    self.selected_job_index = 0;
+            Action::CancelQuit => {
+                if self.mode == AppMode::ConfirmingQuit {
+                    self.mode = self.mode_before_quit_confirm.clone();
+                }
+                None
+            }
+
+            Action::NextMr => {
+                if !self.tracked_mrs.is_empty() {
+                    self.move_mr_selection(true);
+                    self.selected_job_index = 0;
+                    self.job_status_filter = None;
+                    self.description_scroll = 0;
                 }
                 None
             }
 
             Action::PrevMr => {
                 if !self.tracked_mrs.is_empty() {
-                    self.selected_mr_index = self
-                        .selected_mr_index
-                        .checked_sub(1)
-                        .unwrap_or(self.tracked_mrs.len() - 1);
+                    self.move_mr_selection(false);
                     self.selected_job_index = 0;
+                    self.job_status_filter = None;
+                    self.description_scroll = 0;
                 }
                 None
             }
 
-            Action::NextJob => {
-                if let Some(jobs) = self.get_selected_jobs() {
-                    if !jobs.is_empty() {
-                        self.selected_job_index = (self.selected_job_index + 1) % jobs.len();
+            Action::SelectMrTab(mr_index) => {
+                if mr_index < self.tracked_mrs.len() && mr_index != self.selected_mr_index {
+                    self.selected_mr_index = mr_index;
+                    self.selected_job_index = 0;
+                    self.job_status_filter = None;
+                    self.description_scroll = 0;
+                }
+                None
+            }
+
+            Action::ToggleProjectPipelines => {
+                self.selected_job_index = 0;
+                if self.project_pipelines.take().is_some() {
+                    self.mode = AppMode::Normal;
+                    return None;
+                }
+                // Group mode tracks MRs of many projects, so there is no single one to show
+                if self.project_id == 0 {
+                    self.status_message = Some("Project pipelines need a single project, not a group".to_string());
+                    return None;
+                }
+                self.project_pipelines = Some(ProjectPipelines::default());
+                self.mode = AppMode::ViewingProjectPipelines;
+                self.status_message = Some("Loading project pipelines...".to_string());
+                self.fetch_project_pipelines()
+            }
+
+            Action::ToggleProjectPipelinesRef => {
+                let branch = self.default_branch.clone().or_else(|| self.current_branch.clone());
+                let view = self.project_pipelines.as_mut()?;
+                view.ref_name = match (&view.ref_name, branch) {
+                    (None, Some(branch)) => Some(branch),
+                    (None, None) => {
+                        self.status_message = Some("No default branch known, showing every ref".to_string());
+                        return None;
                     }
+                    (Some(_), _) => None,
+                };
+                view.pipelines.clear();
+                view.selected_pipeline_index = 0;
+                self.selected_job_index = 0;
+                self.status_message = Some(match &view.ref_name {
+                    Some(ref_name) => format!("Loading pipelines of '{}'...", ref_name),
+                    None => "Loading pipelines of every ref...".to_string(),
+                });
+                self.fetch_project_pipelines()
+            }
+
+            Action::RefreshProjectPipelines => {
+                self.status_message = Some("Refreshing project pipelines...".to_string());
+                self.fetch_project_pipelines()
+            }
+
+            Action::ProjectPipelinesLoaded(mut pipelines) => {
+                let view = self.project_pipelines.as_mut()?;
+                pipelines.sort_by_key(|p| std::cmp::Reverse(p.id));
+                // Stay on the pipeline that was selected if it's still listed
+                let selected_id = view.pipelines.get(view.selected_pipeline_index).map(|p| p.id);
+                view.selected_pipeline_index = selected_id
+                    .and_then(|id| pipelines.iter().position(|p| p.id == id))
+                    .unwrap_or(0);
+                view.pipelines = pipelines;
+                view.loading = false;
+                self.status_message = Some(format!("Loaded {} project pipelines", view.pipelines.len()));
+                self.last_refresh = Some(chrono::Utc::now());
+
+                // Jobs of the selected pipeline are refetched so their statuses stay current
+                let pipeline_id = view.pipelines.get(view.selected_pipeline_index)?.id;
+                Some(Effect::FetchProjectJobs {
+                    project_id: self.project_id,
+                    pipeline_id,
+                })
+            }
+
+            Action::ProjectJobsLoaded { pipeline_id, mut jobs } => {
+                let view = self.project_pipelines.as_mut()?;
+                self.job_sort.sort(&mut jobs);
+                view.jobs.insert(pipeline_id, jobs);
+                None
+            }
+
+            Action::ReloadConfig => {
+                self.status_message = Some("Reloading config...".to_string());
+                Some(Effect::ReloadConfig)
+            }
+
+            Action::ConfigReloaded { settings, client } => {
+                self.apply_settings(&settings);
+                self.error_message = None;
+                self.status_message = Some("Config reloaded".to_string());
+                // The client is only rebuilt when the connection or MR filter changed
+                let client = client?;
+                self.user_filter = client.mr_filter().filters_users();
+
+                // Another instance, token or MR filter: the MRs listed so far may not belong
+                self.tracked_mrs.clear();
+                self.selected_mr_index = 0;
+                self.selected_job_index = 0;
+                self.last_auto_refresh = Instant::now();
+                self.status_message = Some("Config reloaded, reloading merge requests...".to_string());
+                // A refresh sent with the old connection would fill the list with its MRs:
+                // drop its reply and refresh once it arrives
+                if self.refresh_in_flight {
+                    self.refresh_stale = true;
+                    return None;
+                }
+                self.refresh_in_flight = true;
+                Some(self.refresh_all())
+            }
+
+            Action::ConfigReloadFailed(error) => {
+                self.error_message = Some(format!("Config reload failed: {}", error));
+                None
+            }
+
+            Action::CycleMrState => {
+                // A late reply to the running refresh would mix in MRs of the old state
+                if self.refresh_in_flight {
+                    self.status_message = Some("Refresh in progress, try again when it finishes".to_string());
+                    return None;
+                }
+                self.mr_state = self.mr_state.next();
+                // The tabs now list MRs in the new state only, so drop the old ones
+                self.tracked_mrs.clear();
+                self.selected_mr_index = 0;
+                self.selected_job_index = 0;
+                self.last_auto_refresh = Instant::now();
+                self.status_message = Some(format!("Loading {} MRs...", self.mr_state.as_param()));
+                self.refresh_in_flight = true;
+                Some(Effect::RefreshAll {
+                    project_id: self.project_id,
+                    group_id: self.group_id,
+                    source_branch: self.refresh_source_branch(),
+                    state: self.mr_state,
+                })
+            }
+
+            Action::CycleMrSort => {
+                self.mr_sort = self.mr_sort.next();
+                self.status_message = Some(format!("MRs sorted by {}", self.mr_sort.label()));
+                None
+            }
+
+            Action::NextJob => {
+                let visible = self.get_visible_jobs().len();
+                if visible > 0 {
+                    self.selected_job_index = (self.selected_job_index + 1) % visible;
                 }
                 None
             }
 
             Action::PrevJob => {
-                if let Some(jobs) = self.get_selected_jobs() {
-                    if !jobs.is_empty() {
-                        self.selected_job_index = self
-                            .selected_job_index
-                            .checked_sub(1)
-                            .unwrap_or(jobs.len() - 1);
-                    }
+                let visible = self.get_visible_jobs().len();
+                if visible > 0 {
+                    self.selected_job_index = self
+                        .selected_job_index
+                        .checked_sub(1)
+                        .unwrap_or(visible - 1);
+                }
+                None
+            }
+
+            Action::NextFailedJob | Action::PrevFailedJob => {
+                let failed: Vec<usize> = self
+                    .get_visible_jobs()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, job)| job.status == JobStatus::Failed)
+                    .map(|(index, _)| index)
+                    .collect();
+                let selected = self.selected_job_index;
+                let target = if matches!(action, Action::NextFailedJob) {
+                    failed.iter().find(|&&index| index > selected).or(failed.first())
+                } else {
+                    failed.iter().rfind(|&&index| index < selected).or(failed.last())
+                };
+                if let Some(&index) = target {
+                    self.selected_job_index = index;
                 }
                 None
             }
 
+            Action::CycleJobStatusFilter => {
+                self.job_status_filter = next_job_status_filter(self.job_status_filter.as_ref());
+                self.selected_job_index = 0;
+                None
+            }
+
+            Action::ToggleFailedJobsFilter => {
+                self.job_status_filter = match self.job_status_filter {
+                    Some(JobStatus::Failed) => None,
+                    _ => Some(JobStatus::Failed),
+                };
+                self.selected_job_index = 0;
+                None
+            }
+
+            Action::ToggleCollapsePassedJobs => {
+                // Keep the selected job selected when it stays visible
+                let selected_id = self.get_visible_jobs().get(self.selected_job_index).map(|job| job.id);
+                self.collapse_passed_jobs = !self.collapse_passed_jobs;
+                self.selected_job_index = selected_id
+                    .and_then(|id| self.get_visible_jobs().iter().position(|job| job.id == id))
+                    .unwrap_or(0);
+                None
+            }
+
+            Action::NextPipeline if self.project_pipelines.is_some() => self.select_project_pipeline(true),
+            Action::PrevPipeline if self.project_pipelines.is_some() => self.select_project_pipeline(false),
+
             Action::NextPipeline => {
                 let mr_index = self.selected_mr_index;
-                let project_id = self.project_id;
+                let project_id = self.mr_project_id(mr_index);
 
                 if let Some(mr) = self.tracked_mrs.get_mut(mr_index) {
                     if !mr.pipelines.is_empty() {
@@ -232,13 +1449,12 @@ impl App {
                             let pipeline_id = pipeline.id;
                             let needs_fetch = !mr.jobs.contains_key(&pipeline_id);
 
-                            // Drop the borrow so we can modify self
-                            let _ = mr;
+                            let mr_id = mr.mr.id;
                             self.selected_job_index = 0;
 
                             if needs_fetch {
                                 return Some(Effect::FetchJobs {
-                                    mr_index,
+                                    mr_id,
                                     project_id,
                                     pipeline_id,
                                 });
@@ -253,7 +1469,7 @@ impl App {
 
             Action::PrevPipeline => {
                 let mr_index = self.selected_mr_index;
-                let project_id = self.project_id;
+                let project_id = self.mr_project_id(mr_index);
 
                 if let Some(mr) = self.tracked_mrs.get_mut(mr_index) {
                     if !mr.pipelines.is_empty() {
@@ -267,13 +1483,12 @@ impl App {
                             let pipeline_id = pipeline.id;
                             let needs_fetch = !mr.jobs.contains_key(&pipeline_id);
 
-                            // Drop the borrow so we can modify self
-                            let _ = mr;
+                            let mr_id = mr.mr.id;
                             self.selected_job_index = 0;
 
                             if needs_fetch {
                                 return Some(Effect::FetchJobs {
-                                    mr_index,
+                                    mr_id,
                                     project_id,
                                     pipeline_id,
                                 });
@@ -286,30 +1501,43 @@ impl App {
                 None
             }
 
+            Action::OpenJobLogAt(job_index) => {
+                if job_index >= self.get_visible_jobs().len() {
+                    return None;
+                }
+                self.selected_job_index = job_index;
+                self.handle_action(Action::OpenSelectedJobLog)
+            }
+
             Action::OpenSelectedJobLog => {
-                let job_info = self.get_selected_jobs()
-                    .and_then(|jobs| jobs.get(self.selected_job_index))
+                let job_info = self.get_visible_jobs()
+                    .get(self.selected_job_index)
                     .map(|job| (job.name.clone(), job.id));
 
                 if let Some((job_name, job_id)) = job_info {
-                    // Check if log is already cached
-                    if let Some(mr) = self.tracked_mrs.get(self.selected_mr_index) {
-                        if let Some(cached_log) = mr.job_logs_cache.get(&job_id) {
-                            // Use cached log
-                            self.status_message = None;
-                            self.log_processed_lines = crate::log_processor::process_log_content(cached_log, &self.timestamp_mode);
-                            self.log_content = Some(cached_log.clone());
-                            self.log_job_name = Some(job_name);
-                            self.log_scroll_offset = 0;
-                            self.mode = AppMode::ViewingLog;
-                            return None;
-                        }
+                    // Check if log is already cached (project pipelines' logs aren't)
+                    let cached_log = self
+                        .tracked_mrs
+                        .get_mut(self.selected_mr_index)
+                        .filter(|_| self.project_pipelines.is_none())
+                        .and_then(|mr| mr.job_logs_cache.get(job_id).cloned());
+                    if let Some(cached_log) = cached_log {
+                        self.status_message = None;
+                        self.log_processed_lines = self.process_log(&cached_log);
+                        self.log_content = Some(cached_log);
+                        self.log_job_name = Some(job_name);
+                        self.log_job_id = Some(job_id);
+                        self.log_scroll_offset = 0;
+                        self.log_new_content_line = None;
+                        self.mode = AppMode::ViewingLog;
+                        self.restore_log_position(job_id);
+                        return None;
                     }
 
                     // Not cached, fetch from API
                     self.status_message = Some(format!("Fetching log for job '{}'...", job_name));
                     return Some(Effect::FetchJobTrace {
-                        project_id: self.project_id,
+                        project_id: self.jobs_project_id(),
                         job_id,
                         job_name,
                     });
@@ -318,38 +1546,59 @@ impl App {
             }
 
             Action::Refresh => {
+                // Holding `r` must not stack refreshes while one is still loading
+                if self.refresh_in_flight {
+                    return None;
+                }
+
                 // Reset auto-refresh timer on manual refresh
                 self.last_auto_refresh = Instant::now();
 
-                // Set flag to refetch notes after refresh if currently viewing comments
-                self.refetch_notes_after_refresh = self.mode == AppMode::ViewingComments;
-
-                // Save the currently selected note ID if viewing comments
-                if self.refetch_notes_after_refresh {
-                    self.selected_note_id_before_refresh = self.get_selected_note_id();
-                }
+                self.pending_note_restore = self.note_restore_point();
 
                 // Clear all cached data including notes and job logs
                 for mr in &mut self.tracked_mrs {
                     mr.notes_loaded = false;
                     mr.notes.clear();
-                    mr.job_logs_cache.clear();
+                    mr.clear_log_cache();
                 }
 
                 self.status_message = Some("Refreshing...".to_string());
+                self.refresh_in_flight = true;
                 Some(Effect::RefreshAll {
                     project_id: self.project_id,
-                    source_branch: if self.focus_current_branch {
-                        self.current_branch.clone()
-                    } else {
-                        None
-                    },
+                    group_id: self.group_id,
+                    source_branch: self.refresh_source_branch(),
+                    state: self.mr_state,
+                })
+            }
+
+            Action::RefreshCurrentMr => {
+                let mr_index = self.selected_mr_index;
+                let project_id = self.mr_project_id(mr_index);
+                // The comments being read come back once the pipelines have loaded
+                self.pending_note_restore = self.note_restore_point();
+
+                let mr = self.tracked_mrs.get_mut(mr_index)?;
+                mr.notes_loaded = false;
+                mr.notes.clear();
+                mr.clear_log_cache();
+                mr.loading = true;
+
+                self.status_message = Some(format!("Refreshing MR #{}...", mr.mr.iid));
+                Some(Effect::FetchPipelines {
+                    mr_id: mr.mr.id,
+                    project_id,
+                    mr_iid: mr.mr.iid,
                 })
             }
 
             Action::RemoveCurrentMr => {
                 if !self.tracked_mrs.is_empty() {
-                    self.tracked_mrs.remove(self.selected_mr_index);
+                    let project_id = self.mr_project_id(self.selected_mr_index);
+                    let removed = self.tracked_mrs.remove(self.selected_mr_index);
+                    self.tracked_state.mark_removed(project_id, removed.mr.iid);
+                    self.tracked_state_dirty = true;
                     if self.selected_mr_index > 0 {
                         self.selected_mr_index -= 1;
                     }
@@ -358,89 +1607,98 @@ impl App {
                 None
             }
 
-            Action::MergeRequestsLoaded(mrs) => {
-                // Initialize tracked MRs with the loaded data
-                for mr in mrs {
-                    if !self.tracked_mrs.iter().any(|tmr| tmr.mr.iid == mr.iid) {
-                        let tracked_mr = TrackedMergeRequest {
-                            mr: mr.clone(),
-                            pipelines: Vec::new(),
-                            jobs: HashMap::new(),
-                            job_logs_cache: HashMap::new(),
-                            notes: Vec::new(),
-                            notes_loaded: false,
-                            selected_pipeline_index: 0,
-                            selected_note_index: 0,
-                            loading: true,
-                            error: None,
-                        };
-                        self.tracked_mrs.push(tracked_mr);
-                    }
-                }
+            Action::RestoreRemovedMr => {
+                // Removals are kept with each MR's own project, which in group mode isn't ours
+                let Some(removed) = self.tracked_state.last_removed() else {
+                    self.status_message = Some("No removed MR to restore".to_string());
+                    return None;
+                };
+                self.status_message = Some(format!("Restoring !{}...", removed.iid));
+                self.add_mr(removed.project_id, removed.iid)
+            }
 
-                self.status_message = Some(format!("Loaded {} merge requests", self.tracked_mrs.len()));
+            Action::AddMr(iid) => {
+                // An IID alone doesn't say which of the group's projects it belongs to
+                if self.group_id.is_some() {
+                    self.status_message = Some("Adding an MR by IID needs a project, not a group".to_string());
+                    return None;
+                }
+                self.add_mr(self.project_id, iid)
+            }
 
-                // Fetch pipelines for each MR
-                let effects: Vec<Effect> = self
-                    .tracked_mrs
-                    .iter()
-                    .enumerate()
-                    .map(|(index, tmr)| Effect::FetchPipelines {
-                        mr_index: index,
-                        project_id: self.project_id,
-                        mr_iid: tmr.mr.iid,
-                    })
-                    .collect();
+            Action::MergeRequestsLoaded(_) | Action::RefreshFailed(_) if self.refresh_stale => {
+                self.refresh_stale = false;
+                Some(self.refresh_all())
+            }
 
-                // Return the first effect; in a real implementation, we'd handle multiple
-                effects.into_iter().next()
+            Action::MergeRequestsLoaded(mrs) => {
+                self.refresh_in_flight = false;
+                self.load_merge_requests(mrs, true)
             }
 
-            Action::PipelinesLoaded { mr_index, pipelines } => {
+            Action::PipelinesLoaded { mr_id, mut pipelines } => {
+                // Newest first (pipeline IDs increase with creation), so `pipelines.first()`
+                // is the MR's latest pipeline whatever order the API returned
+                pipelines.sort_by_key(|p| std::cmp::Reverse(p.id));
+
+                let mr_index = self.mr_index_of(mr_id)?;
                 if let Some(mr) = self.tracked_mrs.get_mut(mr_index) {
+                    // Hooks fire when the latest pipeline finishes, not for pipelines
+                    // that were already finished when first loaded
+                    if let (Some(previous), Some(latest)) = (mr.pipelines.first(), pipelines.first()) {
+                        let finished_now = previous.id != latest.id || previous.status != latest.status;
+                        if finished_now {
+                            let event = PipelineEvent::new(mr.project_id_or(self.project_id), &mr.mr, latest);
+                            match latest.status {
+                                PipelineStatus::Failed => {
+                                    self.pending_hook_events.push(HookEvent::PipelineFailed(event));
+                                }
+                                PipelineStatus::Success => {
+                                    self.pending_hook_events.push(HookEvent::PipelineSucceeded(event));
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+
                     mr.pipelines = pipelines;
                     mr.loading = false;
+                    mr.error = None;
+
+                    let project_id = mr.project_id_or(self.project_id);
+                    let mut effects = Vec::new();
 
-                    // Check if we need to refetch notes after refresh (only for selected MR)
-                    if self.refetch_notes_after_refresh && mr_index == self.selected_mr_index {
-                        self.refetch_notes_after_refresh = false;
+                    // Reload the comments that were being read when the refresh started,
+                    // even if another MR got selected meanwhile
+                    if self.pending_note_restore.is_some_and(|(iid, _)| iid == mr.mr.iid) {
                         self.status_message = Some("Reloading comments...".to_string());
-                        return Some(Effect::FetchNotes {
-                            mr_index,
-                            project_id: self.project_id,
+                        effects.push(Effect::FetchNotes {
+                            mr_id,
+                            project_id,
                             mr_iid: mr.mr.iid,
                         });
                     }
 
                     // Fetch jobs for the latest pipeline
                     if let Some(pipeline) = mr.pipelines.first() {
-                        return Some(Effect::FetchJobs {
-                            mr_index,
-                            project_id: self.project_id,
+                        effects.push(Effect::FetchJobs {
+                            mr_id,
+                            project_id,
                             pipeline_id: pipeline.id,
                         });
                     }
+                    return Effect::batch(effects);
                 }
                 None
             }
 
             Action::JobsLoaded {
-                mr_index,
+                mr_id,
                 pipeline_id,
                 mut jobs,
             } => {
-                if let Some(mr) = self.tracked_mrs.get_mut(mr_index) {
-                    // Sort jobs: failed first, then running, pending, etc.
-                    jobs.sort_by_key(|job| match job.status {
-                        JobStatus::Failed => 0,
-                        JobStatus::Running => 1,
-                        JobStatus::Pending => 2,
-                        JobStatus::Canceled => 3,
-                        JobStatus::Created => 4,
-                        JobStatus::Manual => 5,
-                        JobStatus::Success => 6,
-                        JobStatus::Skipped => 7,
-                    });
+                if let Some(mr) = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id) {
+                    self.job_sort.sort(&mut jobs);
                     mr.jobs.insert(pipeline_id, jobs);
                 }
                 self.last_refresh = Some(chrono::Utc::now());
@@ -450,74 +1708,203 @@ impl App {
             Action::JobTraceLoaded { job_id, job_name, trace } => {
                 self.status_message = None;
 
+                // Remember how much of this job's output has been seen to offer a delta view next time
+                let previous_len = self.seen_log_lengths.insert(job_id, trace.len()).unwrap_or(0);
+                self.log_new_content_line =
+                    crate::log_processor::new_content_boundary(previous_len, &trace);
+                if let Some(boundary) = self.log_new_content_line {
+                    let new_lines = trace.lines().count().saturating_sub(boundary);
+                    self.status_message = Some(format!(
+                        "{} new lines since last view (press 'd' to jump to new output)",
+                        new_lines
+                    ));
+                }
+
                 // Cache the log in the current MR
-                if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                if let Some(mr) = self
+                    .tracked_mrs
+                    .get_mut(self.selected_mr_index)
+                    .filter(|_| self.project_pipelines.is_none())
+                {
                     mr.job_logs_cache.insert(job_id, trace.clone());
                 }
 
                 // Process all lines upfront for fast rendering
-                self.log_processed_lines = crate::log_processor::process_log_content(&trace, &self.timestamp_mode);
+                self.log_processed_lines = self.process_log(&trace);
                 self.log_content = Some(trace);
                 self.log_job_name = Some(job_name);
+                self.log_job_id = Some(job_id);
                 self.log_scroll_offset = 0;
                 self.mode = AppMode::ViewingLog;
                 None
             }
 
             Action::CloseLogViewer => {
-                self.mode = AppMode::Normal;
+                self.save_log_position();
+                self.mode = if self.mode == AppMode::ViewingNote {
+                    AppMode::ViewingComments // Back to the list, same comment selected
+                } else {
+                    self.home_mode()
+                };
                 self.log_content = None;
                 self.log_processed_lines.clear();
                 self.log_job_name = None;
+                self.log_job_id = None;
                 self.log_scroll_offset = 0;
                 self.search_query.clear();
                 self.search_results.clear();
                 self.current_search_result = 0;
                 self.is_searching = false;
+                self.log_new_content_line = None;
+                self.log_delta_mode = false;
+                self.log_h_scroll = 0;
+                self.diff_files.clear();
+                self.failure_summary_jobs.clear();
+                self.failure_summary_errors.clear();
                 None
             }
 
-            Action::ScrollLogUp => {
+            Action::ShowFailureSummary => {
+                if self.mode != AppMode::Normal {
+                    return None;
+                }
+                let failed: Vec<Job> = self
+                    .get_selected_jobs()?
+                    .iter()
+                    .filter(|job| job.status == JobStatus::Failed)
+                    .cloned()
+                    .collect();
+                if failed.is_empty() {
+                    self.status_message = Some("No failed jobs in this pipeline".to_string());
+                    return None;
+                }
+
+                let mr = self.get_selected_mr()?;
+                let mr_id = mr.mr.id;
+                let project_id = self.mr_project_id(self.selected_mr_index);
+                let effects: Vec<Effect> = failed
+                    .iter()
+                    .filter(|job| !mr.job_logs_cache.contains_key(job.id))
+                    .map(|job| Effect::FetchFailureSummaryTrace { mr_id, project_id, job_id: job.id })
+                    .collect();
+
+                self.failure_summary_pending = effects.len();
+                self.failure_summary_jobs = failed;
+                self.failure_summary_errors.clear();
+                self.rebuild_failure_summary();
+                self.log_content = None;
+                self.log_scroll_offset = 0;
+                self.log_new_content_line = None;
+                self.mode = AppMode::ViewingFailureSummary;
+                Effect::batch(effects)
+            }
+
+            Action::FailureSummaryTraceLoaded { mr_id, job_id, trace } => {
+                self.failure_summary_pending = self.failure_summary_pending.saturating_sub(1);
+                let mr_index = self.mr_index_of(mr_id)?;
+                self.tracked_mrs[mr_index].job_logs_cache.insert(job_id, trace);
+                if self.mode == AppMode::ViewingFailureSummary && mr_index == self.selected_mr_index {
+                    self.rebuild_failure_summary();
+                }
+                None
+            }
+
+            Action::FailureSummaryTraceFailed { job_id, error } => {
+                self.failure_summary_pending = self.failure_summary_pending.saturating_sub(1);
+                self.failure_summary_errors.insert(job_id, error.clone());
+                if self.mode == AppMode::ViewingFailureSummary {
+                    self.rebuild_failure_summary();
+                }
+                self.error_message = Some(error);
+                None
+            }
+
+            Action::ViewMrChanges => {
+                if self.mode != AppMode::Normal {
+                    return None;
+                }
+                let mr = &self.get_selected_mr()?.mr;
+                let (mr_id, mr_iid) = (mr.id, mr.iid);
+                self.status_message = Some(format!("Loading changes of MR #{}...", mr_iid));
+                Some(Effect::FetchMrChanges {
+                    mr_id,
+                    project_id: self.mr_project_id(self.selected_mr_index),
+                    mr_iid,
+                })
+            }
+
+            Action::MrChangesLoaded { mr_id, changes } => {
+                self.status_message = None;
+                // The user moved on while the changes were loading
+                if self.get_selected_mr().map(|tmr| tmr.mr.id) != Some(mr_id) || self.mode != AppMode::Normal {
+                    return None;
+                }
+                if changes.is_empty() {
+                    self.status_message = Some("This MR has no changes".to_string());
+                    return None;
+                }
+
+                self.log_processed_lines = crate::diff_processor::process_diff(&changes);
+                self.log_content = None;
+                self.log_scroll_offset = 0;
+                self.log_new_content_line = None;
+                self.diff_files = changes;
+                self.mode = AppMode::ViewingDiff;
+                None
+            }
+
+            Action::ToggleLogDeltaMode => {
                 if self.mode == AppMode::ViewingLog {
+                    if let Some(boundary) = self.log_new_content_line {
+                        self.log_delta_mode = !self.log_delta_mode;
+                        if self.log_delta_mode {
+                            self.log_scroll_offset = boundary;
+                        }
+                    } else {
+                        self.status_message = Some("No new output since last view".to_string());
+                    }
+                }
+                None
+            }
+
+            Action::ScrollLogUp => {
+                if self.is_viewing_lines() {
                     self.log_scroll_offset = self.log_scroll_offset.saturating_sub(1);
                 }
                 None
             }
 
             Action::ScrollLogDown => {
-                if self.mode == AppMode::ViewingLog {
+                if self.is_viewing_lines() {
                     self.log_scroll_offset = self.log_scroll_offset.saturating_add(1);
                 }
                 None
             }
 
             Action::ScrollLogPageUp => {
-                if self.mode == AppMode::ViewingLog {
+                if self.is_viewing_lines() {
                     self.log_scroll_offset = self.log_scroll_offset.saturating_sub(10);
                 }
                 None
             }
 
             Action::ScrollLogPageDown => {
-                if self.mode == AppMode::ViewingLog {
+                if self.is_viewing_lines() {
                     self.log_scroll_offset = self.log_scroll_offset.saturating_add(10);
                 }
                 None
             }
 
             Action::ScrollLogHome => {
-                if self.mode == AppMode::ViewingLog {
+                if self.is_viewing_lines() {
                     self.log_scroll_offset = 0;
                 }
                 None
             }
 
             Action::ScrollLogEnd => {
-                if self.mode == AppMode::ViewingLog {
-                    if let Some(content) = &self.log_content {
-                        let total_lines = content.lines().count();
-                        self.log_scroll_offset = total_lines.saturating_sub(1);
-                    }
+                if self.is_viewing_lines() {
+                    self.log_scroll_offset = self.log_processed_lines.len().saturating_sub(1);
                 }
                 None
             }
@@ -530,9 +1917,38 @@ impl App {
                         TimestampDisplayMode::Full => TimestampDisplayMode::Hidden,
                     };
                     // Reprocess lines with new timestamp mode
-                    if let Some(ref content) = self.log_content {
-                        self.log_processed_lines = crate::log_processor::process_log_content(content, &self.timestamp_mode);
-                    }
+                    self.reprocess_log();
+                }
+                None
+            }
+
+            Action::ToggleLogWrap => {
+                if self.mode == AppMode::ViewingLog {
+                    self.log_wrap = !self.log_wrap;
+                    self.log_h_scroll = 0;
+                }
+                None
+            }
+
+            Action::ScrollLogLeft => {
+                if self.mode == AppMode::ViewingLog && !self.log_wrap {
+                    self.log_h_scroll = self.log_h_scroll.saturating_sub(LOG_H_SCROLL_STEP);
+                }
+                None
+            }
+
+            Action::ScrollLogRight => {
+                if self.mode == AppMode::ViewingLog && !self.log_wrap {
+                    self.log_h_scroll = (self.log_h_scroll + LOG_H_SCROLL_STEP)
+                        .min(self.max_log_h_scroll());
+                }
+                None
+            }
+
+            Action::ToggleRawLog => {
+                if self.mode == AppMode::ViewingLog {
+                    self.raw_log = !self.raw_log;
+                    self.reprocess_log();
                 }
                 None
             }
@@ -553,26 +1969,18 @@ impl App {
             }
 
             Action::ExecuteSearch => {
-                if let Some(content) = &self.log_content {
-                    self.search_results.clear();
-
-                    if !self.search_query.is_empty() {
-                        // Find all lines containing the search query (case-insensitive)
-                        let query_lower = self.search_query.to_lowercase();
-                        for (idx, line) in content.lines().enumerate() {
-                            if line.to_lowercase().contains(&query_lower) {
-                                self.search_results.push(idx);
-                            }
-                        }
-                    }
-
+                if self.log_content.is_some() {
                     self.is_searching = false;
-                    self.current_search_result = 0;
+                    self.run_search();
+                }
+                None
+            }
 
-                    // Jump to first result if any, centered in viewport
-                    if !self.search_results.is_empty() {
-                        self.center_log_line(self.search_results[0]);
-                    }
+            Action::ToggleSearchCaseSensitive => {
+                self.search_case_sensitive = !self.search_case_sensitive;
+                // Re-run an already executed search with the new matching
+                if !self.is_searching && !self.search_query.is_empty() {
+                    self.run_search();
                 }
                 None
             }
@@ -580,6 +1988,7 @@ impl App {
             Action::NextSearchResult => {
                 if !self.search_results.is_empty() && self.mode == AppMode::ViewingLog {
                     self.current_search_result = (self.current_search_result + 1) % self.search_results.len();
+                    self.set_search_wrap_message(self.current_search_result == 0, SEARCH_WRAPPED_TO_TOP);
                     self.center_log_line(self.search_results[self.current_search_result]);
                 }
                 None
@@ -587,11 +1996,13 @@ impl App {
 
             Action::PrevSearchResult => {
                 if !self.search_results.is_empty() && self.mode == AppMode::ViewingLog {
-                    self.current_search_result = if self.current_search_result == 0 {
+                    let wrapped = self.current_search_result == 0;
+                    self.current_search_result = if wrapped {
                         self.search_results.len() - 1
                     } else {
                         self.current_search_result - 1
                     };
+                    self.set_search_wrap_message(wrapped, SEARCH_WRAPPED_TO_BOTTOM);
                     self.center_log_line(self.search_results[self.current_search_result]);
                 }
                 None
@@ -603,76 +2014,335 @@ impl App {
                 None
             }
 
-            Action::ApiError(error) => {
-                self.error_message = Some(error.clone());
-                self.status_message = None;
+            Action::StartGlobalSearch => {
+                if self.mode == AppMode::Normal {
+                    self.mode = AppMode::GlobalSearch;
+                    self.is_global_searching = true;
+                } else if self.mode == AppMode::GlobalSearch {
+                    // Edit the query of the search already shown
+                    self.is_global_searching = true;
+                }
                 None
             }
 
-            Action::ShowHelp => {
-                self.mode = AppMode::ShowingHelp;
+            Action::UpdateGlobalSearchQuery(query) => {
+                if self.is_global_searching {
+                    self.global_search_query = query;
+                }
                 None
             }
 
-            Action::HideHelp => {
-                self.mode = AppMode::Normal;
+            Action::ExecuteGlobalSearch => {
+                if self.mode != AppMode::GlobalSearch {
+                    return None;
+                }
+                self.is_global_searching = false;
+                self.selected_global_search_result = 0;
+                self.refresh_global_search_results();
+
+                // Logs not seen yet are fetched; their matches are added as they arrive
+                let effects: Vec<Effect> = latest_failed_jobs(&self.tracked_mrs)
+                    .filter(|(mr_index, job)| !self.tracked_mrs[*mr_index].job_logs_cache.contains_key(job.id))
+                    .map(|(mr_index, job)| Effect::FetchGlobalSearchTrace {
+                        mr_id: self.tracked_mrs[mr_index].mr.id,
+                        project_id: self.mr_project_id(mr_index),
+                        job_id: job.id,
+                    })
+                    .collect();
+                self.global_search_pending = effects.len();
+                Effect::batch(effects)
+            }
+
+            Action::GlobalSearchTraceLoaded { mr_id, job_id, trace } => {
+                self.global_search_pending = self.global_search_pending.saturating_sub(1);
+                let mr = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id)?;
+                mr.job_logs_cache.insert(job_id, trace);
+                if self.mode == AppMode::GlobalSearch {
+                    self.refresh_global_search_results();
+                }
                 None
             }
 
-            Action::ToggleCommentsView => {
-                self.mode = match self.mode {
-                    AppMode::ViewingComments => AppMode::Normal,
-                    AppMode::Normal => {
-                        // Check if we need to fetch notes
-                        if let Some(mr) = self.get_selected_mr() {
-                            if !mr.notes_loaded {
-                                let mr_index = self.selected_mr_index;
-                                let project_id = self.project_id;
-                                let mr_iid = mr.mr.iid;
+            Action::GlobalSearchTraceFailed(error) => {
+                self.global_search_pending = self.global_search_pending.saturating_sub(1);
+                self.error_message = Some(error);
+                None
+            }
 
-                                self.status_message = Some("Loading comments...".to_string());
-                                self.mode = AppMode::ViewingComments;
+            Action::NextGlobalSearchResult => {
+                let count = self.global_search_results.len();
+                if count > 0 {
+                    self.selected_global_search_result = (self.selected_global_search_result + 1) % count;
+                }
+                None
+            }
 
-                                return Some(Effect::FetchNotes {
-                                    mr_index,
-                                    project_id,
-                                    mr_iid,
-                                });
-                            }
-                        }
-                        AppMode::ViewingComments
-                    }
-                    _ => self.mode.clone(), // Don't toggle in other modes
-                };
+            Action::PrevGlobalSearchResult => {
+                let count = self.global_search_results.len();
+                if count > 0 {
+                    self.selected_global_search_result = (self.selected_global_search_result + count - 1) % count;
+                }
                 None
             }
 
-            Action::NotesLoaded { mr_index, notes } => {
-                if let Some(mr) = self.tracked_mrs.get_mut(mr_index) {
-                    mr.notes = notes;
-                    mr.notes_loaded = true;
+            Action::OpenGlobalSearchResult => {
+                if self.mode != AppMode::GlobalSearch {
+                    return None;
+                }
+                let result = self.global_search_results.get(self.selected_global_search_result)?.clone();
+                let log = self.tracked_mrs.get_mut(result.mr_index)?.job_logs_cache.get(result.job_id)?.clone();
 
-                    // Try to restore the previously selected note
-                    if let Some(selected_note_id) = self.selected_note_id_before_refresh.take() {
-                        // Filter user notes (non-system) and find the index of the previously selected note
-                        let user_notes: Vec<_> = mr.notes.iter().filter(|n| !n.system).collect();
-                        let restored_index = user_notes
-                            .iter()
-                            .position(|note| note.id == selected_note_id)
-                            .unwrap_or(0); // Default to 0 if note not found
+                // Show the job's MR and pipeline behind the log, like opening it from the jobs table
+                self.handle_action(Action::SelectMrTab(result.mr_index));
+                if let Some(mr) = self.tracked_mrs.get_mut(result.mr_index) {
+                    mr.selected_pipeline_index = 0;
+                }
+                self.selected_job_index = self
+                    .get_visible_jobs()
+                    .iter()
+                    .position(|job| job.id == result.job_id)
+                    .unwrap_or(0);
 
-                        mr.selected_note_index = restored_index;
-                    } else {
-                        // No note to restore, default to 0
+                self.status_message = None;
+                self.log_processed_lines = self.process_log(&log);
+                self.log_content = Some(log);
+                self.log_job_name = Some(result.job_name);
+                self.log_job_id = Some(result.job_id);
+                self.log_scroll_offset = 0;
+                self.log_new_content_line = None;
+                self.mode = AppMode::ViewingLog;
+
+                // The query carries over as a log search so n/N step through the other matches
+                self.search_query = self.global_search_query.clone();
+                self.run_search();
+                if let Some(index) = self.search_results.iter().position(|&line| line == result.line_number) {
+                    self.current_search_result = index;
+                }
+                self.center_log_line(result.line_number);
+                None
+            }
+
+            Action::CloseGlobalSearch => {
+                if self.mode == AppMode::GlobalSearch {
+                    self.mode = AppMode::Normal;
+                }
+                self.is_global_searching = false;
+                self.global_search_results.clear();
+                self.selected_global_search_result = 0;
+                None
+            }
+
+            Action::StartNote => {
+                if self.mode == AppMode::ViewingComments && self.get_selected_mr().is_some() {
+                    self.is_composing_note = true;
+                }
+                None
+            }
+
+            Action::UpdateNoteDraft(draft) => {
+                if self.is_composing_note {
+                    self.note_draft = draft;
+                }
+                None
+            }
+
+            Action::SubmitNote => {
+                if !self.is_composing_note {
+                    return None;
+                }
+                self.is_composing_note = false;
+
+                let body = self.note_draft.trim().to_string();
+                if body.is_empty() {
+                    return None;
+                }
+
+                let mr = &self.get_selected_mr()?.mr;
+                let (mr_id, mr_iid) = (mr.id, mr.iid);
+                self.status_message = Some("Posting comment...".to_string());
+                Some(Effect::PostNote {
+                    mr_id,
+                    project_id: self.mr_project_id(self.selected_mr_index),
+                    mr_iid,
+                    body,
+                })
+            }
+
+            Action::CancelNote => {
+                self.is_composing_note = false;
+                self.note_draft.clear();
+                None
+            }
+
+            Action::NotePosted { mr_id } => {
+                self.note_draft.clear();
+                self.status_message = Some("Comment posted".to_string());
+                let mr = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id)?;
+                mr.notes_loaded = false;
+                Some(Effect::FetchNotes {
+                    mr_id,
+                    project_id: mr.project_id_or(self.project_id),
+                    mr_iid: mr.mr.iid,
+                })
+            }
+
+            // Merged like a refresh's list, without ending one that is still pending
+            Action::MoreMergeRequestsLoaded(mrs) => self.load_merge_requests(mrs, false),
+
+            Action::MergeRequestsByIidsLoaded { project_id, iids, mrs } => {
+                // Not returned: merged, closed or deleted since it was saved
+                for iid in iids.iter().filter(|&&iid| !mrs.iter().any(|mr| mr.iid == iid)) {
+                    if self.tracked_state.untrack(project_id, *iid) {
+                        self.tracked_state_dirty = true;
+                    }
+                }
+                self.load_merge_requests(mrs, false)
+            }
+
+            Action::RefreshFailed(error) => {
+                self.refresh_in_flight = false;
+                self.handle_action(Action::ApiError(error))
+            }
+
+            Action::ApiError(error) => {
+                if let Some(view) = &mut self.project_pipelines {
+                    view.loading = false;
+                }
+                self.error_message = Some(error.clone());
+                self.status_message = None;
+                None
+            }
+
+            Action::OpenUrlFailed(error) | Action::CopyFailed(error) => {
+                self.error_message = Some(error);
+                None
+            }
+
+            Action::MrApiError { mr_id, error } => {
+                // Only this MR's tab shows the failure; the others keep their status
+                if let Some(mr) = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id) {
+                    mr.error = Some(error);
+                    mr.loading = false;
+                }
+                None
+            }
+
+            Action::ShowHelp => {
+                self.mode = AppMode::ShowingHelp;
+                None
+            }
+
+            Action::HideHelp => {
+                self.mode = self.home_mode();
+                None
+            }
+
+            Action::ToggleCommentsView => {
+                self.mode = match self.mode {
+                    AppMode::ViewingComments => AppMode::Normal,
+                    AppMode::Normal => {
+                        // Check if we need to fetch notes
+                        if let Some(mr) = self.get_selected_mr() {
+                            if !mr.notes_loaded {
+                                let mr_id = mr.mr.id;
+                                let project_id = mr.project_id_or(self.project_id);
+                                let mr_iid = mr.mr.iid;
+
+                                self.status_message = Some("Loading comments...".to_string());
+                                self.mode = AppMode::ViewingComments;
+
+                                return Some(Effect::FetchNotes {
+                                    mr_id,
+                                    project_id,
+                                    mr_iid,
+                                });
+                            }
+                        }
+                        AppMode::ViewingComments
+                    }
+                    _ => self.mode.clone(), // Don't toggle in other modes
+                };
+                None
+            }
+
+            Action::OpenSelectedNote => {
+                if self.mode != AppMode::ViewingComments || self.is_composing_note {
+                    return None;
+                }
+                let mr = self.get_selected_mr()?;
+                let note = *mr.visible_notes(self.show_system_notes).get(mr.selected_note_index)?;
+                let lines = crate::ui::components::comments_list::full_note_lines(self, note);
+
+                self.log_processed_lines = lines;
+                self.log_content = None;
+                self.log_scroll_offset = 0;
+                self.log_new_content_line = None;
+                self.mode = AppMode::ViewingNote;
+                None
+            }
+
+            Action::ToggleDescriptionView => {
+                self.mode = match self.mode {
+                    AppMode::ViewingDescription => AppMode::Normal,
+                    AppMode::Normal | AppMode::ViewingComments => {
+                        self.description_scroll = 0;
+                        AppMode::ViewingDescription
+                    }
+                    _ => self.mode.clone(), // Don't toggle in other modes
+                };
+                None
+            }
+
+            Action::ScrollDescriptionUp => {
+                if self.mode == AppMode::ViewingDescription {
+                    self.description_scroll = self.description_scroll.saturating_sub(1);
+                }
+                None
+            }
+
+            Action::ScrollDescriptionDown => {
+                if self.mode == AppMode::ViewingDescription {
+                    self.description_scroll = self.description_scroll.saturating_add(1);
+                }
+                None
+            }
+
+            Action::NotesLoaded { mr_id, notes } => {
+                let show_system_notes = self.show_system_notes;
+                if let Some(mr) = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id) {
+                    // Marks from earlier loads stay until navigated past
+                    mr.new_note_ids.extend(detect_new_notes(mr.max_seen_note_id, &notes));
+                    mr.max_seen_note_id = notes.iter().map(|note| note.id).max().or(mr.max_seen_note_id);
+                    mr.notes = notes;
+                    mr.notes_loaded = true;
+
+                    // Restore the selection only in the MR it was taken from
+                    let restore = self.pending_note_restore.filter(|(iid, _)| *iid == mr.mr.iid);
+                    if restore.is_some() {
+                        self.pending_note_restore = None;
+                    }
+                    if let Some((_, Some(selected_note_id))) = restore {
+                        // Find the index of the previously selected note among the visible notes
+                        let restored_index = mr
+                            .visible_notes(show_system_notes)
+                            .iter()
+                            .position(|note| note.id == selected_note_id)
+                            .unwrap_or(0); // Default to 0 if note not found
+
+                        mr.selected_note_index = restored_index;
+                    } else {
+                        // No note to restore, default to 0
                         mr.selected_note_index = 0;
                     }
+                    // Let the renderer scroll down to the restored selection
+                    mr.comments_scroll_offset = 0;
 
                     // After notes are loaded following a refresh, continue to fetch jobs
                     if let Some(pipeline) = mr.pipelines.first() {
                         self.status_message = None;
                         return Some(Effect::FetchJobs {
-                            mr_index,
-                            project_id: self.project_id,
+                            mr_id,
+                            project_id: mr.project_id_or(self.project_id),
                             pipeline_id: pipeline.id,
                         });
                     }
@@ -683,14 +2353,14 @@ impl App {
 
             Action::NextNote => {
                 if self.mode == AppMode::ViewingComments {
-                    // Get the length of user notes (excluding system notes)
-                    let user_notes_len = self
-                        .get_selected_notes()
-                        .map(|notes| notes.iter().filter(|n| !n.system).count())
-                        .unwrap_or(0);
+                    let user_notes_len = self.get_visible_notes().len();
                     if user_notes_len > 0 {
+                        let show_system_notes = self.show_system_notes;
                         if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                            mr.mark_selected_note_seen(show_system_notes);
                             mr.selected_note_index = (mr.selected_note_index + 1) % user_notes_len;
+                            mr.comments_scroll_offset =
+                                mr.comments_scroll_offset.min(mr.selected_note_index);
                         }
                     }
                 }
@@ -699,23 +2369,174 @@ impl App {
 
             Action::PrevNote => {
                 if self.mode == AppMode::ViewingComments {
-                    // Get the length of user notes (excluding system notes)
-                    let user_notes_len = self
-                        .get_selected_notes()
-                        .map(|notes| notes.iter().filter(|n| !n.system).count())
-                        .unwrap_or(0);
+                    let user_notes_len = self.get_visible_notes().len();
                     if user_notes_len > 0 {
+                        let show_system_notes = self.show_system_notes;
                         if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                            mr.mark_selected_note_seen(show_system_notes);
                             mr.selected_note_index = mr
                                 .selected_note_index
                                 .checked_sub(1)
                                 .unwrap_or(user_notes_len - 1);
+                            // Scrolling down is handled by the renderer, which knows item heights
+                            mr.comments_scroll_offset =
+                                mr.comments_scroll_offset.min(mr.selected_note_index);
+                        }
+                    }
+                }
+                None
+            }
+
+            Action::NextThread | Action::PrevThread => {
+                if self.mode == AppMode::ViewingComments {
+                    let show_system_notes = self.show_system_notes;
+                    let forward = matches!(action, Action::NextThread);
+                    if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                        if let Some(start) = mr.adjacent_thread_start(show_system_notes, forward) {
+                            mr.mark_selected_note_seen(show_system_notes);
+                            mr.selected_note_index = start;
+                            mr.comments_scroll_offset = mr.comments_scroll_offset.min(start);
                         }
                     }
                 }
                 None
             }
 
+            Action::ToggleCompactComments => {
+                if self.mode == AppMode::ViewingComments {
+                    self.compact_comments = !self.compact_comments;
+                }
+                None
+            }
+
+            Action::ToggleSystemNotes => {
+                if self.mode == AppMode::ViewingComments {
+                    let selected_note_id = self.get_selected_note_id();
+                    self.show_system_notes = !self.show_system_notes;
+
+                    // Keep the same note selected if it is still visible
+                    let show_system_notes = self.show_system_notes;
+                    if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                        mr.selected_note_index = mr
+                            .visible_notes(show_system_notes)
+                            .iter()
+                            .position(|note| Some(note.id) == selected_note_id)
+                            .unwrap_or(0);
+                        mr.comments_scroll_offset = 0;
+                    }
+                }
+                None
+            }
+
+            Action::ScrollCommentsUp | Action::ScrollCommentsDown => {
+                if self.mode == AppMode::ViewingComments {
+                    let user_notes_len = self.get_visible_notes().len();
+                    if let Some(mr) = self.tracked_mrs.get_mut(self.selected_mr_index) {
+                        // Step from the selected comment: the stored offset lags behind the one
+                        // the list was drawn at once j/k moved the view
+                        mr.comments_scroll_offset = if matches!(action, Action::ScrollCommentsUp) {
+                            mr.selected_note_index.saturating_sub(1)
+                        } else {
+                            (mr.selected_note_index + 1).min(user_notes_len.saturating_sub(1))
+                        };
+                        // Keep the selection pinned to the top of the viewport while free scrolling
+                        mr.selected_note_index = mr.comments_scroll_offset;
+                    }
+                }
+                None
+            }
+
+            Action::RequestRetryPipeline => {
+                if self.mode == AppMode::Normal && self.get_selected_pipeline().is_some() {
+                    if self.get_retry_preview().is_empty() {
+                        self.status_message = Some("No failed or canceled jobs to retry".to_string());
+                    } else {
+                        self.mode = AppMode::ConfirmingRetry;
+                    }
+                }
+                None
+            }
+
+            Action::ConfirmRetryPipeline => {
+                if self.mode != AppMode::ConfirmingRetry {
+                    return None;
+                }
+                self.mode = AppMode::Normal;
+
+                let pipeline_id = self.get_selected_pipeline().map(|p| p.id)?;
+                self.status_message = Some(format!("Retrying pipeline #{}...", pipeline_id));
+                Some(Effect::RetryPipeline {
+                    mr_id: self.get_selected_mr()?.mr.id,
+                    project_id: self.mr_project_id(self.selected_mr_index),
+                    pipeline_id,
+                })
+            }
+
+            Action::CancelRetryPipeline => {
+                if self.mode == AppMode::ConfirmingRetry {
+                    self.mode = AppMode::Normal;
+                }
+                None
+            }
+
+            Action::PipelineRetried { mr_id, pipeline } => {
+                self.status_message = Some(format!("Pipeline #{} restarted", pipeline.iid));
+                let mr_index = self.mr_index_of(mr_id)?;
+                let mr_iid = self.tracked_mrs[mr_index].mr.iid;
+                Some(Effect::FetchPipelines {
+                    mr_id,
+                    project_id: self.mr_project_id(mr_index),
+                    mr_iid,
+                })
+            }
+
+            Action::ApprovalsLoaded { mr_id, approvals } => {
+                if let Some(mr) = self.tracked_mrs.iter_mut().find(|tmr| tmr.mr.id == mr_id) {
+                    mr.approvals = Some(approvals);
+                }
+                None
+            }
+
+            Action::ToggleApproval => {
+                let mr = self.get_selected_mr()?;
+                let (mr_id, mr_iid) = (mr.mr.id, mr.mr.iid);
+                // Approve or revoke depending on whether we've already approved
+                let approve = match &mr.approvals {
+                    Some(approvals) => !approvals.user_has_approved,
+                    None => {
+                        self.status_message = Some("Approval state not loaded yet".to_string());
+                        return None;
+                    }
+                };
+
+                self.status_message = Some(if approve {
+                    format!("Approving MR #{}...", mr_iid)
+                } else {
+                    format!("Revoking approval of MR #{}...", mr_iid)
+                });
+                Some(Effect::SetApproval {
+                    mr_id,
+                    project_id: self.mr_project_id(self.selected_mr_index),
+                    mr_iid,
+                    approve,
+                })
+            }
+
+            Action::ApprovalChanged { mr_id, approved } => {
+                let mr_index = self.mr_index_of(mr_id)?;
+                let mr_iid = self.tracked_mrs[mr_index].mr.iid;
+                self.status_message = Some(if approved {
+                    format!("Approved MR #{}", mr_iid)
+                } else {
+                    format!("Revoked approval of MR #{}", mr_iid)
+                });
+                Some(Effect::FetchApprovals {
+                    mr_id,
+                    project_id: self.mr_project_id(mr_index),
+                    mr_iid,
+                })
+            }
+
             Action::OpenMrInBrowser => {
                 if let Some(mr) = self.get_selected_mr() {
                     return Some(Effect::OpenUrl(mr.mr.web_url.clone()));
@@ -723,39 +2544,144 @@ impl App {
                 None
             }
 
+            Action::OpenJobInBrowser => {
+                let job = self.get_visible_jobs().get(self.selected_job_index).copied()?;
+                Some(Effect::OpenUrl(job.web_url.clone()))
+            }
+
+            Action::StartYank => {
+                if self.get_selected_mr().is_some() {
+                    self.pending_yank = true;
+                    self.status_message = Some("Copy: u URL, b branch".to_string());
+                }
+                None
+            }
+
+            Action::CancelYank => {
+                self.pending_yank = false;
+                self.status_message = None;
+                None
+            }
+
+            Action::CopyMrUrl | Action::CopyMrBranch => {
+                self.pending_yank = false;
+                let mr = &self.get_selected_mr()?.mr;
+                let (what, text) = if matches!(action, Action::CopyMrUrl) {
+                    ("URL", mr.web_url.clone())
+                } else {
+                    ("branch", mr.source_branch.clone())
+                };
+                self.status_message = Some(format!("Copied {} {}", what, text));
+                Some(Effect::CopyToClipboard(text))
+            }
+
+            Action::OpenPipelineInBrowser => {
+                let pipeline = self.get_selected_pipeline()?;
+                Some(Effect::OpenUrl(pipeline.web_url.clone()))
+            }
+
+            Action::DownloadArtifacts => {
+                let job = self.get_visible_jobs().get(self.selected_job_index).copied()?;
+                let (job_id, job_name) = (job.id, job.name.clone());
+                self.status_message = Some(format!("Downloading artifacts of '{}'...", job_name));
+                Some(Effect::DownloadArtifacts {
+                    project_id: self.jobs_project_id(),
+                    job_id,
+                    dest: self.artifacts_dir.join(artifacts_file_name(job_id, &job_name)),
+                    job_name,
+                })
+            }
+
+            Action::ArtifactsDownloaded { job_name, path, bytes } => {
+                self.status_message = Some(format!(
+                    "Saved artifacts of '{}' ({}) to {}",
+                    job_name,
+                    crate::ui::components::job_list::format_size(bytes),
+                    path.display()
+                ));
+                None
+            }
+
+            Action::ArtifactsDownloadFailed(error) => {
+                self.status_message = None;
+                self.error_message = Some(error);
+                None
+            }
+
+            Action::ToggleAutoRefreshPause => {
+                if self.auto_refresh_paused {
+                    // Pick the countdown up where it stopped
+                    let elapsed = self
+                        .auto_refresh_paused_at
+                        .saturating_duration_since(self.last_auto_refresh);
+                    let now = Instant::now();
+                    self.last_auto_refresh = now.checked_sub(elapsed).unwrap_or(now);
+                } else {
+                    self.auto_refresh_paused_at = Instant::now();
+                }
+                self.auto_refresh_paused = !self.auto_refresh_paused;
+                self.status_message = Some(if self.auto_refresh_paused {
+                    "Auto-refresh paused (press 'p' to resume)".to_string()
+                } else {
+                    "Auto-refresh resumed".to_string()
+                });
+                None
+            }
+
+            Action::ToggleBranchFocus => {
+                if self.current_branch.is_none() {
+                    self.focus_current_branch = false;
+                    self.status_message = Some("No branch checked out, showing all open MRs".to_string());
+                    return None;
+                }
+                // A late reply to the running refresh would mix in MRs of the old filter
+                if self.refresh_in_flight {
+                    self.status_message = Some("Refresh in progress, try again when it finishes".to_string());
+                    return None;
+                }
+
+                self.focus_current_branch = !self.focus_current_branch;
+                // Start over: merging the new list into the old one would keep the other MRs
+                self.tracked_mrs.clear();
+                self.selected_mr_index = 0;
+                self.selected_job_index = 0;
+                let effect = self.handle_action(Action::Refresh);
+                self.status_message = Some(match (&self.current_branch, self.focus_current_branch) {
+                    (Some(branch), true) => format!("Showing the MR for branch '{}'", branch),
+                    _ => "Showing all open MRs".to_string(),
+                });
+                effect
+            }
+
             Action::Tick => {
-                // Check if it's time for an auto-refresh
-                let elapsed = self.last_auto_refresh.elapsed();
-                let refresh_interval = std::time::Duration::from_secs(self.auto_refresh_interval_minutes * 60);
+                if self.auto_refresh_paused {
+                    return None;
+                }
 
-                if elapsed >= refresh_interval {
+                // A refresh still loading counts as this one; the timer stays due until it ends
+                if self.time_until_auto_refresh().is_zero() && !self.refresh_in_flight {
                     // Trigger auto-refresh
                     self.last_auto_refresh = Instant::now();
 
-                    // Set flag to refetch notes after refresh if currently viewing comments
-                    self.refetch_notes_after_refresh = self.mode == AppMode::ViewingComments;
-
-                    // Save the currently selected note ID if viewing comments
-                    if self.refetch_notes_after_refresh {
-                        self.selected_note_id_before_refresh = self.get_selected_note_id();
-                    }
+                    self.pending_note_restore = self.note_restore_point();
 
                     // Clear all cached data including notes and job logs
                     for mr in &mut self.tracked_mrs {
                         mr.notes_loaded = false;
                         mr.notes.clear();
-                        mr.job_logs_cache.clear();
+                        mr.clear_log_cache();
                     }
 
                     self.status_message = Some("Auto-refreshing...".to_string());
-                    Some(Effect::RefreshAll {
+                    self.refresh_in_flight = true;
+                    let refresh = Effect::RefreshAll {
                         project_id: self.project_id,
-                        source_branch: if self.focus_current_branch {
-                            self.current_branch.clone()
-                        } else {
-                            None
-                        },
-                    })
+                        group_id: self.group_id,
+                        source_branch: self.refresh_source_branch(),
+                        state: self.mr_state,
+                    };
+                    let project_pipelines = self.fetch_project_pipelines();
+                    Effect::batch([Some(refresh), project_pipelines].into_iter().flatten().collect())
                 } else {
                     None
                 }
@@ -769,7 +2695,7 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::gitlab::{JobStatus, PipelineStatus};
+    use crate::gitlab::{GitLabClient, JobStatus, PipelineStatus};
     use crate::gitlab::models::User;
     use chrono::Utc;
 
@@ -778,11 +2704,20 @@ mod tests {
             id,
             iid,
             title: title.to_string(),
+            description: None,
+            source_branch: format!("feature-{}", iid),
+            target_branch: "main".to_string(),
+            draft: false,
+            detailed_merge_status: None,
+            project_id: None,
+            labels: Vec::new(),
             author: User {
                 id: 1,
                 username: "testuser".to_string(),
                 name: "Test User".to_string(),
             },
+            assignees: Vec::new(),
+            reviewers: Vec::new(),
             state: "opened".to_string(),
             web_url: format!("https://gitlab.com/test/-/merge_requests/{}", iid),
             created_at: Utc::now(),
@@ -813,6 +2748,32 @@ mod tests {
             finished_at: Some(Utc::now()),
             duration: Some(120.0),
             web_url: format!("https://gitlab.com/test/-/jobs/{}", id),
+            artifacts: Vec::new(),
+            artifacts_expire_at: None,
+        }
+    }
+
+    fn create_test_note(id: u64, body: &str, system: bool) -> Note {
+        Note {
+            id,
+            body: body.to_string(),
+            author: User {
+                id: 1,
+                username: "testuser".to_string(),
+                name: "Test User".to_string(),
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            system,
+            noteable_id: 1,
+            noteable_type: "MergeRequest".to_string(),
+            project_id: 123,
+            noteable_iid: 10,
+            resolvable: false,
+            confidential: false,
+            internal: false,
+            position: None,
+            discussion_id: None,
         }
     }
 
@@ -837,37 +2798,96 @@ mod tests {
     }
 
     #[test]
-    fn test_next_mr() {
+    fn test_pipelines_sorted_newest_first() {
         let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1")));
 
-        // Add some MRs
-        let mr1 = create_test_mr(1, 10, "MR 1");
-        let mr2 = create_test_mr(2, 20, "MR 2");
-
-        app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr1,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
-            loading: false,
-            error: None,
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![
+                create_test_pipeline(101, PipelineStatus::Failed),
+                create_test_pipeline(103, PipelineStatus::Running),
+                create_test_pipeline(102, PipelineStatus::Success),
+            ],
         });
 
-        app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr2,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
+        let ids: Vec<u64> = app.tracked_mrs[0].pipelines.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![103, 102, 101]);
+        // The tab shows the newest pipeline's status, not the one the API listed first
+        assert_eq!(app.tracked_mrs[0].pipelines.first().unwrap().status.symbol(), "⟳");
+    }
+
+    #[test]
+    fn test_in_flight_fetch_bookkeeping() {
+        let mut app = App::new(123, None, false, 1);
+        assert_eq!(app.in_flight_fetches, 0);
+
+        // Refresh starts one MR list request
+        app.update(Action::Refresh);
+        assert_eq!(app.in_flight_fetches, 1);
+
+        // Its reply ends that request and starts pipelines + approvals for the first MR
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "MR 1")]));
+        assert_eq!(app.in_flight_fetches, 2);
+
+        app.update(Action::ApiError("approvals failed".to_string()));
+        assert_eq!(app.in_flight_fetches, 1);
+
+        // No pipelines: nothing further to fetch
+        app.update(Action::PipelinesLoaded { mr_id: 1, pipelines: vec![] });
+        assert_eq!(app.in_flight_fetches, 0);
+
+        // Replies never drive the count below zero
+        app.update(Action::ApiError("late".to_string()));
+        assert_eq!(app.in_flight_fetches, 0);
+    }
+
+    #[test]
+    fn test_quit_asks_for_confirmation_while_fetching() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        app.in_flight_fetches = 2;
+
+        app.update(Action::Quit);
+        assert!(!app.should_quit);
+        assert_eq!(app.mode, AppMode::ConfirmingQuit);
+
+        app.update(Action::CancelQuit);
+        assert_eq!(app.mode, AppMode::ViewingComments);
+        assert!(!app.should_quit);
+
+        // Confirming (q again or y) quits
+        app.update(Action::Quit);
+        app.update(Action::Quit);
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_force_quit_skips_confirmation() {
+        let mut app = App::new(123, None, false, 1);
+        app.in_flight_fetches = 1;
+
+        app.update(Action::ForceQuit);
+        assert!(app.should_quit);
+        assert_ne!(app.mode, AppMode::ConfirmingQuit);
+    }
+
+    #[test]
+    fn test_next_mr() {
+        let mut app = App::new(123, None, false, 1);
+
+        // Add some MRs
+        let mr1 = create_test_mr(1, 10, "MR 1");
+        let mr2 = create_test_mr(2, 20, "MR 2");
+
+        app.tracked_mrs.push(TrackedMergeRequest {
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr1)
+        });
+
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(mr2)
         });
 
         assert_eq!(app.selected_mr_index, 0);
@@ -885,29 +2905,13 @@ mod tests {
         let mr2 = create_test_mr(2, 20, "MR 2");
 
         app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr1,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr1)
         });
 
         app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr2,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr2)
         });
 
         assert_eq!(app.selected_mr_index, 0);
@@ -932,31 +2936,117 @@ mod tests {
         assert_eq!(app.tracked_mrs[1].mr.title, "MR 2");
     }
 
+    #[test]
+    fn test_refresh_in_comments_view_reloads_notes_and_jobs() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1")));
+        app.mode = AppMode::ViewingComments;
+        app.update(Action::Refresh);
+
+        match app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Running)],
+        }) {
+            Some(Effect::Batch(effects)) => {
+                assert!(matches!(effects[0], Effect::FetchNotes { mr_id: 1, mr_iid: 10, .. }));
+                assert!(matches!(effects[1], Effect::FetchJobs { pipeline_id: 100, .. }));
+                assert_eq!(effects.len(), 2);
+            }
+            other => panic!("Expected notes and jobs fetches, got {:?}", other),
+        }
+        // Kept until the notes arrive
+        assert_eq!(app.pending_note_restore, Some((10, None)));
+    }
+
+    #[test]
+    fn test_note_restore_targets_mr_read_before_refresh() {
+        let mut app = App::new(123, None, false, 1);
+        for (id, iid) in [(1, 10), (2, 20)] {
+            let mut tmr = TrackedMergeRequest::new(create_test_mr(id, iid, "MR"));
+            tmr.notes = (0..5).map(|n| create_test_note(iid * 100 + n, "note", false)).collect();
+            tmr.notes_loaded = true;
+            app.tracked_mrs.push(tmr);
+        }
+        app.mode = AppMode::ViewingComments;
+        app.tracked_mrs[0].selected_note_index = 3;
+        app.update(Action::Refresh);
+        assert_eq!(app.pending_note_restore, Some((10, Some(1003))));
+
+        // Switch to the other MR while the refresh is loading
+        app.update(Action::NextMr);
+        assert_eq!(app.selected_mr_index, 1);
+
+        // The first MR's pipelines still bring its comments back
+        let effect = app.update(Action::PipelinesLoaded { mr_id: 1, pipelines: vec![] });
+        assert!(matches!(effect, Some(Effect::FetchNotes { mr_id: 1, mr_iid: 10, .. })));
+        let effect = app.update(Action::PipelinesLoaded { mr_id: 2, pipelines: vec![] });
+        assert!(effect.is_none());
+
+        // The other MR's notes don't take the saved selection
+        let notes_20: Vec<Note> = (0..5).map(|n| create_test_note(2000 + n, "note", false)).collect();
+        app.update(Action::NotesLoaded { mr_id: 2, notes: notes_20 });
+        assert_eq!(app.tracked_mrs[1].selected_note_index, 0);
+        assert!(app.pending_note_restore.is_some());
+
+        let notes_10: Vec<Note> = (0..5).map(|n| create_test_note(1000 + n, "note", false)).collect();
+        app.update(Action::NotesLoaded { mr_id: 1, notes: notes_10 });
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 3);
+        assert!(app.pending_note_restore.is_none());
+    }
+
+    #[test]
+    fn test_label_filter_skips_unlabeled_mrs() {
+        let mut app = App::new(123, None, false, 1);
+        app.label_filter = vec!["bug".to_string()];
+
+        let mut labeled = create_test_mr(1, 10, "Fix crash");
+        labeled.labels = vec!["Bug".to_string(), "ui".to_string()];
+        let mut other = create_test_mr(2, 20, "Add feature");
+        other.labels = vec!["feature".to_string()];
+        let unlabeled = create_test_mr(3, 30, "Refactor");
+
+        app.update(Action::MergeRequestsLoaded(vec![labeled, other, unlabeled]));
+        let iids: Vec<u64> = app.tracked_mrs.iter().map(|tmr| tmr.mr.iid).collect();
+        assert_eq!(iids, vec![10]);
+        // Filtered out for now, not dismissed
+        assert!(!app.tracked_state.is_removed(123, 20));
+    }
+
+    #[test]
+    fn test_merge_requests_loaded_fetches_pipelines_for_all() {
+        let mut app = App::new(123, None, false, 1);
+        let mrs = (1..=4).map(|i| create_test_mr(i, i * 10, "MR")).collect();
+
+        let effects = match app.update(Action::MergeRequestsLoaded(mrs)) {
+            Some(Effect::Batch(effects)) => effects,
+            other => panic!("Expected a batch, got {:?}", other),
+        };
+        let fetches: Vec<(u64, u64)> = effects
+            .iter()
+            .map(|effect| match effect {
+                Effect::FetchPipelines { mr_id, mr_iid, .. } => (*mr_id, *mr_iid),
+                other => panic!("Expected FetchPipelines, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(fetches, vec![(1, 10), (2, 20), (3, 30), (4, 40)]);
+        // Pipelines plus approvals for each MR
+        assert_eq!(app.in_flight_fetches, 8);
+    }
+
     #[test]
     fn test_pipelines_loaded() {
         let mut app = App::new(123, None, false, 1);
 
         let mr = create_test_mr(1, 10, "Test MR");
-        app.tracked_mrs.push(TrackedMergeRequest {
-            mr,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
-            loading: true,
-            error: None,
-        });
+        app.tracked_mrs.push(TrackedMergeRequest::new(mr));
 
         let pipelines = vec![
-            create_test_pipeline(100, PipelineStatus::Success),
-            create_test_pipeline(101, PipelineStatus::Failed),
+            create_test_pipeline(101, PipelineStatus::Success),
+            create_test_pipeline(100, PipelineStatus::Failed),
         ];
 
         app.update(Action::PipelinesLoaded {
-            mr_index: 0,
+            mr_id: 1,
             pipelines,
         });
 
@@ -973,16 +3063,9 @@ mod tests {
         let pipeline = create_test_pipeline(100, PipelineStatus::Running);
 
         app.tracked_mrs.push(TrackedMergeRequest {
-            mr,
             pipelines: vec![pipeline],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr)
         });
 
         let jobs = vec![
@@ -991,7 +3074,7 @@ mod tests {
         ];
 
         app.update(Action::JobsLoaded {
-            mr_index: 0,
+            mr_id: 1,
             pipeline_id: 100,
             jobs,
         });
@@ -1004,6 +3087,57 @@ mod tests {
         assert_eq!(loaded_jobs[1].name, "build"); // Success
     }
 
+    fn sortable_jobs() -> Vec<Job> {
+        vec![
+            staged_job(5, "test", "unit", JobStatus::Success),
+            staged_job(9, "deploy", "review", JobStatus::Manual),
+            staged_job(1, "build", "compile", JobStatus::Success),
+            staged_job(4, "test", "lint", JobStatus::Failed),
+            staged_job(2, "build", "assets", JobStatus::Running),
+            // Created after the deploy job, e.g. a retry, yet still in the test stage
+            staged_job(12, "test", "e2e", JobStatus::Pending),
+        ]
+    }
+
+    fn sorted_job_names(sort: JobSort) -> Vec<String> {
+        let mut jobs = sortable_jobs();
+        sort.sort(&mut jobs);
+        jobs.into_iter().map(|job| job.name).collect()
+    }
+
+    #[test]
+    fn test_job_sort_by_status() {
+        assert_eq!(
+            sorted_job_names(JobSort::Status),
+            vec!["lint", "assets", "e2e", "review", "unit", "compile"]
+        );
+    }
+
+    #[test]
+    fn test_job_sort_by_stage() {
+        assert_eq!(
+            sorted_job_names(JobSort::Stage),
+            vec!["assets", "compile", "e2e", "lint", "unit", "review"]
+        );
+    }
+
+    #[test]
+    fn test_job_sort_by_name() {
+        assert_eq!(
+            sorted_job_names(JobSort::Name),
+            vec!["assets", "compile", "e2e", "lint", "review", "unit"]
+        );
+    }
+
+    #[test]
+    fn test_jobs_loaded_uses_configured_sort() {
+        let mut app = app_with_jobs(Vec::new());
+        app.job_sort = JobSort::Stage;
+        app.update(Action::JobsLoaded { mr_id: 1, pipeline_id: 100, jobs: sortable_jobs() });
+        let names: Vec<&str> = app.tracked_mrs[0].jobs[&100].iter().map(|job| job.name.as_str()).collect();
+        assert_eq!(names, vec!["assets", "compile", "e2e", "lint", "unit", "review"]);
+    }
+
     #[test]
     fn test_api_error() {
         let mut app = App::new(123, None, false, 1);
@@ -1013,6 +3147,129 @@ mod tests {
         assert!(app.status_message.is_none());
     }
 
+    #[test]
+    fn test_refresh_ignored_while_one_is_pending() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(matches!(app.update(Action::Refresh), Some(Effect::RefreshAll { .. })));
+        assert!(app.refresh_in_flight);
+
+        assert!(app.update(Action::Refresh).is_none());
+
+        // An overdue auto-refresh waits as well
+        app.last_auto_refresh = Instant::now() - std::time::Duration::from_secs(120);
+        assert!(app.update(Action::Tick).is_none());
+    }
+
+    #[test]
+    fn test_refresh_allowed_again_after_completion() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::Refresh);
+        app.update(Action::MergeRequestsLoaded(vec![]));
+        assert!(!app.refresh_in_flight);
+        assert!(matches!(app.update(Action::Refresh), Some(Effect::RefreshAll { .. })));
+
+        // Other requests failing don't end it
+        app.update(Action::ApiError("job trace timed out".to_string()));
+        assert!(app.refresh_in_flight);
+        app.update(Action::MoreMergeRequestsLoaded(vec![create_test_mr(1, 10, "Added MR")]));
+        assert!(app.refresh_in_flight);
+        assert_eq!(app.tracked_mrs.len(), 1);
+
+        app.update(Action::RefreshFailed("timed out".to_string()));
+        assert!(!app.refresh_in_flight);
+        assert_eq!(app.error_message.as_deref(), Some("timed out"));
+
+        // The auto-refresh that came due meanwhile fires now
+        app.last_auto_refresh = Instant::now() - std::time::Duration::from_secs(120);
+        assert!(matches!(app.update(Action::Tick), Some(Effect::RefreshAll { .. })));
+    }
+
+    #[test]
+    fn test_time_until_refresh() {
+        let interval = Duration::from_secs(60);
+
+        let remaining = time_until_refresh(Instant::now() - Duration::from_secs(18), interval);
+        assert!(remaining <= Duration::from_secs(42) && remaining > Duration::from_secs(40));
+
+        // Already due: never negative
+        let remaining = time_until_refresh(Instant::now() - Duration::from_secs(120), interval);
+        assert_eq!(remaining, Duration::ZERO);
+        assert_eq!(time_until_refresh(Instant::now() - interval, interval), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_open_url_failure_is_shown() {
+        let mut app = App::new(123, None, false, 1);
+        app.refresh_in_flight = true;
+        app.update(Action::OpenUrlFailed("Couldn't open browser: xdg-open: not found".to_string()));
+        assert_eq!(app.error_message.as_deref(), Some("Couldn't open browser: xdg-open: not found"));
+        // Not an API reply: a pending refresh is unaffected
+        assert!(app.refresh_in_flight);
+    }
+
+    #[test]
+    fn test_mr_api_error_is_scoped_to_its_mr() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Success)],
+        });
+
+        app.update(Action::MrApiError { mr_id: 2, error: "pipelines failed".to_string() });
+        assert!(app.error_message.is_none());
+        assert_eq!(app.tracked_mrs[1].error.as_deref(), Some("pipelines failed"));
+        assert!(!app.tracked_mrs[1].loading);
+        assert!(app.tracked_mrs[0].error.is_none());
+        assert_eq!(app.tracked_mrs[0].pipelines.len(), 1);
+
+        // Reloading that MR's pipelines clears its error
+        app.update(Action::PipelinesLoaded { mr_id: 2, pipelines: vec![] });
+        assert!(app.tracked_mrs[1].error.is_none());
+    }
+
+    #[test]
+    fn test_late_reply_follows_its_mr_after_reorder() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        // MR 1's pipelines are still loading when a refresh moves it to the second tab
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(2, 20, "MR 2"),
+            create_test_mr(1, 10, "MR 1"),
+        ]));
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Success)],
+        });
+        assert_eq!(app.tracked_mrs[1].mr.id, 1);
+        assert_eq!(app.tracked_mrs[1].pipelines.len(), 1);
+        assert!(app.tracked_mrs[0].pipelines.is_empty());
+
+        // Replies for an MR no longer tracked are dropped
+        app.update(Action::SelectMrTab(0));
+        app.update(Action::RemoveCurrentMr);
+        assert!(app.tracked_mrs.iter().all(|tmr| tmr.mr.id != 2));
+        let effect = app.update(Action::PipelinesLoaded {
+            mr_id: 2,
+            pipelines: vec![create_test_pipeline(200, PipelineStatus::Failed)],
+        });
+        assert!(effect.is_none());
+        assert_eq!(app.tracked_mrs[0].pipelines[0].id, 100);
+    }
+
+    #[test]
+    fn test_mr_api_error_for_unknown_mr_is_ignored() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(app.update(Action::MrApiError { mr_id: 4, error: "late".to_string() }).is_none());
+        assert!(app.error_message.is_none());
+    }
+
     #[test]
     fn test_remove_current_mr() {
         let mut app = App::new(123, None, false, 1);
@@ -1021,29 +3278,13 @@ mod tests {
         let mr2 = create_test_mr(2, 20, "MR 2");
 
         app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr1,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr1)
         });
 
         app.tracked_mrs.push(TrackedMergeRequest {
-            mr: mr2,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
             loading: false,
-            error: None,
+            ..TrackedMergeRequest::new(mr2)
         });
 
         assert_eq!(app.tracked_mrs.len(), 2);
@@ -1052,58 +3293,2250 @@ mod tests {
         assert_eq!(app.tracked_mrs[0].mr.title, "MR 2");
     }
 
+    /// Three MRs loaded in the order A, B, C: B updated last and C has a failed pipeline
+    fn app_with_sortable_mrs() -> App {
+        let mut app = App::new(123, None, false, 1);
+        let now = Utc::now();
+        let fixtures = [
+            (1, "A", 3, Some(PipelineStatus::Success)),
+            (2, "B", 1, Some(PipelineStatus::Running)),
+            (3, "C", 2, Some(PipelineStatus::Failed)),
+        ];
+        for (id, title, hours_ago, status) in fixtures {
+            let mut mr = create_test_mr(id, id * 10, title);
+            mr.updated_at = now - chrono::Duration::hours(hours_ago);
+            let pipeline = status.map(|s| Pipeline {
+                updated_at: mr.updated_at,
+                ..create_test_pipeline(id * 100, s)
+            });
+            app.tracked_mrs.push(TrackedMergeRequest {
+                pipelines: pipeline.into_iter().collect(),
+                loading: false,
+                ..TrackedMergeRequest::new(mr)
+            });
+        }
+        app
+    }
+
+    fn display_titles(app: &App) -> Vec<&str> {
+        app.mr_display_order()
+            .into_iter()
+            .map(|i| app.tracked_mrs[i].mr.title.as_str())
+            .collect()
+    }
+
     #[test]
-    fn test_get_selected_mr() {
+    fn test_cycle_mr_state_refetches_in_new_state() {
         let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "Open MR")]));
+        assert_eq!(app.mr_state, MrStateFilter::Opened);
 
-        assert!(app.get_selected_mr().is_none());
+        match app.update(Action::CycleMrState) {
+            Some(Effect::RefreshAll { state, .. }) => assert_eq!(state, MrStateFilter::Merged),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+        assert_eq!(app.mr_state, MrStateFilter::Merged);
+        assert!(app.tracked_mrs.is_empty());
+        assert!(app.refresh_in_flight);
 
-        let mr = create_test_mr(1, 10, "Test MR");
-        app.tracked_mrs.push(TrackedMergeRequest {
-            mr,
-            pipelines: vec![],
-            jobs: HashMap::new(),
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
-            loading: false,
-            error: None,
-        });
+        // Later refreshes keep listing merged MRs
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(2, 11, "Merged MR")]));
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { state, .. }) => assert_eq!(state, MrStateFilter::Merged),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+    }
 
-        let selected = app.get_selected_mr();
-        assert!(selected.is_some());
-        assert_eq!(selected.unwrap().mr.title, "Test MR");
+    #[test]
+    fn test_apply_settings_updates_interval_and_theme() {
+        let mut app = App::new(123, None, false, 1);
+        assert_eq!(app.theme, Theme::dark());
+
+        let settings: Settings = toml::from_str(
+            r#"
+            [gitlab]
+            token = "test-token"
+
+            [app]
+            auto_refresh_interval_minutes = 5
+
+            [ui]
+            theme = "light"
+            job_sort = "name"
+        "#,
+        )
+        .unwrap();
+        app.apply_settings(&settings);
+
+        assert_eq!(app.auto_refresh_interval_minutes, 5);
+        assert_eq!(app.auto_refresh_interval(), Duration::from_secs(300));
+        assert_eq!(app.theme, Theme::light());
+        assert_eq!(app.job_sort, JobSort::Name);
     }
 
     #[test]
-    fn test_get_selected_jobs() {
+    fn test_config_reload_reports_outcome() {
         let mut app = App::new(123, None, false, 1);
+        assert!(matches!(app.update(Action::ReloadConfig), Some(Effect::ReloadConfig)));
+        assert!(app.update(Action::ConfigReloadFailed("expected `=`".to_string())).is_none());
+        assert_eq!(app.error_message.as_deref(), Some("Config reload failed: expected `=`"));
+
+        let settings: Settings = toml::from_str("[gitlab]\ntoken = \"test-token\"").unwrap();
+        let reloaded = Action::ConfigReloaded { settings: Box::new(settings), client: None };
+        assert!(app.update(reloaded).is_none());
+        assert!(app.error_message.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Config reloaded"));
+    }
 
-        let mr = create_test_mr(1, 10, "Test MR");
-        let pipeline = create_test_pipeline(100, PipelineStatus::Running);
-        let job = create_test_job(200, "test-job", JobStatus::Success);
+    #[test]
+    fn test_config_reload_with_new_connection_refetches_mrs() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "Old instance MR")]));
 
-        let mut jobs_map = HashMap::new();
-        jobs_map.insert(100, vec![job]);
+        let settings: Settings = toml::from_str("[gitlab]\ntoken = \"new-token\"").unwrap();
+        let client = GitLabClient::new("https://gitlab.example.com", "new-token").unwrap();
+        let reloaded = Action::ConfigReloaded { settings: Box::new(settings), client: Some(Box::new(client)) };
+        assert!(matches!(app.update(reloaded), Some(Effect::RefreshAll { .. })));
+        assert!(app.tracked_mrs.is_empty());
+        assert!(app.refresh_in_flight);
+    }
 
-        app.tracked_mrs.push(TrackedMergeRequest {
-            mr,
-            pipelines: vec![pipeline],
-            jobs: jobs_map,
-            job_logs_cache: HashMap::new(),
-            notes: Vec::new(),
-            notes_loaded: false,
-            selected_pipeline_index: 0,
-            selected_note_index: 0,
-            loading: false,
-            error: None,
-        });
+    #[test]
+    fn test_config_reload_drops_refresh_sent_with_old_connection() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::Refresh);
 
-        let jobs = app.get_selected_jobs();
-        assert!(jobs.is_some());
-        assert_eq!(jobs.unwrap().len(), 1);
-        assert_eq!(jobs.unwrap()[0].name, "test-job");
+        let settings: Settings = toml::from_str("[gitlab]\ntoken = \"new-token\"").unwrap();
+        let client = GitLabClient::new("https://gitlab.example.com", "new-token").unwrap();
+        let reloaded = Action::ConfigReloaded { settings: Box::new(settings), client: Some(Box::new(client)) };
+        assert!(app.update(reloaded).is_none());
+
+        // The old connection's list is dropped and the refresh sent again
+        let effect = app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "Old instance MR")]));
+        assert!(matches!(effect, Some(Effect::RefreshAll { .. })));
+        assert!(app.tracked_mrs.is_empty());
+        assert!(app.refresh_in_flight);
+
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(2, 20, "New instance MR")]));
+        assert_eq!(app.tracked_mrs[0].mr.title, "New instance MR");
+        assert!(!app.refresh_in_flight);
+    }
+
+    #[test]
+    fn test_cycle_mr_state_waits_for_running_refresh() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::Refresh);
+        assert!(app.update(Action::CycleMrState).is_none());
+        assert_eq!(app.mr_state, MrStateFilter::Opened);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Refresh in progress, try again when it finishes")
+        );
+
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "Open MR")]));
+        assert!(matches!(app.update(Action::CycleMrState), Some(Effect::RefreshAll { .. })));
+        assert_eq!(app.mr_state, MrStateFilter::Merged);
+    }
+
+    #[test]
+    fn test_cycle_mr_state_wraps_around() {
+        let mut app = App::new(123, None, false, 1);
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            app.update(Action::CycleMrState);
+            app.update(Action::MergeRequestsLoaded(vec![]));
+            seen.push(app.mr_state);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                MrStateFilter::Merged,
+                MrStateFilter::Closed,
+                MrStateFilter::All,
+                MrStateFilter::Opened,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mr_sort_orders() {
+        let mut app = app_with_sortable_mrs();
+        assert_eq!(app.mr_sort, MrSort::LoadOrder);
+        assert_eq!(display_titles(&app), vec!["A", "B", "C"]);
+
+        app.update(Action::CycleMrSort);
+        assert_eq!(app.mr_sort, MrSort::UpdatedAt);
+        assert_eq!(display_titles(&app), vec!["B", "C", "A"]);
+
+        app.update(Action::CycleMrSort);
+        assert_eq!(app.mr_sort, MrSort::PipelineStatus);
+        assert_eq!(display_titles(&app), vec!["C", "B", "A"]);
+
+        app.update(Action::CycleMrSort);
+        assert_eq!(app.mr_sort, MrSort::LoadOrder);
+    }
+
+    #[test]
+    fn test_updated_at_sort_follows_reloaded_mrs_and_new_pipelines() {
+        let mut app = app_with_sortable_mrs();
+        app.mr_sort = MrSort::UpdatedAt;
+        assert_eq!(display_titles(&app), vec!["B", "C", "A"]);
+
+        // A by-IID reload brings the MR's new update time
+        let mut reloaded = app.tracked_mrs[0].mr.clone();
+        reloaded.updated_at = Utc::now();
+        app.update(Action::MoreMergeRequestsLoaded(vec![reloaded]));
+        assert_eq!(app.tracked_mrs.len(), 3);
+        assert_eq!(display_titles(&app), vec!["A", "B", "C"]);
+
+        // So does a pipeline that ran since
+        app.tracked_mrs[2].pipelines[0].updated_at = Utc::now() + chrono::Duration::minutes(1);
+        assert_eq!(display_titles(&app), vec!["C", "A", "B"]);
+    }
+
+    #[test]
+    fn test_mr_sort_without_pipeline_sorts_last() {
+        let mut app = app_with_sortable_mrs();
+        app.tracked_mrs[2].pipelines.clear();
+        app.mr_sort = MrSort::PipelineStatus;
+        assert_eq!(display_titles(&app), vec!["B", "A", "C"]);
+    }
+
+    #[test]
+    fn test_mr_sort_keeps_selection_and_navigates_in_display_order() {
+        let mut app = app_with_sortable_mrs();
+        app.selected_mr_index = 0; // A
+
+        app.update(Action::CycleMrSort); // B, C, A
+        assert_eq!(app.get_selected_mr().unwrap().mr.title, "A");
+
+        app.update(Action::NextMr);
+        assert_eq!(app.get_selected_mr().unwrap().mr.title, "B");
+        app.update(Action::NextMr);
+        assert_eq!(app.get_selected_mr().unwrap().mr.title, "C");
+        app.update(Action::PrevMr);
+        assert_eq!(app.get_selected_mr().unwrap().mr.title, "B");
+        app.update(Action::PrevMr);
+        assert_eq!(app.get_selected_mr().unwrap().mr.title, "A");
+    }
+
+    #[test]
+    fn test_removed_mr_stays_removed_on_refresh() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        // Listed MRs come back with the list, so they aren't saved
+        assert!(app.take_tracked_state_update().is_none());
+
+        app.update(Action::RemoveCurrentMr);
+        let saved = app.take_tracked_state_update().unwrap();
+        assert!(saved.is_removed(123, 10));
+        assert!(saved.tracked_iids(123).is_empty());
+
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        assert_eq!(app.tracked_mrs.len(), 1);
+        assert_eq!(app.tracked_mrs[0].mr.iid, 20);
+        assert!(app.take_tracked_state_update().is_none());
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_mr_by_iid() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        app.update(Action::NextMr);
+        assert_eq!(app.get_selected_mr().unwrap().mr.iid, 20);
+
+        // The refreshed list moves the selected MR to the front
+        app.update(Action::Refresh);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(2, 20, "MR 2"),
+            create_test_mr(3, 30, "MR 3"),
+            create_test_mr(1, 10, "MR 1"),
+        ]));
+        let iids: Vec<u64> = app.tracked_mrs.iter().map(|tmr| tmr.mr.iid).collect();
+        assert_eq!(iids, vec![20, 30, 10]);
+        assert_eq!(app.selected_mr_index, 0);
+        assert_eq!(app.get_selected_mr().unwrap().mr.iid, 20);
+
+        // MRs added by IID keep their tabs, after the listed ones
+        app.update(Action::MoreMergeRequestsLoaded(vec![create_test_mr(4, 40, "MR 4")]));
+        app.update(Action::NextMr);
+        app.update(Action::Refresh);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(3, 30, "MR 3"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        let iids: Vec<u64> = app.tracked_mrs.iter().map(|tmr| tmr.mr.iid).collect();
+        assert_eq!(iids, vec![10, 30, 20, 40]);
+        assert_eq!(app.get_selected_mr().unwrap().mr.iid, 30);
+    }
+
+    #[test]
+    fn test_restore_saved_mrs_missing_from_first_load() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_state.mark_tracked(123, 10);
+        app.tracked_state.mark_tracked(123, 30);
+
+        let effect = app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "MR 1")]));
+        match effect {
+            Some(Effect::FetchMergeRequestsByIids { project_id, iids }) => {
+                assert_eq!(project_id, 123);
+                assert_eq!(iids, vec![30]);
+            }
+            other => panic!("Expected FetchMergeRequestsByIids, got {:?}", other),
+        }
+
+        // The restored MR arrives and pipelines are fetched as usual
+        let effect = app.update(Action::MergeRequestsByIidsLoaded {
+            project_id: 123,
+            iids: vec![30],
+            mrs: vec![create_test_mr(3, 30, "MR 3")],
+        });
+        assert!(matches!(effect, Some(Effect::Batch(effects)) if effects.len() == 2));
+        assert_eq!(app.tracked_mrs.len(), 2);
+    }
+
+    #[test]
+    fn test_no_restore_with_assignee_or_reviewer_filter() {
+        let mut app = App::new(123, None, false, 1);
+        app.user_filter = true;
+        app.tracked_state.mark_tracked(123, 30);
+
+        // !30 was saved in an unfiltered session and may not be assigned to this user
+        let effect = app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "MR 1")]));
+        assert!(matches!(effect, Some(Effect::FetchPipelines { mr_iid: 10, .. })));
+        assert_eq!(app.tracked_state.tracked_iids(123), &[30]);
+    }
+
+    #[test]
+    fn test_saved_mrs_not_returned_are_forgotten() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_state.mark_tracked(123, 30);
+        app.tracked_state.mark_tracked(123, 40);
+
+        match app.update(Action::MergeRequestsLoaded(Vec::new())) {
+            Some(Effect::FetchMergeRequestsByIids { iids, .. }) => assert_eq!(iids, vec![30, 40]),
+            other => panic!("Expected FetchMergeRequestsByIids, got {:?}", other),
+        }
+        // !40 was merged since: only !30 comes back
+        app.update(Action::MergeRequestsByIidsLoaded {
+            project_id: 123,
+            iids: vec![30, 40],
+            mrs: vec![create_test_mr(3, 30, "MR 3")],
+        });
+        assert_eq!(app.tracked_mrs.len(), 1);
+        assert_eq!(app.take_tracked_state_update().unwrap().tracked_iids(123), &[30]);
+    }
+
+    #[test]
+    fn test_add_mr_fetches_and_saves() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_state.mark_removed(123, 40);
+
+        match app.update(Action::AddMr(40)) {
+            Some(Effect::FetchMergeRequestsByIids { iids, .. }) => assert_eq!(iids, vec![40]),
+            other => panic!("Expected FetchMergeRequestsByIids, got {:?}", other),
+        }
+        let saved = app.take_tracked_state_update().unwrap();
+        assert!(!saved.is_removed(123, 40));
+        assert_eq!(saved.tracked_iids(123), &[40]);
+    }
+
+    #[test]
+    fn test_restore_removed_mr() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::MergeRequestsLoaded(vec![
+            create_test_mr(1, 10, "MR 1"),
+            create_test_mr(2, 20, "MR 2"),
+        ]));
+        app.update(Action::RemoveCurrentMr);
+        assert!(app.tracked_state.is_removed(123, 10));
+
+        match app.update(Action::RestoreRemovedMr) {
+            Some(Effect::FetchMergeRequestsByIids { iids, .. }) => assert_eq!(iids, vec![10]),
+            other => panic!("Expected FetchMergeRequestsByIids, got {:?}", other),
+        }
+        assert!(!app.tracked_state.is_removed(123, 10));
+        app.update(Action::MergeRequestsByIidsLoaded {
+            project_id: 123,
+            iids: vec![10],
+            mrs: vec![create_test_mr(1, 10, "MR 1")],
+        });
+        assert_eq!(app.tracked_mrs.len(), 2);
+
+        assert!(app.update(Action::RestoreRemovedMr).is_none());
+        assert_eq!(app.status_message.as_deref(), Some("No removed MR to restore"));
+    }
+
+    #[test]
+    fn test_restore_removed_mr_in_group_mode() {
+        let mut app = App::new(0, None, false, 1);
+        app.group_id = Some(7);
+        let mut mr = create_test_mr(1, 10, "MR in project 100");
+        mr.project_id = Some(100);
+        app.update(Action::MergeRequestsLoaded(vec![mr.clone()]));
+        app.update(Action::RemoveCurrentMr);
+        assert!(app.tracked_state.is_removed(100, 10));
+
+        // Fetched from the MR's own project, not the group's placeholder project 0
+        match app.update(Action::RestoreRemovedMr) {
+            Some(Effect::FetchMergeRequestsByIids { project_id, iids }) => {
+                assert_eq!((project_id, iids), (100, vec![10]));
+            }
+            other => panic!("Expected FetchMergeRequestsByIids, got {:?}", other),
+        }
+        app.update(Action::MergeRequestsByIidsLoaded { project_id: 100, iids: vec![10], mrs: vec![mr] });
+        assert_eq!(app.tracked_mrs.len(), 1);
+
+        assert!(app.update(Action::AddMr(10)).is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Adding an MR by IID needs a project, not a group"));
+    }
+
+    #[test]
+    fn test_quit_saves_tracked_state() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::Quit);
+        assert!(app.take_tracked_state_update().is_some());
+    }
+
+    #[test]
+    fn test_get_selected_mr() {
+        let mut app = App::new(123, None, false, 1);
+
+        assert!(app.get_selected_mr().is_none());
+
+        let mr = create_test_mr(1, 10, "Test MR");
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(mr)
+        });
+
+        let selected = app.get_selected_mr();
+        assert!(selected.is_some());
+        assert_eq!(selected.unwrap().mr.title, "Test MR");
+    }
+
+    #[test]
+    fn test_get_selected_jobs() {
+        let mut app = App::new(123, None, false, 1);
+
+        let mr = create_test_mr(1, 10, "Test MR");
+        let pipeline = create_test_pipeline(100, PipelineStatus::Running);
+        let job = create_test_job(200, "test-job", JobStatus::Success);
+
+        let mut jobs_map = HashMap::new();
+        jobs_map.insert(100, vec![job]);
+
+        app.tracked_mrs.push(TrackedMergeRequest {
+            pipelines: vec![pipeline],
+            jobs: jobs_map,
+            loading: false,
+            ..TrackedMergeRequest::new(mr)
+        });
+
+        let jobs = app.get_selected_jobs();
+        assert!(jobs.is_some());
+        assert_eq!(jobs.unwrap().len(), 1);
+        assert_eq!(jobs.unwrap()[0].name, "test-job");
+    }
+
+    #[test]
+    fn test_scroll_comments() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+
+        let notes = (0..5).map(|i| create_test_note(i, "comment", false)).collect();
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes,
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        app.update(Action::ScrollCommentsDown);
+        app.update(Action::ScrollCommentsDown);
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 2);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 2);
+
+        // Offset is clamped to the last comment
+        for _ in 0..10 {
+            app.update(Action::ScrollCommentsDown);
+        }
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 4);
+
+        // Moving the selection above the viewport pulls the offset up with it
+        app.update(Action::PrevNote);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 3);
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 3);
+
+        app.update(Action::ScrollCommentsUp);
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 2);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 2);
+    }
+
+    #[test]
+    fn test_scroll_comments_after_selection_moved_the_view() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        let notes = (0..8).map(|i| create_test_note(i, "comment", false)).collect();
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes,
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        // j down to the sixth comment; the list scrolled to show it, the stored offset didn't
+        for _ in 0..5 {
+            app.update(Action::NextNote);
+        }
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 0);
+
+        // J continues from there instead of jumping back to the top
+        app.update(Action::ScrollCommentsDown);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 6);
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 6);
+
+        app.update(Action::ScrollCommentsUp);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 5);
+        assert_eq!(app.tracked_mrs[0].comments_scroll_offset, 5);
+    }
+
+    #[test]
+    fn test_toggle_compact_comments() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(!app.compact_comments);
+
+        // Only toggles while viewing comments
+        app.update(Action::ToggleCompactComments);
+        assert!(!app.compact_comments);
+
+        app.mode = AppMode::ViewingComments;
+        app.update(Action::ToggleCompactComments);
+        assert!(app.compact_comments);
+        app.update(Action::ToggleCompactComments);
+        assert!(!app.compact_comments);
+    }
+
+    #[test]
+    fn test_is_highlighted_author() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(!app.is_highlighted_author("alice"));
+
+        app.highlight_authors = vec!["alice".to_string(), "Bob".to_string()];
+        assert!(app.is_highlighted_author("alice"));
+        assert!(app.is_highlighted_author("bob"));
+        assert!(!app.is_highlighted_author("carol"));
+        assert!(!app.is_highlighted_author("ali"));
+    }
+
+    #[test]
+    fn test_thread_navigation_skips_replies() {
+        let in_thread = |id: u64, thread: &str| Note {
+            discussion_id: Some(thread.to_string()),
+            ..create_test_note(id, "reply", false)
+        };
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes: vec![
+                in_thread(1, "a"),
+                in_thread(2, "a"),
+                in_thread(3, "a"),
+                create_test_note(4, "standalone", false),
+                in_thread(5, "b"),
+                in_thread(6, "b"),
+            ],
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        app.update(Action::NextThread);
+        assert_eq!(app.get_selected_note_id(), Some(4));
+        app.update(Action::NextThread);
+        assert_eq!(app.get_selected_note_id(), Some(5));
+        app.update(Action::NextThread);
+        assert_eq!(app.get_selected_note_id(), Some(1)); // Wraps around
+
+        // Replies are still reachable one by one
+        app.update(Action::NextNote);
+        assert_eq!(app.get_selected_note_id(), Some(2));
+        // From a reply, back goes to the previous thread, not the current one's start
+        app.update(Action::PrevThread);
+        assert_eq!(app.get_selected_note_id(), Some(5));
+        app.update(Action::PrevThread);
+        assert_eq!(app.get_selected_note_id(), Some(4));
+
+        let notes = app.get_visible_notes();
+        let replies: Vec<bool> = (0..notes.len()).map(|i| is_thread_reply(&notes, i)).collect();
+        assert_eq!(replies, vec![false, true, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_toggle_system_notes_changes_navigable_count() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes: vec![
+                create_test_note(1, "first", false),
+                create_test_note(2, "added label", true),
+                create_test_note(3, "second", false),
+            ],
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        assert_eq!(app.get_visible_notes().len(), 2);
+        app.update(Action::NextNote);
+        app.update(Action::NextNote);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 0); // Wrapped over 2 notes
+
+        app.update(Action::ToggleSystemNotes);
+        assert!(app.show_system_notes);
+        assert_eq!(app.get_visible_notes().len(), 3);
+        app.update(Action::NextNote);
+        app.update(Action::NextNote);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 2);
+    }
+
+    #[test]
+    fn test_selected_note_maps_to_displayed_item() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes: vec![
+                create_test_note(1, "first", false),
+                create_test_note(2, "added label", true),
+                create_test_note(3, "second", false),
+            ],
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        // With system notes hidden, the second displayed item is note 3
+        app.update(Action::NextNote);
+        let index = app.tracked_mrs[0].selected_note_index;
+        assert_eq!(app.get_visible_notes()[index].id, 3);
+        assert_eq!(app.get_selected_note_id(), Some(3));
+
+        // Showing system notes keeps note 3 selected at its new display position
+        app.update(Action::ToggleSystemNotes);
+        let index = app.tracked_mrs[0].selected_note_index;
+        assert_eq!(index, 2);
+        assert_eq!(app.get_visible_notes()[index].id, 3);
+        assert_eq!(app.get_selected_note_id(), Some(3));
+
+        app.update(Action::PrevNote);
+        assert_eq!(app.get_selected_note_id(), Some(2));
+    }
+
+    fn app_viewing_comments(bodies: &[&str]) -> App {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingComments;
+        app.tracked_mrs.push(TrackedMergeRequest {
+            notes: bodies
+                .iter()
+                .enumerate()
+                .map(|(index, body)| create_test_note(index as u64 + 1, body, false))
+                .collect(),
+            notes_loaded: true,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+        app
+    }
+
+    #[test]
+    fn test_open_and_close_selected_note_keeps_selection() {
+        let mut app = app_viewing_comments(&["first", "second\nwith\nmore\nlines", "third"]);
+        app.update(Action::NextNote);
+
+        app.update(Action::OpenSelectedNote);
+        assert_eq!(app.mode, AppMode::ViewingNote);
+        assert!(app.is_viewing_comments()); // The list stays behind the comment
+        let body: Vec<String> = app.log_processed_lines.iter().map(|line| line.to_string()).collect();
+        assert!(body.ends_with(&["second", "with", "more", "lines"].map(String::from)));
+
+        app.update(Action::ScrollLogDown);
+        assert_eq!(app.log_scroll_offset, 1);
+        // Comment navigation doesn't apply while one is open
+        app.update(Action::NextNote);
+        app.update(Action::CloseLogViewer);
+        assert_eq!(app.mode, AppMode::ViewingComments);
+        assert_eq!(app.tracked_mrs[0].selected_note_index, 1);
+        assert!(app.log_processed_lines.is_empty());
+        assert_eq!(app.log_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_open_selected_note_needs_a_note() {
+        let mut app = app_viewing_comments(&[]);
+        assert!(app.update(Action::OpenSelectedNote).is_none());
+        assert_eq!(app.mode, AppMode::ViewingComments);
+
+        let mut app = app_viewing_comments(&["only"]);
+        app.mode = AppMode::Normal;
+        app.update(Action::OpenSelectedNote);
+        assert_eq!(app.mode, AppMode::Normal);
+    }
+
+    #[test]
+    fn test_toggle_description_view() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+
+        app.update(Action::ToggleDescriptionView);
+        assert!(app.is_viewing_description());
+
+        app.update(Action::ScrollDescriptionDown);
+        app.update(Action::ScrollDescriptionDown);
+        app.update(Action::ScrollDescriptionUp);
+        assert_eq!(app.description_scroll, 1);
+
+        app.update(Action::ToggleDescriptionView);
+        assert_eq!(app.mode, AppMode::Normal);
+
+        // Reopening starts from the top again
+        app.update(Action::ToggleDescriptionView);
+        assert_eq!(app.description_scroll, 0);
+    }
+
+    fn diff_file(path: &str, diff: &str) -> DiffFile {
+        DiffFile {
+            old_path: path.to_string(),
+            new_path: path.to_string(),
+            diff: diff.to_string(),
+            new_file: false,
+            renamed_file: false,
+            deleted_file: false,
+        }
+    }
+
+    #[test]
+    fn test_view_mr_changes_enter_scroll_and_leave() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+
+        match app.update(Action::ViewMrChanges) {
+            Some(Effect::FetchMrChanges { mr_id: 1, project_id: 123, mr_iid: 10 }) => {}
+            other => panic!("Expected FetchMrChanges, got {:?}", other),
+        }
+        assert_eq!(app.mode, AppMode::Normal);
+
+        app.update(Action::MrChangesLoaded {
+            mr_id: 1,
+            changes: vec![
+                diff_file("src/lib.rs", "@@ -1 +1 @@\n-old\n+new\n"),
+                diff_file("README.md", "@@ -1 +1,2 @@\n a\n+b\n"),
+            ],
+        });
+        assert_eq!(app.mode, AppMode::ViewingDiff);
+        assert!(app.is_viewing_lines());
+        // Two headers, six diff lines and a blank line between the files
+        assert_eq!(app.log_processed_lines.len(), 9);
+
+        // Scrolling goes through the log viewer's actions
+        app.update(Action::ScrollLogDown);
+        app.update(Action::ScrollLogDown);
+        assert_eq!(app.log_scroll_offset, 2);
+        app.update(Action::ScrollLogEnd);
+        assert_eq!(app.log_scroll_offset, 8);
+
+        app.update(Action::CloseLogViewer);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.diff_files.is_empty());
+        assert!(app.log_processed_lines.is_empty());
+        assert_eq!(app.log_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_mr_changes_ignored_after_switching_mr() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "First")));
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(2, 20, "Second")));
+
+        app.update(Action::ViewMrChanges);
+        app.update(Action::NextMr);
+        app.update(Action::MrChangesLoaded { mr_id: 1, changes: vec![diff_file("a.rs", "+x\n")] });
+        assert_eq!(app.mode, AppMode::Normal);
+
+        // An MR without changes doesn't open an empty viewer
+        app.update(Action::MrChangesLoaded { mr_id: 2, changes: Vec::new() });
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("This MR has no changes"));
+    }
+
+    fn log_text(app: &App) -> Vec<String> {
+        app.log_processed_lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_toggle_raw_log_keeps_gitlab_prefixes() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+        app.update(Action::JobTraceLoaded {
+            job_id: 200,
+            job_name: "test".to_string(),
+            trace: "2026-01-12T10:35:38.187431Z 00O Running tests\n".to_string(),
+        });
+        assert_eq!(log_text(&app), vec!["Running tests"]);
+
+        app.update(Action::ToggleRawLog);
+        assert!(app.raw_log);
+        assert_eq!(log_text(&app), vec!["2026-01-12T10:35:38.187431Z 00O Running tests"]);
+
+        // Timestamp modes don't apply to the raw log
+        app.update(Action::ToggleTimestampMode);
+        assert_eq!(log_text(&app), vec!["2026-01-12T10:35:38.187431Z 00O Running tests"]);
+
+        app.update(Action::ToggleRawLog);
+        assert_eq!(log_text(&app), vec!["2026-01-12 Running tests"]);
+    }
+
+    #[test]
+    fn test_raw_log_applies_to_next_opened_log() {
+        let mut app = App::new(123, None, false, 1);
+        app.raw_log = true;
+        app.update(Action::JobTraceLoaded {
+            job_id: 201,
+            job_name: "build".to_string(),
+            trace: "00E \x1b[31mfailed\x1b[0m\n".to_string(),
+        });
+        // ANSI colors are still parsed, the prefix is kept
+        assert_eq!(log_text(&app), vec!["00E failed"]);
+
+        // Outside the log viewer the toggle does nothing
+        app.update(Action::CloseLogViewer);
+        app.update(Action::ToggleRawLog);
+        assert!(app.raw_log);
+    }
+
+    #[test]
+    fn test_search_wrap_sets_transient_message() {
+        let mut app = app_viewing_log("match 1\nother\nmatch 2\n");
+        app.search_query = "match".to_string();
+        app.update(Action::ExecuteSearch);
+        app.update(Action::NextSearchResult);
+        assert_eq!(app.current_search_result, 1);
+        assert_ne!(app.status_message.as_deref(), Some("Search wrapped to top"));
+
+        app.update(Action::NextSearchResult);
+        assert_eq!(app.current_search_result, 0);
+        assert_eq!(app.status_message.as_deref(), Some("Search wrapped to top"));
+
+        // Gone on the next step that doesn't wrap
+        app.update(Action::NextSearchResult);
+        assert_eq!(app.current_search_result, 1);
+        assert!(app.status_message.is_none());
+
+        app.update(Action::PrevSearchResult);
+        assert!(app.status_message.is_none());
+        app.update(Action::PrevSearchResult);
+        assert_eq!(app.current_search_result, 1);
+        assert_eq!(app.status_message.as_deref(), Some("Search wrapped to bottom"));
+    }
+
+    #[test]
+    fn test_search_matches_displayed_lines() {
+        let mut trace = String::from("\x1b[0Ksection_start:1700000000:build\r\x1b[0K\n");
+        for i in 0..40 {
+            trace.push_str(&format!("00O step {}\n", i));
+        }
+        trace.push_str("00E \x1b[31mcompile\x1b[0m error\n");
+        trace.push_str(&"00O more\n".repeat(20));
+        let mut app = app_viewing_log(&trace);
+        app.log_viewport_height = 10;
+
+        app.search_query = "compile error".to_string();
+        app.update(Action::ExecuteSearch);
+        assert_eq!(app.search_results, vec![41]);
+        assert_eq!(log_text(&app)[41], "compile error");
+        assert_eq!(app.log_scroll_offset, 41 - 10 / 2);
+
+        // Hidden markers and prefixes don't match
+        app.search_query = "section_start".to_string();
+        app.update(Action::ExecuteSearch);
+        assert!(app.search_results.is_empty());
+
+        // Showing the raw log makes them visible, and an executed search follows
+        app.search_query = "00E".to_string();
+        app.update(Action::ExecuteSearch);
+        assert!(app.search_results.is_empty());
+        app.update(Action::ToggleRawLog);
+        assert_eq!(app.search_results, vec![41]);
+    }
+
+    fn app_viewing_log(trace: &str) -> App {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+        app.update(Action::JobTraceLoaded {
+            job_id: 200,
+            job_name: "test".to_string(),
+            trace: trace.to_string(),
+        });
+        app
+    }
+
+    #[test]
+    fn test_toggle_log_wrap_and_horizontal_scroll() {
+        let mut app = app_viewing_log(&format!("{}\n", "x".repeat(200)));
+        assert!(app.log_wrap);
+
+        app.update(Action::ToggleLogWrap);
+        assert!(!app.log_wrap);
+        app.update(Action::ScrollLogRight);
+        app.update(Action::ScrollLogRight);
+        assert_eq!(app.log_h_scroll, 16);
+
+        // Scrolling left stops at the first column
+        app.update(Action::ScrollLogLeft);
+        app.update(Action::ScrollLogLeft);
+        app.update(Action::ScrollLogLeft);
+        assert_eq!(app.log_h_scroll, 0);
+
+        // Wrapping again resets the sideways offset
+        app.update(Action::ScrollLogRight);
+        app.update(Action::ToggleLogWrap);
+        assert!(app.log_wrap);
+        assert_eq!(app.log_h_scroll, 0);
+
+        // The wrap setting outlives the viewer, the offset doesn't
+        app.update(Action::ToggleLogWrap);
+        app.update(Action::ScrollLogRight);
+        app.update(Action::CloseLogViewer);
+        assert!(!app.log_wrap);
+        assert_eq!(app.log_h_scroll, 0);
+    }
+
+    #[test]
+    fn test_copy_mr_url_and_branch() {
+        let mut app = App::new(123, None, false, 1);
+        let mut mr = create_test_mr(1, 10, "Test MR");
+        mr.web_url = "https://gitlab.com/test/-/merge_requests/10".to_string();
+        mr.source_branch = "feature/copy".to_string();
+        app.tracked_mrs.push(TrackedMergeRequest::new(mr));
+
+        app.update(Action::StartYank);
+        assert!(app.pending_yank);
+
+        let effect = app.update(Action::CopyMrUrl);
+        assert!(matches!(effect, Some(Effect::CopyToClipboard(ref text))
+            if text == "https://gitlab.com/test/-/merge_requests/10"));
+        assert!(!app.pending_yank);
+
+        let effect = app.update(Action::CopyMrBranch);
+        assert!(matches!(effect, Some(Effect::CopyToClipboard(ref text)) if text == "feature/copy"));
+        assert_eq!(app.status_message.as_deref(), Some("Copied branch feature/copy"));
+    }
+
+    #[test]
+    fn test_copy_without_mr_does_nothing() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::StartYank);
+        assert!(!app.pending_yank);
+        assert!(app.update(Action::CopyMrUrl).is_none());
+    }
+
+    #[test]
+    fn test_horizontal_scroll_clamped_to_longest_visible_line() {
+        let long_line = "y".repeat(20);
+        let mut app = app_viewing_log(&format!("short\n{}\n", long_line));
+        app.update(Action::ToggleLogWrap);
+
+        for _ in 0..5 {
+            app.update(Action::ScrollLogRight);
+        }
+        assert_eq!(app.log_h_scroll, 19);
+
+        for _ in 0..5 {
+            app.update(Action::ScrollLogLeft);
+        }
+        assert_eq!(app.log_h_scroll, 0);
+
+        // Only lines in the viewport count towards the bound
+        app.log_viewport_height = 1;
+        app.update(Action::ScrollLogRight);
+        assert_eq!(app.log_h_scroll, 4);
+    }
+
+    #[test]
+    fn test_horizontal_scroll_ignored_while_wrapping() {
+        let mut app = app_viewing_log(&format!("{}\n", "z".repeat(100)));
+        assert!(app.log_wrap);
+
+        app.update(Action::ScrollLogRight);
+        assert_eq!(app.log_h_scroll, 0);
+    }
+
+    #[test]
+    fn test_job_trace_reload_offers_delta_view() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+
+        app.update(Action::JobTraceLoaded {
+            job_id: 200,
+            job_name: "test".to_string(),
+            trace: "line 1\nline 2\n".to_string(),
+        });
+        assert_eq!(app.log_new_content_line, None);
+        app.update(Action::CloseLogViewer);
+
+        // Same job fetched again after a refresh with more output appended
+        app.update(Action::JobTraceLoaded {
+            job_id: 200,
+            job_name: "test".to_string(),
+            trace: "line 1\nline 2\nline 3\n".to_string(),
+        });
+        assert_eq!(app.log_new_content_line, Some(2));
+
+        app.update(Action::ToggleLogDeltaMode);
+        assert!(app.log_delta_mode);
+        assert_eq!(app.log_scroll_offset, 2);
+
+        app.update(Action::CloseLogViewer);
+        assert!(!app.log_delta_mode);
+        assert_eq!(app.seen_log_lengths[&200], "line 1\nline 2\nline 3\n".len());
+    }
+
+    #[test]
+    fn test_refresh_uses_source_branch() {
+        // Not focusing on a branch: refresh fetches all MRs
+        let mut app = App::new(123, Some("feature-10".to_string()), false, 1);
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => assert!(source_branch.is_none()),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // Focused, nothing tracked yet: falls back to the local branch
+        app.refresh_in_flight = false;
+        app.focus_current_branch = true;
+        app.current_branch = Some("Feature-10".to_string());
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("Feature-10"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // Focused with the MR tracked: uses the MR's source branch as reported by GitLab
+        app.refresh_in_flight = false;
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("feature-10"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // An MR with the exact spelling wins over one differing only in case
+        app.refresh_in_flight = false;
+        let mut exact = create_test_mr(2, 20, "Other MR");
+        exact.source_branch = "Feature-10".to_string();
+        app.tracked_mrs.push(TrackedMergeRequest::new(exact));
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("Feature-10"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_refresh_on_default_branch_lists_all_mrs() {
+        let mut app = App::new(123, Some("main".to_string()), true, 1);
+        app.default_branch = Some("main".to_string());
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => assert!(source_branch.is_none()),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // Unless it was asked for with `--branch`
+        app.refresh_in_flight = false;
+        app.branch_from_cli = true;
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => assert_eq!(source_branch.as_deref(), Some("main")),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // Any other branch is still focused
+        app.refresh_in_flight = false;
+        app.branch_from_cli = false;
+        app.current_branch = Some("feature-10".to_string());
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("feature-10"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_retry_preview_selects_failed_and_canceled_jobs() {
+        let jobs = vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit-tests", JobStatus::Failed),
+            create_test_job(3, "deploy", JobStatus::Manual),
+            create_test_job(4, "lint", JobStatus::Canceled),
+            create_test_job(5, "docs", JobStatus::Skipped),
+        ];
+
+        let preview: Vec<&str> = retry_preview(&jobs).iter().map(|job| job.name.as_str()).collect();
+        assert_eq!(preview, vec!["unit-tests", "lint"]);
+    }
+
+    fn app_with_jobs(jobs: Vec<Job>) -> App {
+        let mut app = App::new(123, None, false, 1);
+        let mut jobs_map = HashMap::new();
+        jobs_map.insert(100, jobs);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Failed)],
+            jobs: jobs_map,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+        app
+    }
+
+    #[test]
+    fn test_failed_job_navigation_lands_only_on_failures() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit", JobStatus::Failed),
+            create_test_job(3, "lint", JobStatus::Running),
+            create_test_job(4, "e2e", JobStatus::Failed),
+            create_test_job(5, "docs", JobStatus::Skipped),
+        ]);
+
+        app.update(Action::NextFailedJob);
+        assert_eq!(selected_job_name(&app), "unit");
+        app.update(Action::NextFailedJob);
+        assert_eq!(selected_job_name(&app), "e2e");
+        app.update(Action::NextFailedJob);
+        assert_eq!(selected_job_name(&app), "unit"); // Wraps around
+
+        app.update(Action::PrevFailedJob);
+        assert_eq!(selected_job_name(&app), "e2e"); // Wraps backwards
+        app.update(Action::PrevFailedJob);
+        assert_eq!(selected_job_name(&app), "unit");
+
+        // From a job between failures, each direction finds the nearest one
+        app.selected_job_index = 2;
+        app.update(Action::PrevFailedJob);
+        assert_eq!(selected_job_name(&app), "unit");
+        app.selected_job_index = 2;
+        app.update(Action::NextFailedJob);
+        assert_eq!(selected_job_name(&app), "e2e");
+    }
+
+    #[test]
+    fn test_failed_job_navigation_without_failures_is_noop() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit", JobStatus::Success),
+        ]);
+        app.selected_job_index = 1;
+        app.update(Action::NextFailedJob);
+        assert_eq!(app.selected_job_index, 1);
+        app.update(Action::PrevFailedJob);
+        assert_eq!(app.selected_job_index, 1);
+    }
+
+    #[test]
+    fn test_tail_non_empty_lines() {
+        let trace = "one\ntwo\n\n   \nthree\n\x1b[0K\nfour\n\n";
+        assert_eq!(tail_non_empty_lines(trace, 3), vec!["two", "three", "four"]);
+        // Fewer lines than asked for keeps them all, in order
+        assert_eq!(tail_non_empty_lines(trace, 10), vec!["one", "two", "three", "four"]);
+        assert!(tail_non_empty_lines("\n\n", 5).is_empty());
+        assert!(tail_non_empty_lines(trace, 0).is_empty());
+    }
+
+    #[test]
+    fn test_failure_summary_fetches_uncached_failed_logs() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit", JobStatus::Failed),
+            create_test_job(3, "lint", JobStatus::Failed),
+        ]);
+        app.tracked_mrs[0].job_logs_cache.insert(2, "compiling\nassertion failed\n".to_string());
+
+        let effect = app.update(Action::ShowFailureSummary);
+        match effect {
+            Some(Effect::FetchFailureSummaryTrace { mr_id: 1, job_id: 3, .. }) => {}
+            other => panic!("Expected a trace fetch for job 3, got {:?}", other),
+        }
+        assert_eq!(app.mode, AppMode::ViewingFailureSummary);
+        assert_eq!(app.failure_summary_pending, 1);
+        let text: Vec<String> = app.log_processed_lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(
+            text,
+            vec!["✗ unit (test)", "compiling", "assertion failed", "", "✗ lint (test)", "loading…"]
+        );
+
+        app.update(Action::FailureSummaryTraceLoaded {
+            mr_id: 1,
+            job_id: 3,
+            trace: "warning: unused\n".to_string(),
+        });
+        assert_eq!(app.failure_summary_pending, 0);
+        assert_eq!(app.log_processed_lines.last().unwrap().to_string(), "warning: unused");
+
+        app.update(Action::CloseLogViewer);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.failure_summary_jobs.is_empty());
+    }
+
+    #[test]
+    fn test_failure_summary_shows_failed_trace_fetch() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "unit", JobStatus::Failed),
+            create_test_job(2, "lint", JobStatus::Failed),
+        ]);
+        app.update(Action::ShowFailureSummary);
+        assert_eq!(app.failure_summary_pending, 2);
+
+        app.update(Action::FailureSummaryTraceFailed { job_id: 2, error: "404 Not Found".to_string() });
+        assert_eq!(app.failure_summary_pending, 1);
+        let text: Vec<String> = app.log_processed_lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(
+            text,
+            vec!["✗ unit (test)", "loading…", "", "✗ lint (test)", "failed to load log: 404 Not Found"]
+        );
+
+        app.update(Action::CloseLogViewer);
+        assert!(app.failure_summary_errors.is_empty());
+    }
+
+    #[test]
+    fn test_failure_summary_without_failures_stays_in_jobs_view() {
+        let mut app = app_with_jobs(vec![create_test_job(1, "build", JobStatus::Success)]);
+        assert!(app.update(Action::ShowFailureSummary).is_none());
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("No failed jobs in this pipeline"));
+    }
+
+    /// Two MRs whose latest pipelines each have a failed job; only the first job's log is cached
+    fn app_for_global_search() -> App {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit", JobStatus::Failed),
+        ]);
+        let first = &mut app.tracked_mrs[0];
+        first.job_logs_cache.insert(1, "connection refused\n".to_string());
+        first.job_logs_cache.insert(2, "setup\nERROR: connection refused\nretrying\nConnection refused again\n".to_string());
+
+        let mut jobs_map = HashMap::new();
+        jobs_map.insert(300, vec![create_test_job(4, "e2e", JobStatus::Failed)]);
+        jobs_map.insert(200, vec![create_test_job(3, "old-e2e", JobStatus::Failed)]);
+        let mut second = TrackedMergeRequest {
+            pipelines: vec![
+                create_test_pipeline(300, PipelineStatus::Failed),
+                create_test_pipeline(200, PipelineStatus::Failed),
+            ],
+            jobs: jobs_map,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(2, 20, "Second MR"))
+        };
+        // An older pipeline's failure isn't searched
+        second.job_logs_cache.insert(3, "connection refused\n".to_string());
+        app.tracked_mrs.push(second);
+        app
+    }
+
+    #[test]
+    fn test_tracked_mrs_get_configured_log_cache_limits() {
+        let mut app = App::new(123, None, false, 1);
+        app.log_cache_max_entries = 1;
+        app.update(Action::MergeRequestsLoaded(vec![create_test_mr(1, 10, "Test MR")]));
+
+        let cache = &mut app.tracked_mrs[0].job_logs_cache;
+        cache.insert(1, "first".to_string());
+        cache.insert(2, "second".to_string());
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains_key(2));
+    }
+
+    #[test]
+    fn test_global_search_results_cover_failed_jobs_of_latest_pipelines() {
+        let mut app = app_for_global_search();
+        // Styling splitting the match doesn't hide it
+        app.tracked_mrs[1].job_logs_cache.insert(4, "\x1b[31mconnection\x1b[0m REFUSED\n".to_string());
+
+        let results = global_search_results(&app.tracked_mrs, "connection refused", false);
+        let found: Vec<(u64, &str, usize, &str)> = results
+            .iter()
+            .map(|r| (r.mr_iid, r.job_name.as_str(), r.line_number, r.line.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (10, "unit", 1, "ERROR: connection refused"),
+                (10, "unit", 3, "Connection refused again"),
+                (20, "e2e", 0, "connection REFUSED"),
+            ]
+        );
+
+        assert_eq!(global_search_results(&app.tracked_mrs, "connection refused", true).len(), 1);
+        assert!(global_search_results(&app.tracked_mrs, "", false).is_empty());
+    }
+
+    #[test]
+    fn test_global_search_fetches_missing_logs() {
+        let mut app = app_for_global_search();
+        app.update(Action::StartGlobalSearch);
+        assert_eq!(app.mode, AppMode::GlobalSearch);
+        app.update(Action::UpdateGlobalSearchQuery("refused".to_string()));
+
+        match app.update(Action::ExecuteGlobalSearch) {
+            Some(Effect::FetchGlobalSearchTrace { mr_id, job_id, .. }) => {
+                assert_eq!((mr_id, job_id), (2, 4));
+            }
+            other => panic!("Expected FetchGlobalSearchTrace, got {:?}", other),
+        }
+        assert_eq!(app.global_search_results.len(), 2);
+        assert_eq!(app.global_search_pending, 1);
+
+        app.update(Action::GlobalSearchTraceLoaded {
+            mr_id: 2,
+            job_id: 4,
+            trace: "refused by peer\n".to_string(),
+        });
+        assert_eq!(app.global_search_pending, 0);
+        assert_eq!(app.global_search_results.len(), 3);
+        assert!(app.tracked_mrs[1].job_logs_cache.contains_key(4));
+    }
+
+    #[test]
+    fn test_open_global_search_result_jumps_to_match() {
+        let mut app = app_for_global_search();
+        app.update(Action::StartGlobalSearch);
+        app.update(Action::UpdateGlobalSearchQuery("refused".to_string()));
+        app.update(Action::ExecuteGlobalSearch);
+
+        app.update(Action::NextGlobalSearchResult);
+        app.update(Action::OpenGlobalSearchResult);
+        assert_eq!(app.mode, AppMode::ViewingLog);
+        assert_eq!(app.selected_mr_index, 0);
+        assert_eq!(selected_job_name(&app), "unit");
+        assert_eq!(app.log_job_name.as_deref(), Some("unit"));
+        // The second match is current so n/N continue from it
+        assert_eq!(app.search_query, "refused");
+        assert_eq!(app.search_results, vec![1, 3]);
+        assert_eq!(app.current_search_result, 1);
+    }
+
+    #[test]
+    fn test_open_global_search_result_with_styled_match() {
+        let mut app = app_for_global_search();
+        app.tracked_mrs[1]
+            .job_logs_cache
+            .insert(4, "setup\n\x1b[1mtimed\x1b[0m out\n".to_string());
+        app.update(Action::StartGlobalSearch);
+        app.update(Action::UpdateGlobalSearchQuery("timed out".to_string()));
+        app.update(Action::ExecuteGlobalSearch);
+        assert_eq!(app.global_search_results.len(), 1);
+        assert_eq!(app.global_search_results[0].line, "timed out");
+
+        app.update(Action::OpenGlobalSearchResult);
+        assert_eq!(app.selected_mr_index, 1);
+        assert_eq!(app.search_results, vec![1]);
+        assert_eq!(app.current_search_result, 0);
+    }
+
+    #[test]
+    fn test_global_search_reply_for_removed_mr_is_dropped() {
+        let mut app = app_for_global_search();
+        app.mode = AppMode::GlobalSearch;
+        app.global_search_pending = 1;
+        // The MR the log was fetched for is gone and the other one took its index
+        app.tracked_mrs.remove(0);
+        app.update(Action::GlobalSearchTraceLoaded {
+            mr_id: 1,
+            job_id: 4,
+            trace: "refused\n".to_string(),
+        });
+        assert_eq!(app.global_search_pending, 0);
+        assert!(!app.tracked_mrs[0].job_logs_cache.contains_key(4));
+    }
+
+    fn selected_job_name(app: &App) -> &str {
+        app.get_visible_jobs()[app.selected_job_index].name.as_str()
+    }
+
+    fn job_row_labels(app: &App) -> Vec<String> {
+        app.get_job_rows()
+            .iter()
+            .map(|row| match row {
+                JobRow::Job(job) => job.name.clone(),
+                JobRow::Passed { stage, count } => format!("{} +{} passed", stage, count),
+            })
+            .collect()
+    }
+
+    fn staged_job(id: u64, stage: &str, name: &str, status: JobStatus) -> Job {
+        Job { stage: stage.to_string(), ..create_test_job(id, name, status) }
+    }
+
+    #[test]
+    fn test_collapsed_job_rows_summarize_passed_stages() {
+        let mut app = app_with_jobs(vec![]);
+        app.update(Action::JobsLoaded {
+            mr_id: 1,
+            pipeline_id: 100,
+            jobs: vec![
+                staged_job(1, "build", "compile", JobStatus::Success),
+                staged_job(2, "test", "unit", JobStatus::Success),
+                staged_job(3, "test", "integration", JobStatus::Failed),
+                staged_job(4, "build", "docs", JobStatus::Success),
+                staged_job(5, "test", "e2e", JobStatus::Success),
+                staged_job(6, "deploy", "review", JobStatus::Manual),
+            ],
+        });
+        assert_eq!(job_row_labels(&app).len(), 6);
+
+        app.update(Action::ToggleCollapsePassedJobs);
+        assert_eq!(
+            job_row_labels(&app),
+            vec!["integration", "review", "build +2 passed", "test +2 passed"]
+        );
+        // Only real jobs can be selected and opened
+        assert_eq!(app.get_visible_jobs().len(), 2);
+
+        app.update(Action::ToggleCollapsePassedJobs);
+        assert_eq!(job_row_labels(&app).len(), 6);
+    }
+
+    #[test]
+    fn test_collapsing_keeps_selection_on_visible_job() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "lint", JobStatus::Failed),
+            create_test_job(2, "unit", JobStatus::Success),
+            create_test_job(3, "e2e", JobStatus::Failed),
+        ]);
+        app.selected_job_index = 2;
+        app.update(Action::ToggleCollapsePassedJobs);
+        assert_eq!(selected_job_name(&app), "e2e");
+
+        // A selected passed job disappears; the selection falls back to the first row
+        app.update(Action::ToggleCollapsePassedJobs);
+        app.selected_job_index = 1;
+        app.update(Action::ToggleCollapsePassedJobs);
+        assert_eq!(selected_job_name(&app), "lint");
+
+        // With only passed jobs there is nothing left to select
+        let mut green = app_with_jobs(vec![create_test_job(1, "unit", JobStatus::Success)]);
+        green.update(Action::ToggleCollapsePassedJobs);
+        assert!(green.get_visible_jobs().is_empty());
+        assert_eq!(job_row_labels(&green), vec!["test +1 passed"]);
+    }
+
+    #[test]
+    fn test_open_job_in_browser() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "test", JobStatus::Failed),
+        ]);
+        app.update(Action::NextJob);
+
+        match app.update(Action::OpenJobInBrowser) {
+            Some(Effect::OpenUrl(url)) => assert_eq!(url, "https://gitlab.com/test/-/jobs/2"),
+            other => panic!("Expected OpenUrl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_open_pipeline_in_browser() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            pipelines: vec![
+                create_test_pipeline(101, PipelineStatus::Running),
+                create_test_pipeline(100, PipelineStatus::Failed),
+            ],
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+        app.tracked_mrs[0].selected_pipeline_index = 1;
+
+        match app.update(Action::OpenPipelineInBrowser) {
+            Some(Effect::OpenUrl(url)) => assert_eq!(url, "https://gitlab.com/test/-/pipelines/100"),
+            other => panic!("Expected OpenUrl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_open_pipeline_in_browser_without_pipelines() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR")));
+        assert!(app.update(Action::OpenPipelineInBrowser).is_none());
+    }
+
+    #[test]
+    fn test_download_artifacts_of_selected_job() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "unit tests", JobStatus::Failed),
+        ]);
+        app.artifacts_dir = PathBuf::from("/tmp/artifacts");
+        app.update(Action::NextJob);
+
+        match app.update(Action::DownloadArtifacts) {
+            Some(Effect::DownloadArtifacts { project_id, job_id, job_name, dest }) => {
+                assert_eq!((project_id, job_id), (123, 2));
+                assert_eq!(job_name, "unit tests");
+                assert_eq!(dest, PathBuf::from("/tmp/artifacts/job-2-unit_tests.zip"));
+            }
+            other => panic!("Expected DownloadArtifacts, got {:?}", other),
+        }
+        assert_eq!(app.in_flight_fetches, 1);
+
+        app.update(Action::ArtifactsDownloaded {
+            job_name: "unit tests".to_string(),
+            path: PathBuf::from("/tmp/artifacts/job-2-unit_tests.zip"),
+            bytes: 2048,
+        });
+        assert_eq!(app.in_flight_fetches, 0);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Saved artifacts of 'unit tests' (2.0KB) to /tmp/artifacts/job-2-unit_tests.zip")
+        );
+
+        app.update(Action::ArtifactsDownloadFailed("Job 'build' has no artifacts".to_string()));
+        assert_eq!(app.error_message.as_deref(), Some("Job 'build' has no artifacts"));
+    }
+
+    #[test]
+    fn test_artifacts_file_name_is_sanitized() {
+        assert_eq!(artifacts_file_name(7, "rspec 1/3"), "job-7-rspec_1_3.zip");
+        assert_eq!(artifacts_file_name(8, "build-linux_x86"), "job-8-build-linux_x86.zip");
+    }
+
+    #[test]
+    fn test_clicked_job_row_opens_its_log() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "build", JobStatus::Success),
+            create_test_job(2, "test", JobStatus::Failed),
+        ]);
+
+        match app.update(Action::OpenJobLogAt(1)) {
+            Some(Effect::FetchJobTrace { job_id: 2, .. }) => {}
+            other => panic!("Expected FetchJobTrace, got {:?}", other),
+        }
+        assert_eq!(selected_job_name(&app), "test");
+
+        // A stale click past the visible jobs does nothing
+        assert!(app.update(Action::OpenJobLogAt(5)).is_none());
+        assert_eq!(app.selected_job_index, 1);
+    }
+
+    #[test]
+    fn test_reopened_cached_log_restores_scroll_and_search() {
+        let mut app = app_with_jobs(vec![create_test_job(2, "test", JobStatus::Failed)]);
+        let trace: String = (0..50).map(|i| format!("line {}\n", i)).collect();
+        app.update(Action::OpenSelectedJobLog);
+        app.update(Action::JobTraceLoaded { job_id: 2, job_name: "test".to_string(), trace });
+
+        app.search_query = "line 4".to_string();
+        app.update(Action::ExecuteSearch);
+        app.update(Action::NextSearchResult);
+        app.update(Action::ScrollLogDown);
+        let (offset, result) = (app.log_scroll_offset, app.current_search_result);
+        assert_eq!(result, 1);
+        app.update(Action::CloseLogViewer);
+        assert_eq!(app.log_scroll_offset, 0);
+
+        // Cached, so no fetch; the log opens where it was left
+        assert!(app.update(Action::OpenSelectedJobLog).is_none());
+        assert_eq!(app.log_scroll_offset, offset);
+        assert_eq!(app.search_query, "line 4");
+        assert_eq!(app.current_search_result, result);
+
+        // A refresh drops the positions with the cache
+        app.update(Action::CloseLogViewer);
+        app.update(Action::Refresh);
+        assert!(app.tracked_mrs[0].log_positions.is_empty());
+    }
+
+    #[test]
+    fn test_select_mr_tab_resets_job_view() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(1, 10, "First")));
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(2, 20, "Second")));
+        app.selected_job_index = 3;
+        app.job_status_filter = Some(JobStatus::Failed);
+
+        app.update(Action::SelectMrTab(1));
+        assert_eq!(app.selected_mr_index, 1);
+        assert_eq!(app.selected_job_index, 0);
+        assert_eq!(app.job_status_filter, None);
+
+        app.update(Action::SelectMrTab(7));
+        assert_eq!(app.selected_mr_index, 1);
+    }
+
+    #[test]
+    fn test_open_job_in_browser_without_jobs() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(app.update(Action::OpenJobInBrowser).is_none());
+
+        let mut app = app_with_jobs(vec![]);
+        assert!(app.update(Action::OpenJobInBrowser).is_none());
+    }
+
+    #[test]
+    fn test_job_navigation_skips_filtered_out_jobs() {
+        let mut app = app_with_jobs(vec![
+            create_test_job(1, "lint", JobStatus::Failed),
+            create_test_job(2, "build", JobStatus::Success),
+            create_test_job(3, "unit", JobStatus::Failed),
+            create_test_job(4, "docs", JobStatus::Success),
+        ]);
+
+        app.update(Action::ToggleFailedJobsFilter);
+        assert_eq!(app.job_status_filter, Some(JobStatus::Failed));
+        assert_eq!(selected_job_name(&app), "lint");
+
+        app.update(Action::NextJob);
+        assert_eq!(selected_job_name(&app), "unit");
+        app.update(Action::NextJob);
+        assert_eq!(selected_job_name(&app), "lint");
+        app.update(Action::PrevJob);
+        assert_eq!(selected_job_name(&app), "unit");
+
+        // Clearing the filter restores navigation over every job
+        app.update(Action::ToggleFailedJobsFilter);
+        assert_eq!(app.job_status_filter, None);
+        assert_eq!(app.get_visible_jobs().len(), 4);
+        for name in ["build", "unit", "docs", "lint"] {
+            app.update(Action::NextJob);
+            assert_eq!(selected_job_name(&app), name);
+        }
+    }
+
+    #[test]
+    fn test_job_status_filter_cycle() {
+        let mut app = app_with_jobs(vec![create_test_job(1, "lint", JobStatus::Failed)]);
+
+        let mut seen = Vec::new();
+        for _ in 0..6 {
+            app.update(Action::CycleJobStatusFilter);
+            seen.push(app.job_status_filter.clone());
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some(JobStatus::Failed),
+                Some(JobStatus::Running),
+                Some(JobStatus::Pending),
+                Some(JobStatus::Manual),
+                Some(JobStatus::Success),
+                None,
+            ]
+        );
+
+        // No running jobs: the table is empty and navigation is a no-op
+        app.job_status_filter = Some(JobStatus::Running);
+        assert!(app.get_visible_jobs().is_empty());
+        app.update(Action::NextJob);
+        assert_eq!(app.selected_job_index, 0);
+    }
+
+    #[test]
+    fn test_job_status_filter_resets_on_mr_switch() {
+        let mut app = app_with_jobs(vec![create_test_job(1, "lint", JobStatus::Failed)]);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(2, 20, "Other MR"))
+        });
+
+        app.update(Action::ToggleFailedJobsFilter);
+        app.update(Action::NextMr);
+        assert_eq!(app.job_status_filter, None);
+
+        app.update(Action::ToggleFailedJobsFilter);
+        app.update(Action::PrevMr);
+        assert_eq!(app.job_status_filter, None);
+    }
+
+    #[test]
+    fn test_retry_pipeline_requires_confirmation() {
+        let mut app = App::new(123, None, false, 1);
+        let mut jobs_map = HashMap::new();
+        jobs_map.insert(100, vec![create_test_job(200, "test", JobStatus::Failed)]);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Failed)],
+            jobs: jobs_map,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        assert!(app.update(Action::RequestRetryPipeline).is_none());
+        assert_eq!(app.mode, AppMode::ConfirmingRetry);
+
+        app.update(Action::CancelRetryPipeline);
+        assert_eq!(app.mode, AppMode::Normal);
+
+        app.update(Action::RequestRetryPipeline);
+        match app.update(Action::ConfirmRetryPipeline) {
+            Some(Effect::RetryPipeline { pipeline_id, .. }) => assert_eq!(pipeline_id, 100),
+            other => panic!("Expected RetryPipeline, got {:?}", other),
+        }
+        assert_eq!(app.mode, AppMode::Normal);
+    }
+
+    #[test]
+    fn test_retry_pipeline_skipped_when_nothing_to_retry() {
+        let mut app = App::new(123, None, false, 1);
+        let mut jobs_map = HashMap::new();
+        jobs_map.insert(100, vec![create_test_job(200, "test", JobStatus::Success)]);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Success)],
+            jobs: jobs_map,
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "Test MR"))
+        });
+
+        app.update(Action::RequestRetryPipeline);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.update(Action::ConfirmRetryPipeline).is_none());
+    }
+
+    #[test]
+    fn test_group_mrs_use_their_own_project() {
+        let mut app = App::new(0, None, false, 1);
+        app.group_id = Some(7);
+
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { group_id, .. }) => assert_eq!(group_id, Some(7)),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+
+        // Same IID in two projects of the group: both are tracked
+        let mut mr_a = create_test_mr(1, 10, "MR in project A");
+        mr_a.project_id = Some(100);
+        let mut mr_b = create_test_mr(2, 10, "MR in project B");
+        mr_b.project_id = Some(200);
+
+        match app.update(Action::MergeRequestsLoaded(vec![mr_a, mr_b])) {
+            Some(Effect::Batch(effects)) => {
+                let project_ids: Vec<u64> = effects
+                    .iter()
+                    .map(|effect| match effect {
+                        Effect::FetchPipelines { project_id, .. } => *project_id,
+                        other => panic!("Expected FetchPipelines, got {:?}", other),
+                    })
+                    .collect();
+                assert_eq!(project_ids, vec![100, 200]);
+            }
+            other => panic!("Expected a batch of FetchPipelines, got {:?}", other),
+        }
+        assert_eq!(app.tracked_mrs.len(), 2);
+
+        match app.update(Action::PipelinesLoaded {
+            mr_id: 2,
+            pipelines: vec![create_test_pipeline(300, PipelineStatus::Running)],
+        }) {
+            Some(Effect::FetchJobs { project_id, pipeline_id, .. }) => {
+                assert_eq!(project_id, 200);
+                assert_eq!(pipeline_id, 300);
+            }
+            other => panic!("Expected FetchJobs, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_approvals_loaded_populates_tracked_mr() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+        assert!(app.tracked_mrs[0].approvals.is_none());
+
+        let approvals: Approvals = serde_json::from_value(serde_json::json!({
+            "approved": true,
+            "approvals_required": 2,
+            "approvals_left": 0,
+            "approved_by": []
+        }))
+        .unwrap();
+
+        let effect = app.update(Action::ApprovalsLoaded { mr_id: 1, approvals });
+        assert!(effect.is_none());
+        assert_eq!(
+            app.tracked_mrs[0].approvals.as_ref().map(|a| a.summary()).as_deref(),
+            Some("2/2 approved")
+        );
+
+        // Out-of-range index is ignored
+        let approvals: Approvals = serde_json::from_value(serde_json::json!({"approved": false})).unwrap();
+        assert!(app.update(Action::ApprovalsLoaded { mr_id: 6, approvals }).is_none());
+    }
+
+    #[test]
+    fn test_toggle_branch_focus_refreshes_with_filter() {
+        let mut app = App::new(123, Some("feature-10".to_string()), false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest::new(create_test_mr(2, 20, "Other MR")));
+
+        match app.update(Action::ToggleBranchFocus) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("feature-10"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+        assert!(app.focus_current_branch);
+        assert!(app.tracked_mrs.is_empty());
+        assert_eq!(app.status_message.as_deref(), Some("Showing the MR for branch 'feature-10'"));
+
+        app.update(Action::MergeRequestsLoaded(vec![]));
+        match app.update(Action::ToggleBranchFocus) {
+            Some(Effect::RefreshAll { source_branch, .. }) => assert!(source_branch.is_none()),
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+        assert!(!app.focus_current_branch);
+        assert_eq!(app.status_message.as_deref(), Some("Showing all open MRs"));
+    }
+
+    #[test]
+    fn test_toggle_branch_focus_without_branch() {
+        let mut app = App::new(123, None, true, 1);
+        assert!(app.update(Action::ToggleBranchFocus).is_none());
+        assert!(!app.focus_current_branch);
+        assert!(app.status_message.as_deref().unwrap().contains("showing all open MRs"));
+    }
+
+    #[test]
+    fn test_toggle_branch_focus_waits_for_running_refresh() {
+        let mut app = App::new(123, Some("feature-10".to_string()), false, 1);
+        app.update(Action::Refresh);
+        assert!(app.update(Action::ToggleBranchFocus).is_none());
+        assert!(!app.focus_current_branch);
+    }
+
+    #[test]
+    fn test_paused_auto_refresh_skips_tick() {
+        let mut app = App::new(123, None, false, 1);
+        // Pretend the refresh interval has long passed
+        app.last_auto_refresh = Instant::now() - std::time::Duration::from_secs(120);
+
+        app.update(Action::ToggleAutoRefreshPause);
+        assert!(app.auto_refresh_paused);
+        assert!(app.update(Action::Tick).is_none());
+
+        // Manual refresh still works while paused
+        assert!(matches!(app.update(Action::Refresh), Some(Effect::RefreshAll { .. })));
+        app.update(Action::MergeRequestsLoaded(vec![]));
+
+        app.last_auto_refresh = Instant::now() - std::time::Duration::from_secs(120);
+        app.update(Action::ToggleAutoRefreshPause);
+        assert!(!app.auto_refresh_paused);
+        assert!(matches!(app.update(Action::Tick), Some(Effect::RefreshAll { .. })));
+    }
+
+    #[test]
+    fn test_paused_auto_refresh_freezes_countdown() {
+        let mut app = App::new(123, None, false, 2);
+        app.last_auto_refresh = Instant::now() - Duration::from_secs(30);
+        app.update(Action::ToggleAutoRefreshPause);
+
+        // Time passing while paused doesn't count
+        app.auto_refresh_paused_at -= Duration::from_secs(600);
+        app.last_auto_refresh -= Duration::from_secs(600);
+        assert_eq!(app.time_until_auto_refresh().as_secs(), 89);
+        assert!(app.update(Action::Tick).is_none());
+
+        app.update(Action::ToggleAutoRefreshPause);
+        let left = app.time_until_auto_refresh().as_secs();
+        assert!(left == 89 || left == 88, "{left}");
+        assert!(app.update(Action::Tick).is_none());
+    }
+
+    #[test]
+    fn test_submit_note_posts_to_selected_mr() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            notes_loaded: true,
+            ..TrackedMergeRequest::new(create_test_mr(2, 20, "MR 2"))
+        });
+        app.selected_mr_index = 1;
+        app.mode = AppMode::ViewingComments;
+
+        app.update(Action::StartNote);
+        assert!(app.is_composing_note);
+        app.update(Action::UpdateNoteDraft("  Retrying the flaky job\n".to_string()));
+
+        match app.update(Action::SubmitNote) {
+            Some(Effect::PostNote { mr_id, project_id, mr_iid, body }) => {
+                assert_eq!(mr_id, 2);
+                assert_eq!(project_id, 123);
+                assert_eq!(mr_iid, 20);
+                assert_eq!(body, "Retrying the flaky job");
+            }
+            other => panic!("Expected PostNote, got {:?}", other),
+        }
+        assert!(!app.is_composing_note);
+        // Draft survives until the post succeeds
+        assert!(!app.note_draft.is_empty());
+
+        match app.update(Action::NotePosted { mr_id: 2 }) {
+            Some(Effect::FetchNotes { mr_iid, .. }) => assert_eq!(mr_iid, 20),
+            other => panic!("Expected FetchNotes, got {:?}", other),
+        }
+        assert!(app.note_draft.is_empty());
+        assert!(!app.tracked_mrs[1].notes_loaded);
+    }
+
+    #[test]
+    fn test_submit_empty_note_is_noop() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+        app.mode = AppMode::ViewingComments;
+
+        app.update(Action::StartNote);
+        app.update(Action::UpdateNoteDraft("   \n ".to_string()));
+        assert!(app.update(Action::SubmitNote).is_none());
+        assert!(!app.is_composing_note);
+    }
+
+    #[test]
+    fn test_toggle_approval_follows_current_state() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+
+        // Nothing to decide on until approvals are loaded
+        assert!(app.update(Action::ToggleApproval).is_none());
+
+        let approvals = |user_has_approved: bool| -> Approvals {
+            serde_json::from_value(serde_json::json!({
+                "approved": user_has_approved,
+                "user_has_approved": user_has_approved
+            }))
+            .unwrap()
+        };
+
+        app.update(Action::ApprovalsLoaded { mr_id: 1, approvals: approvals(false) });
+        match app.update(Action::ToggleApproval) {
+            Some(Effect::SetApproval { mr_iid, approve, .. }) => {
+                assert_eq!(mr_iid, 10);
+                assert!(approve);
+            }
+            other => panic!("Expected SetApproval, got {:?}", other),
+        }
+
+        app.update(Action::ApprovalsLoaded { mr_id: 1, approvals: approvals(true) });
+        match app.update(Action::ToggleApproval) {
+            Some(Effect::SetApproval { approve, .. }) => assert!(!approve),
+            other => panic!("Expected SetApproval, got {:?}", other),
+        }
+
+        // Approval state is refreshed after the change
+        match app.update(Action::ApprovalChanged { mr_id: 1, approved: false }) {
+            Some(Effect::FetchApprovals { mr_id, mr_iid, .. }) => {
+                assert_eq!(mr_id, 1);
+                assert_eq!(mr_iid, 10);
+            }
+            other => panic!("Expected FetchApprovals, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_pipeline_finishing_queues_hook_event() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+
+        // First load of an already-failed pipeline doesn't fire
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(100, PipelineStatus::Failed)],
+        });
+        assert!(app.take_hook_events().is_empty());
+
+        // A new pipeline starts, then fails
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(101, PipelineStatus::Running)],
+        });
+        assert!(app.take_hook_events().is_empty());
+
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(101, PipelineStatus::Failed)],
+        });
+        match app.take_hook_events().as_slice() {
+            [HookEvent::PipelineFailed(event)] => {
+                assert_eq!(event.mr_iid, 10);
+                assert_eq!(event.pipeline_id, 101);
+                assert_eq!(event.project_id, 123);
+            }
+            other => panic!("Expected one PipelineFailed event, got {:?}", other),
+        }
+
+        // Unchanged status on the next refresh doesn't fire again
+        app.update(Action::PipelinesLoaded {
+            mr_id: 1,
+            pipelines: vec![create_test_pipeline(101, PipelineStatus::Failed)],
+        });
+        assert!(app.take_hook_events().is_empty());
+    }
+
+    #[test]
+    fn test_case_sensitive_search_match_counts() {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingLog;
+        let log = "ERROR: build failed\nerror: retrying\nAll good\nERROR again";
+        app.log_processed_lines = crate::log_processor::process_log_content(log, &app.timestamp_mode);
+        app.log_content = Some(log.to_string());
+        app.search_query = "ERROR".to_string();
+
+        app.update(Action::ExecuteSearch);
+        assert_eq!(app.search_results, vec![0, 1, 3]);
+
+        // Toggling re-runs the executed search
+        app.update(Action::ToggleSearchCaseSensitive);
+        assert!(app.search_case_sensitive);
+        assert_eq!(app.search_results, vec![0, 3]);
+
+        app.search_query = "error".to_string();
+        app.update(Action::ExecuteSearch);
+        assert_eq!(app.search_results, vec![1]);
+    }
+
+    #[test]
+    fn test_detect_new_notes() {
+        let notes: Vec<Note> = [5, 7, 9, 12]
+            .iter()
+            .map(|&id| create_test_note(id, "note", false))
+            .collect();
+
+        assert!(detect_new_notes(None, &notes).is_empty());
+        assert_eq!(detect_new_notes(Some(7), &notes), HashSet::from([9, 12]));
+        assert!(detect_new_notes(Some(12), &notes).is_empty());
+    }
+
+    #[test]
+    fn test_new_notes_marked_after_reload_until_navigated_past() {
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(TrackedMergeRequest {
+            loading: false,
+            ..TrackedMergeRequest::new(create_test_mr(1, 10, "MR 1"))
+        });
+        app.mode = AppMode::ViewingComments;
+
+        let initial = vec![create_test_note(2, "b", false), create_test_note(1, "a", false)];
+        app.update(Action::NotesLoaded { mr_id: 1, notes: initial });
+        assert!(app.tracked_mrs[0].new_note_ids.is_empty());
+
+        // Refresh brings two newer notes (newest first, like the API)
+        let reloaded = vec![
+            create_test_note(4, "d", false),
+            create_test_note(3, "c", false),
+            create_test_note(2, "b", false),
+            create_test_note(1, "a", false),
+        ];
+        app.update(Action::NotesLoaded { mr_id: 1, notes: reloaded });
+        assert_eq!(app.tracked_mrs[0].new_note_ids, HashSet::from([3, 4]));
+
+        // Moving off note 4 clears its mark only
+        app.update(Action::NextNote);
+        assert_eq!(app.tracked_mrs[0].new_note_ids, HashSet::from([3]));
+        app.update(Action::NextNote);
+        assert!(app.tracked_mrs[0].new_note_ids.is_empty());
+    }
+
+    fn pipeline_on(id: u64, ref_name: &str) -> Pipeline {
+        Pipeline {
+            ref_name: ref_name.to_string(),
+            ..create_test_pipeline(id, PipelineStatus::Success)
+        }
+    }
+
+    #[test]
+    fn test_project_pipelines_view_shows_project_jobs() {
+        let mut app = app_with_jobs(vec![create_test_job(1, "mr-build", JobStatus::Failed)]);
+
+        match app.update(Action::ToggleProjectPipelines) {
+            Some(Effect::FetchProjectPipelines { project_id, ref_name }) => {
+                assert_eq!(project_id, 123);
+                assert_eq!(ref_name, None);
+            }
+            other => panic!("Expected FetchProjectPipelines, got {:?}", other),
+        }
+        assert_eq!(app.mode, AppMode::ViewingProjectPipelines);
+        assert!(app.get_selected_jobs().is_none());
+
+        // Newest first, and the selected one's jobs are fetched
+        let loaded = vec![pipeline_on(200, "main"), pipeline_on(300, "nightly")];
+        match app.update(Action::ProjectPipelinesLoaded(loaded)) {
+            Some(Effect::FetchProjectJobs { project_id, pipeline_id }) => {
+                assert_eq!(project_id, 123);
+                assert_eq!(pipeline_id, 300);
+            }
+            other => panic!("Expected FetchProjectJobs, got {:?}", other),
+        }
+        assert_eq!(app.get_selected_pipeline().unwrap().id, 300);
+
+        app.update(Action::ProjectJobsLoaded {
+            pipeline_id: 300,
+            jobs: vec![create_test_job(7, "nightly-e2e", JobStatus::Failed)],
+        });
+        assert_eq!(selected_job_name(&app), "nightly-e2e");
+
+        match app.update(Action::OpenSelectedJobLog) {
+            Some(Effect::FetchJobTrace { project_id, job_id, .. }) => {
+                assert_eq!(project_id, 123);
+                assert_eq!(job_id, 7);
+            }
+            other => panic!("Expected FetchJobTrace, got {:?}", other),
+        }
+        app.update(Action::JobTraceLoaded {
+            job_id: 7,
+            job_name: "nightly-e2e".to_string(),
+            trace: "boom".to_string(),
+        });
+        assert_eq!(app.mode, AppMode::ViewingLog);
+        // Not cached as the MR's log
+        assert!(app.tracked_mrs[0].job_logs_cache.get(7).is_none());
+        app.update(Action::CloseLogViewer);
+        assert_eq!(app.mode, AppMode::ViewingProjectPipelines);
+
+        // The other pipeline's jobs are fetched when it's selected
+        match app.update(Action::NextPipeline) {
+            Some(Effect::FetchProjectJobs { pipeline_id, .. }) => assert_eq!(pipeline_id, 200),
+            other => panic!("Expected FetchProjectJobs, got {:?}", other),
+        }
+        assert!(app.update(Action::PrevPipeline).is_none());
+
+        assert!(app.update(Action::ToggleProjectPipelines).is_none());
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(selected_job_name(&app), "mr-build");
+    }
+
+    #[test]
+    fn test_project_pipelines_reload_keeps_selection() {
+        let mut app = App::new(123, None, false, 1);
+        app.update(Action::ToggleProjectPipelines);
+        app.update(Action::ProjectPipelinesLoaded(vec![pipeline_on(200, "main"), pipeline_on(300, "main")]));
+        app.update(Action::NextPipeline);
+        assert_eq!(app.get_selected_pipeline().unwrap().id, 200);
+
+        app.update(Action::ProjectPipelinesLoaded(vec![
+            pipeline_on(200, "main"),
+            pipeline_on(300, "main"),
+            pipeline_on(400, "main"),
+        ]));
+        assert_eq!(app.get_selected_pipeline().unwrap().id, 200);
+    }
+
+    #[test]
+    fn test_project_pipelines_ref_toggle() {
+        let mut app = App::new(123, Some("feature".to_string()), false, 1);
+        app.default_branch = Some("main".to_string());
+        app.update(Action::ToggleProjectPipelines);
+
+        match app.update(Action::ToggleProjectPipelinesRef) {
+            Some(Effect::FetchProjectPipelines { ref_name, .. }) => {
+                assert_eq!(ref_name.as_deref(), Some("main"))
+            }
+            other => panic!("Expected FetchProjectPipelines, got {:?}", other),
+        }
+        match app.update(Action::ToggleProjectPipelinesRef) {
+            Some(Effect::FetchProjectPipelines { ref_name, .. }) => assert_eq!(ref_name, None),
+            other => panic!("Expected FetchProjectPipelines, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_project_pipelines_need_a_project() {
+        let mut app = App::new(0, None, false, 1);
+        app.group_id = Some(7);
+        assert!(app.update(Action::ToggleProjectPipelines).is_none());
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.project_pipelines.is_none());
+    }
+
+    fn app_with_cached_mrs() -> App {
+        let mut app = App::new(123, None, false, 1);
+        for (id, iid) in [(1, 10), (2, 20)] {
+            let mut tmr = TrackedMergeRequest::new(create_test_mr(id, iid, "MR"));
+            tmr.notes = vec![create_test_note(iid * 100, "note", false)];
+            tmr.notes_loaded = true;
+            tmr.job_logs_cache.insert(iid, "log".to_string());
+            tmr.loading = false;
+            app.tracked_mrs.push(tmr);
+        }
+        app
+    }
+
+    #[test]
+    fn test_refresh_current_mr_clears_only_its_caches() {
+        let mut app = app_with_cached_mrs();
+        app.update(Action::NextMr);
+
+        match app.update(Action::RefreshCurrentMr) {
+            Some(Effect::FetchPipelines { mr_id, project_id, mr_iid }) => {
+                assert_eq!(mr_id, 2);
+                assert_eq!(project_id, 123);
+                assert_eq!(mr_iid, 20);
+            }
+            other => panic!("Expected FetchPipelines, got {:?}", other),
+        }
+        let refreshed = &app.tracked_mrs[1];
+        assert!(!refreshed.notes_loaded);
+        assert!(refreshed.notes.is_empty());
+        assert!(refreshed.job_logs_cache.is_empty());
+
+        let other = &app.tracked_mrs[0];
+        assert!(other.notes_loaded);
+        assert_eq!(other.notes.len(), 1);
+        assert!(other.job_logs_cache.contains_key(10));
+        // Not a full refresh, so `r` still works meanwhile
+        assert!(!app.refresh_in_flight);
+    }
+
+    #[test]
+    fn test_refresh_current_mr_reloads_comments_being_read() {
+        let mut app = app_with_cached_mrs();
+        app.mode = AppMode::ViewingComments;
+        app.update(Action::RefreshCurrentMr);
+        assert_eq!(app.pending_note_restore, Some((10, Some(1000))));
+
+        match app.update(Action::PipelinesLoaded { mr_id: 1, pipelines: Vec::new() }) {
+            Some(Effect::FetchNotes { mr_id, mr_iid, .. }) => {
+                assert_eq!(mr_id, 1);
+                assert_eq!(mr_iid, 10);
+            }
+            other => panic!("Expected FetchNotes, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_refresh_current_mr_without_mrs() {
+        let mut app = App::new(123, None, false, 1);
+        assert!(app.update(Action::RefreshCurrentMr).is_none());
     }
 }
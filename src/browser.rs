@@ -0,0 +1,60 @@
+use crate::error::{PeeplabError, Result};
+use std::process::{Command, Stdio};
+
+/// Program and leading arguments that open a URL with the platform's default handler
+fn opener() -> (&'static str, &'static [&'static str]) {
+    if cfg!(target_os = "macos") {
+        ("open", &[])
+    } else if cfg!(target_os = "windows") {
+        // The empty argument is `start`'s window title, so the URL isn't taken for one
+        ("cmd", &["/C", "start", ""])
+    } else {
+        ("xdg-open", &[])
+    }
+}
+
+/// Open `url` in the default browser. Blocks until the opener exits so that a missing
+/// opener or one without a configured browser is reported instead of ignored.
+pub fn open_url(url: &str) -> Result<()> {
+    let (program, args) = opener();
+    open_with(program, args, url)
+}
+
+fn open_with(program: &str, args: &[&str], url: &str) -> Result<()> {
+    // Silenced so the opener's own messages don't draw over the TUI
+    let status = Command::new(program)
+        .args(args)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| PeeplabError::BrowserLaunch(format!("{}: {}", program, e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(PeeplabError::BrowserLaunch(format!("{} exited with {}", program, status)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_opener_is_an_error() {
+        let error = open_with("peeplab-no-such-opener", &[], "https://gitlab.com").unwrap_err();
+        assert!(matches!(error, PeeplabError::BrowserLaunch(_)));
+        assert!(error.to_string().starts_with("Couldn't open browser: peeplab-no-such-opener"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_opener_exit_status() {
+        assert!(open_with("true", &[], "https://gitlab.com").is_ok());
+
+        let error = open_with("false", &[], "https://gitlab.com").unwrap_err();
+        assert!(error.to_string().contains("false exited with"));
+    }
+}
@@ -0,0 +1,194 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Command-line arguments
+#[derive(Debug, Clone, Default, Parser)]
+#[command(name = "peeplab", version, about = "GitLab pipeline monitor TUI")]
+pub struct Cli {
+    /// Config file to use instead of the one in the config directory
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// `[[profiles]]` entry of the config to use, by its `name`, instead of `active_profile`
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// ID of the project to monitor, overriding the config and git detection
+    #[arg(long, value_name = "N", conflicts_with = "project")]
+    pub project_id: Option<u64>,
+
+    /// Project to monitor, overriding the config and git detection
+    #[arg(long, value_name = "NAMESPACE/PATH")]
+    pub project: Option<String>,
+
+    /// Only show the MR of this source branch instead of the local git branch's
+    #[arg(long, value_name = "NAME")]
+    pub branch: Option<String>,
+
+    /// Only track MRs with this label; repeat to require several
+    #[arg(long = "label", value_name = "LABEL")]
+    pub labels: Vec<String>,
+
+    /// Only MRs assigned to this username (`@me` for yourself), overriding `[app] assignee`
+    #[arg(long, value_name = "USERNAME")]
+    pub assignee: Option<String>,
+
+    /// Only MRs reviewed by this username (`@me` for yourself), overriding `[app] reviewer`
+    #[arg(long, value_name = "USERNAME")]
+    pub reviewer: Option<String>,
+
+    /// Only MRs in this state, overriding `[app] mr_state`
+    #[arg(long, value_name = "STATE", value_parser = ["opened", "merged", "closed", "all"])]
+    pub state: Option<String>,
+
+    /// Print a one-line pipeline status summary and exit
+    #[arg(long)]
+    pub status_line: bool,
+
+    /// Log GitLab requests to peeplab.log next to the config file
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Where the project to monitor comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectSource {
+    Id(u64),
+    Path(String), // Resolved to an ID through the API
+    Group,        // Group-scoped MRs carry their own project ID
+    DetectFromGit,
+}
+
+impl Cli {
+    /// Whether a project was given on the command line, which also overrides group mode
+    pub fn overrides_project(&self) -> bool {
+        self.project_id.is_some() || self.project.is_some()
+    }
+
+    /// Command-line project first, then the configured project ID, group mode, and
+    /// finally the git remote of the working directory
+    pub fn project_source(&self, config_project_id: Option<u64>, group_id: Option<u64>) -> ProjectSource {
+        if let Some(id) = self.project_id {
+            return ProjectSource::Id(id);
+        }
+        if let Some(path) = &self.project {
+            return ProjectSource::Path(path.clone());
+        }
+        match (config_project_id, group_id) {
+            (Some(id), _) => ProjectSource::Id(id),
+            (None, Some(_)) => ProjectSource::Group,
+            (None, None) => ProjectSource::DetectFromGit,
+        }
+    }
+
+    /// Branch to focus on and whether branch focus is on. `--branch` forces focus on and
+    /// skips `detect_branch`, so it also works outside a git repository.
+    pub fn focused_branch(
+        &self,
+        focus_current_branch: bool,
+        detect_branch: impl FnOnce() -> Option<String>,
+    ) -> (Option<String>, bool) {
+        match &self.branch {
+            Some(branch) => (Some(branch.clone()), true),
+            None if focus_current_branch => (detect_branch(), true),
+            None => (None, false),
+        }
+    }
+}
+
+/// Stands for the user the token belongs to in `--assignee`/`--reviewer`
+pub const CURRENT_USER: &str = "@me";
+
+/// Whether an assignee/reviewer username refers to the token's user
+pub fn is_current_user(username: &str) -> bool {
+    username == CURRENT_USER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::events::{Action, Effect};
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("peeplab").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let cli = parse(&["--project", "group/app", "--profile", "work", "-v"]);
+        assert_eq!(cli.project.as_deref(), Some("group/app"));
+        assert_eq!(cli.profile.as_deref(), Some("work"));
+        assert!(cli.verbose);
+        assert!(!cli.status_line);
+
+        assert!(cli.labels.is_empty());
+
+        let cli = parse(&["--label", "bug", "--label=priority::high"]);
+        assert_eq!(cli.labels, vec!["bug", "priority::high"]);
+
+        let cli = parse(&["--assignee", "@me", "--reviewer", "bob"]);
+        assert_eq!(cli.assignee.as_deref(), Some("@me"));
+        assert!(is_current_user(cli.assignee.as_deref().unwrap()));
+        assert_eq!(cli.reviewer.as_deref(), Some("bob"));
+
+        let cli = parse(&["--project-id=42", "--status-line"]);
+        assert_eq!(cli.project_id, Some(42));
+        assert!(cli.status_line);
+
+        let cli = parse(&["--config", "/tmp/ci.toml"]);
+        assert_eq!(cli.config, Some(PathBuf::from("/tmp/ci.toml")));
+        assert!(parse(&[]).config.is_none());
+
+        let cli = parse(&["--state", "merged"]);
+        assert_eq!(cli.state.as_deref(), Some("merged"));
+        assert!(Cli::try_parse_from(["peeplab", "--state", "locked"]).is_err());
+
+        assert!(Cli::try_parse_from(["peeplab", "--project-id", "abc"]).is_err());
+        assert!(Cli::try_parse_from(["peeplab", "--project-id", "1", "--project", "a/b"]).is_err());
+    }
+
+    #[test]
+    fn test_project_id_skips_git_detection() {
+        let cli = parse(&["--project-id", "42"]);
+        assert!(cli.overrides_project());
+        assert_eq!(cli.project_source(None, None), ProjectSource::Id(42));
+        assert_eq!(cli.project_source(Some(7), Some(9)), ProjectSource::Id(42));
+    }
+
+    #[test]
+    fn test_project_source_fallbacks() {
+        let cli = parse(&["--project", "group/app"]);
+        assert_eq!(cli.project_source(Some(7), None), ProjectSource::Path("group/app".to_string()));
+
+        let cli = Cli::default();
+        assert!(!cli.overrides_project());
+        assert_eq!(cli.project_source(Some(7), Some(9)), ProjectSource::Id(7));
+        assert_eq!(cli.project_source(None, Some(9)), ProjectSource::Group);
+        assert_eq!(cli.project_source(None, None), ProjectSource::DetectFromGit);
+    }
+
+    #[test]
+    fn test_branch_flag_filters_refresh_outside_git() {
+        let cli = parse(&["--branch", "release/2.0"]);
+        let (branch, focus) = cli.focused_branch(false, || panic!("git must not be consulted"));
+        assert_eq!(branch.as_deref(), Some("release/2.0"));
+        assert!(focus);
+
+        let mut app = App::new(1, branch, focus, 1);
+        match app.update(Action::Refresh) {
+            Some(Effect::RefreshAll { source_branch, .. }) => {
+                assert_eq!(source_branch.as_deref(), Some("release/2.0"))
+            }
+            other => panic!("Expected RefreshAll, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_focused_branch_without_flag() {
+        let cli = Cli::default();
+        assert_eq!(cli.focused_branch(true, || Some("main".to_string())), (Some("main".to_string()), true));
+        assert_eq!(cli.focused_branch(true, || None), (None, true));
+        assert_eq!(cli.focused_branch(false, || panic!("not focused")), (None, false));
+    }
+}
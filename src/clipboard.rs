@@ -0,0 +1,23 @@
+use crate::error::{PeeplabError, Result};
+use std::sync::{Mutex, OnceLock};
+
+/// Kept for the life of the process: on X11 and Wayland the copied text is served by
+/// its owner, so dropping the clipboard right after copying would lose it
+static CLIPBOARD: OnceLock<Mutex<Option<arboard::Clipboard>>> = OnceLock::new();
+
+/// Put `text` on the system clipboard
+pub fn copy_text(text: &str) -> Result<()> {
+    let mut clipboard = CLIPBOARD
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|_| PeeplabError::Clipboard("clipboard lock poisoned".to_string()))?;
+
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new().map_err(|e| PeeplabError::Clipboard(e.to_string()))?);
+    }
+    clipboard
+        .as_mut()
+        .expect("clipboard was just initialised")
+        .set_text(text)
+        .map_err(|e| PeeplabError::Clipboard(e.to_string()))
+}
@@ -1,21 +1,32 @@
 use crate::error::{PeeplabError, Result};
-use super::settings::Settings;
+use super::settings::{ConfigFile, Settings};
 use dirs::config_dir;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-pub fn get_config_path() -> Result<PathBuf> {
+/// `peeplab` in the user's config directory, created if missing; the config file,
+/// state file and log all live there
+pub fn app_config_dir() -> Result<PathBuf> {
     let config_dir = config_dir()
         .ok_or_else(|| PeeplabError::Config("Could not determine config directory".to_string()))?;
 
     let app_config_dir = config_dir.join("peeplab");
     std::fs::create_dir_all(&app_config_dir)?;
 
-    Ok(app_config_dir.join("config.toml"))
+    Ok(app_config_dir)
 }
 
-pub fn load_config() -> Result<Settings> {
-    let config_path = get_config_path()?;
+pub fn get_config_path() -> Result<PathBuf> {
+    Ok(app_config_dir()?.join("config.toml"))
+}
+
+/// Load the config file, resolving `profile` (or the file's `active_profile`) if given
+pub fn load_config(profile: Option<&str>) -> Result<Settings> {
+    load_config_from(&get_config_path()?, profile)
+}
 
+/// `load_config` for an explicit file instead of the one in the config directory
+/// (`--config <path>`)
+pub fn load_config_from(config_path: &Path, profile: Option<&str>) -> Result<Settings> {
     if !config_path.exists() {
         return Err(PeeplabError::Config(format!(
             "Config file not found at {:?}. Please create one with your GitLab token.",
@@ -23,8 +34,15 @@ pub fn load_config() -> Result<Settings> {
         )));
     }
 
-    let content = std::fs::read_to_string(&config_path)?;
-    let settings: Settings = toml::from_str(&content)?;
+    let content = std::fs::read_to_string(config_path)?;
+    let config_file: ConfigFile = toml::from_str(&content)?;
+    let mut settings = config_file
+        .into_settings(profile)
+        .map_err(|e| PeeplabError::Config(e.to_string()))?;
+    settings
+        .gitlab
+        .resolve_token()
+        .map_err(|e| PeeplabError::Config(e.to_string()))?;
 
     settings.validate().map_err(|e| PeeplabError::Config(e.to_string()))?;
     Ok(settings)
@@ -51,7 +69,7 @@ mod tests {
 
         // Mock the config path by reading directly
         let content = fs::read_to_string(&config_path).unwrap();
-        let settings: Settings = toml::from_str(&content).unwrap();
+        let mut settings: Settings = toml::from_str(&content).unwrap();
 
         assert!(settings.validate().is_ok());
         assert_eq!(settings.gitlab.token, "test-token-123");
@@ -90,6 +108,31 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_load_config_from_explicit_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("ci.toml");
+        fs::write(
+            &config_path,
+            r#"
+            [gitlab]
+            token = "ci-token"
+            default_project_id = 7
+            instance_url = "https://gitlab.example.com/"
+        "#,
+        )
+        .unwrap();
+
+        let settings = load_config_from(&config_path, None).unwrap();
+        assert_eq!(settings.gitlab.token, "ci-token");
+        assert_eq!(settings.gitlab.default_project_id, Some(7));
+        // Validated like the default config
+        assert_eq!(settings.gitlab.instance_url, "https://gitlab.example.com");
+
+        let missing = load_config_from(&temp_dir.path().join("missing.toml"), None);
+        assert!(matches!(missing, Err(PeeplabError::Config(message)) if message.contains("missing.toml")));
+    }
+
     #[test]
     fn test_get_config_path() {
         let result = get_config_path();
@@ -1,4 +1,4 @@
 pub mod loader;
 pub mod settings;
 
-pub use loader::{get_config_path, load_config};
+pub use loader::{app_config_dir, get_config_path, load_config, load_config_from};
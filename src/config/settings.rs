@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Settings {
@@ -9,14 +11,65 @@ pub struct Settings {
     pub ui: UiConfig,
     #[serde(default)]
     pub editor: EditorConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub keybindings: HashMap<String, String>, // action name -> key, e.g. next_mr = "n"
+}
+
+/// On-disk config shape: the `[gitlab]` section can be replaced or complemented by
+/// named `[[profiles]]`, one of which is resolved into `Settings::gitlab`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConfigFile {
+    pub gitlab: Option<GitLabConfig>,
+    #[serde(default)]
+    pub profiles: Vec<ProfileConfig>,
+    pub active_profile: Option<String>,
+    #[serde(default)]
+    pub app: AppConfig,
+    #[serde(default)]
+    pub ui: UiConfig,
+    #[serde(default)]
+    pub editor: EditorConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub keybindings: HashMap<String, String>, // action name -> key, e.g. next_mr = "n"
 }
 
+/// A named GitLab instance/token pair
 #[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProfileConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub gitlab: GitLabConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct GitLabConfig {
+    #[serde(default)]
     pub token: String,
+    #[serde(default)]
+    pub token_command: Option<String>, // Command printing the token, e.g. `pass show gitlab/token`
+    #[serde(default)]
+    pub token_env: Option<String>,     // Environment variable holding the token
     pub default_project_id: Option<u64>,
+    #[serde(default)]
+    pub group_id: Option<u64>,
     #[serde(default = "default_instance_url")]
     pub instance_url: String,
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32, // Retries after a connection error or timeout
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize, // GitLab requests in flight at once
+    #[serde(default)]
+    pub http_proxy: Option<String>,  // Falls back to HTTP_PROXY
+    #[serde(default)]
+    pub https_proxy: Option<String>, // Falls back to HTTPS_PROXY
+    #[serde(default)]
+    pub ca_cert_path: Option<PathBuf>, // PEM root certificate for an internal CA
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -27,8 +80,22 @@ pub struct AppConfig {
     pub max_tracked_mrs: usize,
     #[serde(default = "default_focus_current_branch")]
     pub focus_current_branch: bool,
+    #[serde(default)]
+    pub verify_git_project: bool, // Warn when the git remote's project isn't default_project_id
     #[serde(default = "default_auto_refresh_interval_minutes")]
     pub auto_refresh_interval_minutes: u64,
+    #[serde(default)]
+    pub assignee: Option<String>, // Only MRs assigned to this username ("@me": the token's user)
+    #[serde(default)]
+    pub reviewer: Option<String>, // Only MRs this username reviews ("@me": the token's user)
+    #[serde(default = "default_mr_state")]
+    pub mr_state: String, // "opened", "merged", "closed" or "all"
+    #[serde(default)]
+    pub artifacts_dir: Option<PathBuf>, // Where downloaded job artifacts are saved
+    #[serde(default = "default_log_cache_max_entries")]
+    pub log_cache_max_entries: usize, // Job logs kept in memory per MR
+    #[serde(default = "default_log_cache_max_mb")]
+    pub log_cache_max_mb: usize, // Total size of the job logs kept per MR
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -37,18 +104,48 @@ pub struct UiConfig {
     pub relative_timestamps: bool,
     #[serde(default = "default_theme")]
     pub theme: String,
+    #[serde(default)]
+    pub highlight_authors: Vec<String>,
+    #[serde(default = "default_notes_per_page")]
+    pub notes_per_page: u32,
+    #[serde(default = "default_notes_order")]
+    pub notes_order: String, // "asc" or "desc" by creation time
+    #[serde(default = "default_timestamp_mode")]
+    pub default_timestamp_mode: String, // Log timestamps when a log opens: "hidden", "date" or "full"
+    #[serde(default = "default_job_sort")]
+    pub job_sort: String, // "status", "stage" or "name"
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct EditorConfig {
     pub custom_editor: Option<String>,
 }
 
+/// Shell commands run on dashboard events; see `hooks` for the env vars they receive
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HooksConfig {
+    pub on_pipeline_failed: Option<String>,
+    pub on_pipeline_succeeded: Option<String>,
+    pub on_refresh: Option<String>,
+}
+
 // Default functions
 fn default_instance_url() -> String {
     "https://gitlab.com".to_string()
 }
 
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_retries() -> u32 {
+    2
+}
+
+fn default_max_concurrent_requests() -> usize {
+    4
+}
+
 fn default_refresh_interval() -> u64 {
     30
 }
@@ -69,10 +166,38 @@ fn default_relative_timestamps() -> bool {
     true
 }
 
+fn default_log_cache_max_entries() -> usize {
+    crate::log_cache::DEFAULT_MAX_ENTRIES
+}
+
+fn default_log_cache_max_mb() -> usize {
+    crate::log_cache::DEFAULT_MAX_BYTES / (1024 * 1024)
+}
+
 fn default_theme() -> String {
     "dark".to_string()
 }
 
+fn default_notes_per_page() -> u32 {
+    100
+}
+
+fn default_mr_state() -> String {
+    "opened".to_string()
+}
+
+fn default_notes_order() -> String {
+    "desc".to_string()
+}
+
+fn default_timestamp_mode() -> String {
+    "hidden".to_string()
+}
+
+fn default_job_sort() -> String {
+    "status".to_string()
+}
+
 // Defaults for the configs
 impl Default for AppConfig {
     fn default() -> Self {
@@ -80,32 +205,194 @@ impl Default for AppConfig {
             refresh_interval: default_refresh_interval(),
             max_tracked_mrs: default_max_tracked_mrs(),
             focus_current_branch: default_focus_current_branch(),
+            verify_git_project: false,
             auto_refresh_interval_minutes: default_auto_refresh_interval_minutes(),
+            assignee: None,
+            reviewer: None,
+            mr_state: default_mr_state(),
+            artifacts_dir: None,
+            log_cache_max_entries: default_log_cache_max_entries(),
+            log_cache_max_mb: default_log_cache_max_mb(),
         }
     }
 }
 
+impl AppConfig {
+    pub fn log_cache_max_bytes(&self) -> usize {
+        self.log_cache_max_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// `artifacts_dir`, or `peeplab` in the user's download directory
+    pub fn resolved_artifacts_dir(&self) -> PathBuf {
+        self.artifacts_dir.clone().unwrap_or_else(|| {
+            dirs::download_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join("peeplab")
+        })
+    }
+}
+
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             relative_timestamps: default_relative_timestamps(),
             theme: default_theme(),
+            highlight_authors: Vec::new(),
+            notes_per_page: default_notes_per_page(),
+            notes_order: default_notes_order(),
+            default_timestamp_mode: default_timestamp_mode(),
+            job_sort: default_job_sort(),
         }
     }
 }
 
-impl Default for EditorConfig {
-    fn default() -> Self {
-        Self {
-            custom_editor: None,
+impl GitLabConfig {
+    /// Fill in `token` when it isn't set explicitly.
+    ///
+    /// Precedence: `token` > `token_command` > `token_env`. Leaves `token` empty when
+    /// nothing is configured, which `Settings::validate` then rejects.
+    pub fn resolve_token(&mut self) -> anyhow::Result<()> {
+        if !self.token.is_empty() {
+            return Ok(());
         }
+
+        if let Some(command) = &self.token_command {
+            let output = std::process::Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .output()
+                .map_err(|e| anyhow::anyhow!("Failed to run token_command '{}': {}", command, e))?;
+            if !output.status.success() {
+                anyhow::bail!("token_command '{}' exited with {}", command, output.status);
+            }
+            self.token = String::from_utf8_lossy(&output.stdout)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+        } else if let Some(var) = &self.token_env {
+            self.token = std::env::var(var).unwrap_or_default();
+        }
+
+        Ok(())
+    }
+
+    /// `http_proxy`, or the `HTTP_PROXY`/`http_proxy` environment variable
+    pub fn resolved_http_proxy(&self) -> Option<String> {
+        self.http_proxy.clone().or_else(|| env_proxy(&["HTTP_PROXY", "http_proxy"]))
+    }
+
+    /// `https_proxy`, or the `HTTPS_PROXY`/`https_proxy` environment variable
+    pub fn resolved_https_proxy(&self) -> Option<String> {
+        self.https_proxy.clone().or_else(|| env_proxy(&["HTTPS_PROXY", "https_proxy"]))
+    }
+}
+
+fn env_proxy(vars: &[&str]) -> Option<String> {
+    vars.iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+impl ConfigFile {
+    /// Resolve the GitLab connection to use.
+    ///
+    /// `profile` (e.g. from the command line) takes precedence over `active_profile`.
+    /// Without either, the `[gitlab]` section is used, falling back to the first profile.
+    pub fn into_settings(self, profile: Option<&str>) -> anyhow::Result<Settings> {
+        let selected = profile.map(str::to_string).or(self.active_profile);
+
+        let gitlab = match selected {
+            Some(name) => match self.profiles.into_iter().find(|p| p.name == name) {
+                Some(profile) => profile.gitlab,
+                None => anyhow::bail!("Profile '{}' not found in config", name),
+            },
+            None => match (self.gitlab, self.profiles.into_iter().next()) {
+                (Some(gitlab), _) => gitlab,
+                (None, Some(profile)) => profile.gitlab,
+                (None, None) => anyhow::bail!("Config needs a [gitlab] section or at least one [[profiles]] entry"),
+            },
+        };
+
+        Ok(Settings {
+            gitlab,
+            app: self.app,
+            ui: self.ui,
+            editor: self.editor,
+            hooks: self.hooks,
+            keybindings: self.keybindings,
+        })
     }
 }
 
 impl Settings {
-    pub fn validate(&self) -> anyhow::Result<()> {
+    /// Check the settings and normalize `instance_url` (trailing slashes removed)
+    pub fn validate(&mut self) -> anyhow::Result<()> {
         if self.gitlab.token.is_empty() {
-            anyhow::bail!("GitLab token cannot be empty");
+            anyhow::bail!("GitLab token cannot be empty: set token, token_command or token_env");
+        }
+
+        let instance_url = &self.gitlab.instance_url;
+        let parsed = url::Url::parse(instance_url).map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid instance_url '{}': {} (expected e.g. \"https://gitlab.com\")",
+                instance_url,
+                e
+            )
+        })?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            anyhow::bail!(
+                "Invalid instance_url '{}': scheme must be http or https, not '{}'",
+                instance_url,
+                parsed.scheme()
+            );
+        }
+        if parsed.host_str().is_none_or(str::is_empty) {
+            anyhow::bail!("Invalid instance_url '{}': missing host", instance_url);
+        }
+
+        self.gitlab.instance_url = instance_url.trim_end_matches('/').to_string();
+
+        if !matches!(self.ui.notes_order.as_str(), "asc" | "desc") {
+            anyhow::bail!(
+                "Invalid notes_order '{}': expected \"asc\" or \"desc\"",
+                self.ui.notes_order
+            );
+        }
+        if self.gitlab.request_timeout_secs == 0 {
+            anyhow::bail!("Invalid request_timeout_secs: must be at least 1");
+        }
+        if self.gitlab.max_concurrent_requests == 0 {
+            anyhow::bail!("Invalid max_concurrent_requests: must be at least 1");
+        }
+
+        if crate::ui::theme::Theme::from_name(&self.ui.theme).is_none() {
+            anyhow::bail!(
+                "Invalid theme '{}': expected \"dark\" or \"light\"",
+                self.ui.theme
+            );
+        }
+        if crate::app::TimestampDisplayMode::from_name(&self.ui.default_timestamp_mode).is_none() {
+            anyhow::bail!(
+                "Invalid default_timestamp_mode '{}': expected \"hidden\", \"date\" or \"full\"",
+                self.ui.default_timestamp_mode
+            );
+        }
+        if crate::gitlab::MrStateFilter::from_name(&self.app.mr_state).is_none() {
+            anyhow::bail!(
+                "Invalid mr_state '{}': expected \"opened\", \"merged\", \"closed\" or \"all\"",
+                self.app.mr_state
+            );
+        }
+        if crate::app::JobSort::from_name(&self.ui.job_sort).is_none() {
+            anyhow::bail!(
+                "Invalid job_sort '{}': expected \"status\", \"stage\" or \"name\"",
+                self.ui.job_sort
+            );
+        }
+        if !(1..=100).contains(&self.ui.notes_per_page) {
+            anyhow::bail!(
+                "Invalid notes_per_page {}: must be between 1 and 100",
+                self.ui.notes_per_page
+            );
         }
         Ok(())
     }
@@ -125,12 +412,18 @@ mod tests {
         let settings: Settings = toml::from_str(toml).unwrap();
         assert_eq!(settings.gitlab.token, "test-token");
         assert_eq!(settings.gitlab.instance_url, "https://gitlab.com");
+        assert_eq!(settings.gitlab.request_timeout_secs, 30);
+        assert_eq!(settings.gitlab.max_retries, 2);
+        assert_eq!(settings.gitlab.max_concurrent_requests, 4);
+        assert!(settings.gitlab.group_id.is_none());
         assert_eq!(settings.app.refresh_interval, 30);
         assert_eq!(settings.app.max_tracked_mrs, 5);
         assert_eq!(settings.app.auto_refresh_interval_minutes, 1);
         assert!(settings.ui.relative_timestamps);
         assert_eq!(settings.ui.theme, "dark");
+        assert!(settings.ui.highlight_authors.is_empty());
         assert!(settings.editor.custom_editor.is_none());
+        assert!(settings.hooks.on_pipeline_failed.is_none());
     }
 
     #[test]
@@ -139,44 +432,78 @@ mod tests {
             [gitlab]
             token = "glpat-test123"
             default_project_id = 42
+            group_id = 7
             instance_url = "https://gitlab.example.com"
 
             [app]
             refresh_interval = 60
             max_tracked_mrs = 10
             auto_refresh_interval_minutes = 5
+            log_cache_max_mb = 8
 
             [ui]
             relative_timestamps = false
             theme = "light"
+            highlight_authors = ["alice", "bob"]
 
             [editor]
             custom_editor = "nvim"
+
+            [hooks]
+            on_pipeline_failed = "notify-send 'Pipeline failed'"
+            on_refresh = "date >> ~/peeplab.log"
+
+            [keybindings]
+            next_mr = "n"
+            refresh = "ctrl-r"
         "#;
 
         let settings: Settings = toml::from_str(toml).unwrap();
         assert_eq!(settings.gitlab.token, "glpat-test123");
         assert_eq!(settings.gitlab.default_project_id, Some(42));
+        assert_eq!(settings.gitlab.group_id, Some(7));
         assert_eq!(settings.gitlab.instance_url, "https://gitlab.example.com");
         assert_eq!(settings.app.refresh_interval, 60);
         assert_eq!(settings.app.max_tracked_mrs, 10);
         assert_eq!(settings.app.auto_refresh_interval_minutes, 5);
+        assert_eq!(settings.app.log_cache_max_bytes(), 8 * 1024 * 1024);
+        assert_eq!(settings.app.log_cache_max_entries, 20);
         assert!(!settings.ui.relative_timestamps);
         assert_eq!(settings.ui.theme, "light");
+        assert_eq!(settings.ui.highlight_authors, vec!["alice", "bob"]);
         assert_eq!(settings.editor.custom_editor, Some("nvim".to_string()));
+        assert_eq!(
+            settings.hooks.on_pipeline_failed.as_deref(),
+            Some("notify-send 'Pipeline failed'")
+        );
+        assert!(settings.hooks.on_pipeline_succeeded.is_none());
+        assert_eq!(settings.hooks.on_refresh.as_deref(), Some("date >> ~/peeplab.log"));
+        assert_eq!(settings.keybindings.get("next_mr").map(String::as_str), Some("n"));
+        assert_eq!(settings.keybindings.get("refresh").map(String::as_str), Some("ctrl-r"));
     }
 
     #[test]
     fn test_validation_empty_token() {
-        let settings = Settings {
+        let mut settings = Settings {
             gitlab: GitLabConfig {
                 token: String::new(),
+                token_command: None,
+                token_env: None,
                 default_project_id: Some(1),
+                group_id: None,
                 instance_url: "https://gitlab.com".to_string(),
+                request_timeout_secs: 30,
+                max_retries: 2,
+                max_concurrent_requests: 4,
+                http_proxy: None,
+                https_proxy: None,
+                ca_cert_path: None,
             },
             app: AppConfig::default(),
             ui: UiConfig::default(),
             editor: EditorConfig::default(),
+            hooks: HooksConfig::default(),
+            keybindings: HashMap::new(),
         };
 
         assert!(settings.validate().is_err());
@@ -184,15 +511,26 @@ mod tests {
 
     #[test]
     fn test_validation_valid_token() {
-        let settings = Settings {
+        let mut settings = Settings {
             gitlab: GitLabConfig {
                 token: "valid-token".to_string(),
+                token_command: None,
+                token_env: None,
                 default_project_id: Some(1),
+                group_id: None,
                 instance_url: "https://gitlab.com".to_string(),
+                request_timeout_secs: 30,
+                max_retries: 2,
+                max_concurrent_requests: 4,
+                http_proxy: None,
+                https_proxy: None,
+                ca_cert_path: None,
             },
             app: AppConfig::default(),
             ui: UiConfig::default(),
             editor: EditorConfig::default(),
+            hooks: HooksConfig::default(),
+            keybindings: HashMap::new(),
         };
 
         assert!(settings.validate().is_ok());
@@ -204,6 +542,8 @@ mod tests {
         assert_eq!(config.refresh_interval, 30);
         assert_eq!(config.max_tracked_mrs, 5);
         assert_eq!(config.auto_refresh_interval_minutes, 1);
+        assert_eq!(config.mr_state, "opened");
+        assert!(!config.verify_git_project);
     }
 
     #[test]
@@ -211,6 +551,10 @@ mod tests {
         let config = UiConfig::default();
         assert!(config.relative_timestamps);
         assert_eq!(config.theme, "dark");
+        assert_eq!(config.notes_per_page, 100);
+        assert_eq!(config.notes_order, "desc");
+        assert_eq!(config.default_timestamp_mode, "hidden");
+        assert_eq!(config.job_sort, "status");
     }
 
     #[test]
@@ -218,4 +562,289 @@ mod tests {
         let config = EditorConfig::default();
         assert!(config.custom_editor.is_none());
     }
+
+    const PROFILES_TOML: &str = r#"
+        active_profile = "work"
+
+        [[profiles]]
+        name = "public"
+        token = "glpat-public"
+
+        [[profiles]]
+        name = "work"
+        token = "glpat-work"
+        instance_url = "https://gitlab.example.com"
+        default_project_id = 42
+
+        [app]
+        refresh_interval = 60
+    "#;
+
+    #[test]
+    fn test_profiles_use_active_profile() {
+        let config: ConfigFile = toml::from_str(PROFILES_TOML).unwrap();
+        assert_eq!(config.profiles.len(), 2);
+
+        let settings = config.into_settings(None).unwrap();
+        assert_eq!(settings.gitlab.token, "glpat-work");
+        assert_eq!(settings.gitlab.instance_url, "https://gitlab.example.com");
+        assert_eq!(settings.gitlab.default_project_id, Some(42));
+        assert_eq!(settings.app.refresh_interval, 60);
+    }
+
+    #[test]
+    fn test_profile_selected_by_name() {
+        let config: ConfigFile = toml::from_str(PROFILES_TOML).unwrap();
+        let settings = config.into_settings(Some("public")).unwrap();
+        assert_eq!(settings.gitlab.token, "glpat-public");
+        assert_eq!(settings.gitlab.instance_url, "https://gitlab.com");
+        assert!(settings.gitlab.default_project_id.is_none());
+    }
+
+    #[test]
+    fn test_unknown_profile_is_an_error() {
+        let config: ConfigFile = toml::from_str(PROFILES_TOML).unwrap();
+        let err = config.into_settings(Some("missing")).unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[test]
+    fn test_config_without_profiles_uses_gitlab_section() {
+        let config: ConfigFile = toml::from_str(
+            r#"
+            [gitlab]
+            token = "test-token"
+        "#,
+        )
+        .unwrap();
+        let settings = config.into_settings(None).unwrap();
+        assert_eq!(settings.gitlab.token, "test-token");
+
+        let empty: ConfigFile = toml::from_str("[app]\nrefresh_interval = 30").unwrap();
+        assert!(empty.into_settings(None).is_err());
+    }
+
+    fn token_config(token: &str, token_command: Option<&str>, token_env: Option<&str>) -> GitLabConfig {
+        GitLabConfig {
+            token: token.to_string(),
+            token_command: token_command.map(str::to_string),
+            token_env: token_env.map(str::to_string),
+            default_project_id: None,
+            group_id: None,
+            instance_url: default_instance_url(),
+            request_timeout_secs: default_request_timeout_secs(),
+            max_retries: default_max_retries(),
+            max_concurrent_requests: default_max_concurrent_requests(),
+            http_proxy: None,
+            https_proxy: None,
+            ca_cert_path: None,
+        }
+    }
+
+    #[test]
+    fn test_token_from_env() {
+        std::env::set_var("PEEPLAB_TEST_TOKEN_ENV", "glpat-from-env");
+        let mut config = token_config("", None, Some("PEEPLAB_TEST_TOKEN_ENV"));
+        config.resolve_token().unwrap();
+        assert_eq!(config.token, "glpat-from-env");
+    }
+
+    #[test]
+    fn test_token_from_command() {
+        let mut config = token_config("", Some("echo glpat-from-command"), Some("PEEPLAB_TEST_UNSET_VAR"));
+        config.resolve_token().unwrap();
+        assert_eq!(config.token, "glpat-from-command");
+
+        let mut failing = token_config("", Some("exit 3"), None);
+        assert!(failing.resolve_token().is_err());
+    }
+
+    #[test]
+    fn test_explicit_token_wins() {
+        let mut config = token_config("glpat-explicit", Some("echo other"), None);
+        config.resolve_token().unwrap();
+        assert_eq!(config.token, "glpat-explicit");
+    }
+
+    #[test]
+    fn test_error_when_no_token_resolves() {
+        let toml = r#"
+            [gitlab]
+            token_env = "PEEPLAB_TEST_UNSET_VAR"
+        "#;
+        let mut settings: Settings = toml::from_str(toml).unwrap();
+        settings.gitlab.resolve_token().unwrap();
+        assert!(settings.gitlab.token.is_empty());
+        assert!(settings.validate().is_err());
+    }
+
+    fn settings_with_instance_url(instance_url: &str) -> Settings {
+        let mut gitlab = token_config("valid-token", None, None);
+        gitlab.instance_url = instance_url.to_string();
+        Settings {
+            gitlab,
+            app: AppConfig::default(),
+            ui: UiConfig::default(),
+            editor: EditorConfig::default(),
+            hooks: HooksConfig::default(),
+            keybindings: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_instance_url_bare_host_rejected() {
+        let err = settings_with_instance_url("gitlab.com").validate().unwrap_err();
+        assert!(err.to_string().contains("gitlab.com"));
+    }
+
+    #[test]
+    fn test_instance_url_https_accepted_and_normalized() {
+        let mut settings = settings_with_instance_url("https://gitlab.example.com/");
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.gitlab.instance_url, "https://gitlab.example.com");
+
+        let mut with_path = settings_with_instance_url("http://localhost:8080/gitlab//");
+        assert!(with_path.validate().is_ok());
+        assert_eq!(with_path.gitlab.instance_url, "http://localhost:8080/gitlab");
+    }
+
+    #[test]
+    fn test_instance_url_ftp_scheme_rejected() {
+        let err = settings_with_instance_url("ftp://gitlab.com").validate().unwrap_err();
+        assert!(err.to_string().contains("http or https"));
+    }
+
+    #[test]
+    fn test_notes_options_validation() {
+        let mut settings = settings_with_instance_url("https://gitlab.com");
+        settings.ui.notes_per_page = 50;
+        settings.ui.notes_order = "asc".to_string();
+        assert!(settings.validate().is_ok());
+
+        settings.ui.notes_order = "newest".to_string();
+        assert!(settings.validate().unwrap_err().to_string().contains("notes_order"));
+
+        settings.ui.notes_order = "desc".to_string();
+        settings.ui.notes_per_page = 0;
+        assert!(settings.validate().is_err());
+        settings.ui.notes_per_page = 101;
+        assert!(settings.validate().unwrap_err().to_string().contains("notes_per_page"));
+    }
+
+    #[test]
+    fn test_proxy_config_parsing() {
+        let toml = r#"
+            [gitlab]
+            token = "test-token"
+            https_proxy = "http://proxy.corp.example:3128"
+        "#;
+
+        let settings: Settings = toml::from_str(toml).unwrap();
+        assert_eq!(
+            settings.gitlab.resolved_https_proxy().as_deref(),
+            Some("http://proxy.corp.example:3128")
+        );
+    }
+
+    #[test]
+    fn test_zero_request_timeout_rejected() {
+        let mut settings = settings_with_instance_url("https://gitlab.com");
+        settings.gitlab.request_timeout_secs = 0;
+        assert!(settings.validate().unwrap_err().to_string().contains("request_timeout_secs"));
+    }
+
+    #[test]
+    fn test_zero_max_concurrent_requests_rejected() {
+        let mut settings = settings_with_instance_url("https://gitlab.com");
+        settings.gitlab.max_concurrent_requests = 0;
+        assert!(settings.validate().unwrap_err().to_string().contains("max_concurrent_requests"));
+    }
+
+    #[test]
+    fn test_unknown_theme_rejected() {
+        let mut settings = settings_with_instance_url("https://gitlab.com");
+        settings.ui.theme = "light".to_string();
+        assert!(settings.validate().is_ok());
+
+        settings.ui.theme = "solarized".to_string();
+        assert!(settings.validate().unwrap_err().to_string().contains("theme"));
+    }
+
+    #[test]
+    fn test_notes_options_parsing() {
+        let toml = r#"
+            [gitlab]
+            token = "test-token"
+
+            [ui]
+            notes_per_page = 30
+            notes_order = "asc"
+        "#;
+        let settings: Settings = toml::from_str(toml).unwrap();
+        assert_eq!(settings.ui.notes_per_page, 30);
+        assert_eq!(settings.ui.notes_order, "asc");
+    }
+
+    #[test]
+    fn test_mr_state_parsing() {
+        let toml = r#"
+            [gitlab]
+            token = "test-token"
+
+            [app]
+            mr_state = "merged"
+        "#;
+        let mut settings: Settings = toml::from_str(toml).unwrap();
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.app.mr_state, "merged");
+
+        settings.app.mr_state = "locked".to_string();
+        assert!(settings.validate().unwrap_err().to_string().contains("mr_state"));
+    }
+
+    #[test]
+    fn test_job_sort_parsing() {
+        let toml = r#"
+            [gitlab]
+            token = "test-token"
+
+            [ui]
+            job_sort = "stage"
+        "#;
+        let mut settings: Settings = toml::from_str(toml).unwrap();
+        assert!(settings.validate().is_ok());
+        assert_eq!(crate::app::JobSort::from_name(&settings.ui.job_sort), Some(crate::app::JobSort::Stage));
+
+        settings.ui.job_sort = "duration".to_string();
+        assert!(settings.validate().unwrap_err().to_string().contains("job_sort"));
+    }
+
+    #[test]
+    fn test_default_timestamp_mode_parsing() {
+        use crate::app::TimestampDisplayMode;
+
+        let parse = |ui: &str| -> Settings {
+            toml::from_str(&format!("[gitlab]\ntoken = \"test-token\"\n\n[ui]\n{}", ui)).unwrap()
+        };
+        let cases = [
+            ("hidden", TimestampDisplayMode::Hidden),
+            ("date", TimestampDisplayMode::DateOnly),
+            ("full", TimestampDisplayMode::Full),
+        ];
+        for (name, mode) in cases {
+            let mut settings = parse(&format!("default_timestamp_mode = \"{}\"", name));
+            assert!(settings.validate().is_ok(), "{}", name);
+            assert_eq!(TimestampDisplayMode::from_name(&settings.ui.default_timestamp_mode), Some(mode));
+        }
+
+        // Unset keeps logs starting without timestamps
+        let settings = parse("");
+        assert_eq!(
+            TimestampDisplayMode::from_name(&settings.ui.default_timestamp_mode),
+            Some(TimestampDisplayMode::Hidden)
+        );
+
+        let mut settings = parse("default_timestamp_mode = \"utc\"");
+        assert!(settings.validate().unwrap_err().to_string().contains("default_timestamp_mode"));
+    }
 }
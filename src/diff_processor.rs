@@ -0,0 +1,118 @@
+use crate::gitlab::DiffFile;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Line,
+};
+
+/// Header line of a changed file, e.g. `src/lib.rs`, `old.md → new.md (renamed)`
+pub fn file_header(file: &DiffFile) -> String {
+    if file.new_file {
+        format!("{} (new file)", file.new_path)
+    } else if file.deleted_file {
+        format!("{} (deleted)", file.old_path)
+    } else if file.renamed_file || file.old_path != file.new_path {
+        format!("{} → {} (renamed)", file.old_path, file.new_path)
+    } else {
+        file.new_path.clone()
+    }
+}
+
+/// Style of a unified diff line: additions green, removals red, hunk headers cyan
+fn diff_line_style(line: &str) -> Style {
+    if line.starts_with("@@") {
+        Style::default().fg(Color::Cyan)
+    } else if line.starts_with('+') {
+        Style::default().fg(Color::Green)
+    } else if line.starts_with('-') {
+        Style::default().fg(Color::Red)
+    } else if line.starts_with('\\') {
+        // "\ No newline at end of file"
+        Style::default().fg(Color::DarkGray)
+    } else {
+        Style::default()
+    }
+}
+
+/// Render all changed files into lines for the diff viewer: a bold header per file,
+/// then its colored hunks, with a blank line between files
+pub fn process_diff(files: &[DiffFile]) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    for (index, file) in files.iter().enumerate() {
+        if index > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::styled(
+            file_header(file),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ));
+
+        if file.diff.is_empty() {
+            lines.push(Line::styled(
+                "(no textual changes)",
+                Style::default().fg(Color::DarkGray),
+            ));
+            continue;
+        }
+        for line in file.diff.lines() {
+            lines.push(Line::styled(line.to_string(), diff_line_style(line)));
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff_file(old_path: &str, new_path: &str, diff: &str) -> DiffFile {
+        DiffFile {
+            old_path: old_path.to_string(),
+            new_path: new_path.to_string(),
+            diff: diff.to_string(),
+            new_file: false,
+            renamed_file: false,
+            deleted_file: false,
+        }
+    }
+
+    #[test]
+    fn test_process_diff_colors_lines() {
+        let files = vec![
+            diff_file("src/lib.rs", "src/lib.rs", "@@ -1,2 +1,2 @@\n-old\n+new\n same\n"),
+            diff_file("a.md", "b.md", ""),
+        ];
+
+        let lines = process_diff(&files);
+        let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        assert_eq!(
+            text,
+            vec![
+                "src/lib.rs",
+                "@@ -1,2 +1,2 @@",
+                "-old",
+                "+new",
+                " same",
+                "",
+                "a.md → b.md (renamed)",
+                "(no textual changes)",
+            ]
+        );
+        assert_eq!(lines[1].style.fg, Some(Color::Cyan));
+        assert_eq!(lines[2].style.fg, Some(Color::Red));
+        assert_eq!(lines[3].style.fg, Some(Color::Green));
+        assert_eq!(lines[4].style.fg, None);
+    }
+
+    #[test]
+    fn test_file_header_new_and_deleted() {
+        let mut file = diff_file("new.rs", "new.rs", "+x\n");
+        file.new_file = true;
+        assert_eq!(file_header(&file), "new.rs (new file)");
+
+        let mut file = diff_file("gone.rs", "gone.rs", "-x\n");
+        file.deleted_file = true;
+        assert_eq!(file_header(&file), "gone.rs (deleted)");
+    }
+}
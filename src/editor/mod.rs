@@ -13,6 +13,7 @@ impl Drop for TerminalRestoreGuard {
         let _ = crossterm::execute!(
             std::io::stdout(),
             crossterm::terminal::EnterAlternateScreen,
+            crossterm::event::EnableMouseCapture,
             crossterm::cursor::Hide
         );
         let _ = crossterm::terminal::enable_raw_mode();
@@ -45,6 +46,7 @@ pub fn open_in_editor(content: &str) -> Result<()> {
     crossterm::execute!(
         std::io::stdout(),
         crossterm::terminal::LeaveAlternateScreen,
+        crossterm::event::DisableMouseCapture, // Clicks and wheel go to the editor
         crossterm::cursor::Show
     )?;
 
@@ -64,6 +66,7 @@ pub fn open_in_editor(content: &str) -> Result<()> {
     crossterm::execute!(
         std::io::stdout(),
         crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture,
         crossterm::cursor::Hide
     )?;
     crossterm::terminal::enable_raw_mode()?;
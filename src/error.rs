@@ -3,7 +3,10 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum PeeplabError {
     #[error("GitLab API error: {0}")]
-    GitLabApi(#[from] reqwest::Error),
+    GitLabApi(reqwest::Error),
+
+    #[error("GitLab API error ({status}): {message}")]
+    ApiStatus { status: u16, message: String }, // GitLab's explanation from the error body
 
     #[error("Configuration error: {0}")]
     Config(String),
@@ -20,8 +23,17 @@ pub enum PeeplabError {
     #[error("Editor launch failed: {0}")]
     EditorLaunch(String),
 
+    #[error("Couldn't open browser: {0}")]
+    BrowserLaunch(String),
+
+    #[error("Couldn't copy to clipboard: {0}")]
+    Clipboard(String),
+
     #[error("Authentication failed: {0}")]
-    Authentication(String),
+    Authentication(String), // 401: the token is invalid, expired or revoked
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String), // 403: a valid token without access to the resource
 
     #[error("Network error: {0}")]
     Network(String),
@@ -30,4 +42,17 @@ pub enum PeeplabError {
     NotFound(String),
 }
 
+impl From<reqwest::Error> for PeeplabError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            PeeplabError::Network(
+                "Request to GitLab timed out; the instance may be slow or unreachable (see request_timeout_secs)"
+                    .to_string(),
+            )
+        } else {
+            PeeplabError::GitLabApi(error)
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, PeeplabError>;
@@ -1,49 +1,122 @@
-use crate::gitlab::{Job, MergeRequest, Note, Pipeline};
+use crate::config::settings::Settings;
+use crate::gitlab::{Approvals, DiffFile, GitLabClient, Job, MergeRequest, MrStateFilter, Note, Pipeline};
 
 #[derive(Debug, Clone)]
 pub enum Action {
     // User Input Actions
-    Quit,
+    Quit,      // Asks for confirmation while fetches are in flight
+    ForceQuit, // Ctrl+C, or confirming the quit prompt
+    CancelQuit,
     NextMr,
     PrevMr,
+    SelectMrTab(usize), // Index into `tracked_mrs`, e.g. from a click on its tab
+    CycleMrSort,
+    CycleMrState, // Refetch the MR list in the next state: opened → merged → closed → all
+    ReloadConfig, // Re-read the config file without restarting
+    ToggleProjectPipelines,    // Open/close the project's own pipelines, independent of MRs
+    ToggleProjectPipelinesRef, // All refs or only the default branch's pipelines
+    RefreshProjectPipelines,
+    ProjectPipelinesLoaded(Vec<Pipeline>),
+    ProjectJobsLoaded { pipeline_id: u64, jobs: Vec<Job> },
+    ConfigReloaded {
+        settings: Box<Settings>,
+        client: Option<Box<GitLabClient>>, // Rebuilt when the token, instance or MR filter changed
+    },
+    ConfigReloadFailed(String),
     NextJob,
     PrevJob,
+    NextFailedJob, // Skip to the next failed job, wrapping around
+    PrevFailedJob,
     NextPipeline,
     PrevPipeline,
+    CycleJobStatusFilter,
+    ToggleFailedJobsFilter,
+    ToggleCollapsePassedJobs,
     SelectMr,
     OpenSelectedJobLog,
+    OpenJobLogAt(usize), // Select the visible job at this index and open its log (a click)
     Refresh,
+    RefreshCurrentMr, // Re-fetch only the selected MR's pipelines (and comments being read)
     RemoveCurrentMr,
     AddMr(u64), // Add MR by IID
+    RestoreRemovedMr, // Track again the MR removed last
     ShowHelp,
     HideHelp,
     ToggleCommentsView,
     NextNote,
     PrevNote,
-    CloseLogViewer,
+    NextThread, // First note of the next discussion thread
+    PrevThread,
+    ScrollCommentsUp,
+    ScrollCommentsDown,
+    ToggleCompactComments,
+    ToggleSystemNotes,
+    OpenSelectedNote, // The selected comment full-screen
+    ToggleDescriptionView,
+    ScrollDescriptionUp,
+    ScrollDescriptionDown,
+    ViewMrChanges,
+    ShowFailureSummary, // Log tails of every failed job in the selected pipeline
+    CloseLogViewer, // Also closes the diff viewer, the failure summary and the comment viewer
     ScrollLogUp,
     ScrollLogDown,
     ScrollLogPageUp,
     ScrollLogPageDown,
     ScrollLogHome,
     ScrollLogEnd,
+    ScrollLogLeft,  // Only while wrapping is off
+    ScrollLogRight,
+    ToggleLogWrap,
     ToggleTimestampMode,
+    ToggleRawLog,
     StartSearch,
     UpdateSearchQuery(String),
     ExecuteSearch,
     NextSearchResult,
     PrevSearchResult,
     CancelSearch,
+    ToggleSearchCaseSensitive,
+    StartGlobalSearch, // Search the logs of every tracked MR's failed jobs
+    UpdateGlobalSearchQuery(String),
+    ExecuteGlobalSearch,
+    NextGlobalSearchResult,
+    PrevGlobalSearchResult,
+    OpenGlobalSearchResult, // Open the selected result's log at the matching line
+    CloseGlobalSearch,
+    ToggleLogDeltaMode,
     OpenMrInBrowser,
+    OpenJobInBrowser,
+    OpenPipelineInBrowser,
+    StartYank, // `y`: the next key picks what to copy
+    CancelYank,
+    CopyMrUrl,
+    CopyMrBranch,
+    DownloadArtifacts, // Of the selected job
+    RequestRetryPipeline,
+    ConfirmRetryPipeline,
+    CancelRetryPipeline,
+    ToggleAutoRefreshPause,
+    ToggleBranchFocus,
+    StartNote,
+    UpdateNoteDraft(String),
+    SubmitNote,
+    CancelNote,
+    ToggleApproval,
 
     // API Response Actions
-    MergeRequestsLoaded(Vec<MergeRequest>),
+    MergeRequestsLoaded(Vec<MergeRequest>), // Reply to `RefreshAll`, which it ends
+    MoreMergeRequestsLoaded(Vec<MergeRequest>), // Other MR fetches (by branch); a refresh stays pending
+    MergeRequestsByIidsLoaded {
+        project_id: u64,
+        iids: Vec<u64>, // The IIDs asked for; saved ones that didn't come back are forgotten
+        mrs: Vec<MergeRequest>,
+    },
     PipelinesLoaded {
-        mr_index: usize,
+        mr_id: u64, // Global ID of the MR the request was about, looked up again on arrival
         pipelines: Vec<Pipeline>,
     },
     JobsLoaded {
-        mr_index: usize,
+        mr_id: u64,
         pipeline_id: u64,
         jobs: Vec<Job>,
     },
@@ -52,13 +125,60 @@ pub enum Action {
         job_name: String,
         trace: String,
     },
+    GlobalSearchTraceLoaded {
+        mr_id: u64,
+        job_id: u64,
+        trace: String,
+    },
+    GlobalSearchTraceFailed(String),
+    FailureSummaryTraceLoaded {
+        mr_id: u64,
+        job_id: u64,
+        trace: String,
+    },
+    FailureSummaryTraceFailed {
+        job_id: u64,
+        error: String,
+    },
     NotesLoaded {
-        mr_index: usize,
+        mr_id: u64,
         notes: Vec<Note>,
     },
+    MrChangesLoaded {
+        mr_id: u64,
+        changes: Vec<DiffFile>,
+    },
+    PipelineRetried {
+        mr_id: u64,
+        pipeline: Pipeline,
+    },
+    ApprovalsLoaded {
+        mr_id: u64,
+        approvals: Approvals,
+    },
+    NotePosted {
+        mr_id: u64,
+    },
+    ApprovalChanged {
+        mr_id: u64,
+        approved: bool,
+    },
+    ArtifactsDownloaded {
+        job_name: String,
+        path: std::path::PathBuf,
+        bytes: u64,
+    },
 
     // Error Actions
+    RefreshFailed(String), // `RefreshAll` failed, ending the refresh
     ApiError(String),
+    OpenUrlFailed(String),
+    CopyFailed(String),
+    ArtifactsDownloadFailed(String),
+    MrApiError {
+        mr_id: u64, // The request was about this MR; the error stays on its tab
+        error: String,
+    },
 
     // Tick for auto-refresh
     Tick,
@@ -67,15 +187,146 @@ pub enum Action {
     None,
 }
 
+impl Action {
+    /// Whether this action is the reply to an API request started by an effect
+    pub fn is_api_response(&self) -> bool {
+        matches!(
+            self,
+            Action::MergeRequestsLoaded(_)
+                | Action::MoreMergeRequestsLoaded(_)
+                | Action::MergeRequestsByIidsLoaded { .. }
+                | Action::PipelinesLoaded { .. }
+                | Action::ProjectPipelinesLoaded(_)
+                | Action::ProjectJobsLoaded { .. }
+                | Action::JobsLoaded { .. }
+                | Action::JobTraceLoaded { .. }
+                | Action::GlobalSearchTraceLoaded { .. }
+                | Action::GlobalSearchTraceFailed(_)
+                | Action::FailureSummaryTraceLoaded { .. }
+                | Action::FailureSummaryTraceFailed { .. }
+                | Action::NotesLoaded { .. }
+                | Action::MrChangesLoaded { .. }
+                | Action::PipelineRetried { .. }
+                | Action::ApprovalsLoaded { .. }
+                | Action::NotePosted { .. }
+                | Action::ApprovalChanged { .. }
+                | Action::ArtifactsDownloaded { .. }
+                | Action::ArtifactsDownloadFailed(_)
+                | Action::RefreshFailed(_)
+                | Action::ApiError(_)
+                | Action::MrApiError { .. }
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Effect {
     FetchMergeRequests { project_id: u64 },
     FetchMergeRequestsByBranch { project_id: u64, source_branch: String },
-    FetchPipelines { mr_index: usize, project_id: u64, mr_iid: u64 },
-    FetchJobs { mr_index: usize, project_id: u64, pipeline_id: u64 },
+    FetchMergeRequestsByIids { project_id: u64, iids: Vec<u64> },
+    FetchPipelines { mr_id: u64, project_id: u64, mr_iid: u64 },
+    FetchJobs { mr_id: u64, project_id: u64, pipeline_id: u64 },
+    FetchProjectPipelines { project_id: u64, ref_name: Option<String> },
+    FetchProjectJobs { project_id: u64, pipeline_id: u64 },
     FetchJobTrace { project_id: u64, job_id: u64, job_name: String },
-    FetchNotes { mr_index: usize, project_id: u64, mr_iid: u64 },
+    FetchGlobalSearchTrace { mr_id: u64, project_id: u64, job_id: u64 },
+    FetchFailureSummaryTrace { mr_id: u64, project_id: u64, job_id: u64 },
+    FetchNotes { mr_id: u64, project_id: u64, mr_iid: u64 },
+    FetchMrChanges { mr_id: u64, project_id: u64, mr_iid: u64 },
     OpenInEditor(String),
-    RefreshAll { project_id: u64, source_branch: Option<String> },
+    RefreshAll {
+        project_id: u64,
+        group_id: Option<u64>,
+        source_branch: Option<String>,
+        state: MrStateFilter,
+    },
     OpenUrl(String),
+    CopyToClipboard(String),
+    RetryPipeline { mr_id: u64, project_id: u64, pipeline_id: u64 },
+    PostNote { mr_id: u64, project_id: u64, mr_iid: u64, body: String },
+    SetApproval { mr_id: u64, project_id: u64, mr_iid: u64, approve: bool },
+    FetchApprovals { mr_id: u64, project_id: u64, mr_iid: u64 },
+    DownloadArtifacts { project_id: u64, job_id: u64, job_name: String, dest: std::path::PathBuf },
+    ReloadConfig, // Spawned by the main loop, which installs the client it may rebuild
+    Batch(Vec<Effect>), // Independent effects, all dispatched at once
+}
+
+impl Effect {
+    /// Combine effects: nothing for none, the effect itself for one, otherwise a `Batch`
+    pub fn batch(effects: Vec<Effect>) -> Option<Effect> {
+        let mut effects: Vec<Effect> = effects.into_iter().flat_map(Effect::into_effects).collect();
+        match effects.len() {
+            0 => None,
+            1 => effects.pop(),
+            _ => Some(Effect::Batch(effects)),
+        }
+    }
+
+    /// The individual effects to dispatch, with (nested) batches flattened in order
+    pub fn into_effects(self) -> Vec<Effect> {
+        match self {
+            Effect::Batch(effects) => effects.into_iter().flat_map(Effect::into_effects).collect(),
+            effect => vec![effect],
+        }
+    }
+
+    /// How many API response actions handling this effect will send back
+    pub fn api_request_count(&self) -> usize {
+        match self {
+            // Approvals are fetched alongside pipelines
+            Effect::FetchPipelines { .. } => 2,
+            Effect::OpenInEditor(_)
+            | Effect::OpenUrl(_)
+            | Effect::CopyToClipboard(_)
+            | Effect::ReloadConfig => 0,
+            Effect::Batch(effects) => effects.iter().map(Effect::api_request_count).sum(),
+            _ => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fetch_notes(mr_id: u64) -> Effect {
+        Effect::FetchNotes { mr_id, project_id: 1, mr_iid: mr_id }
+    }
+
+    fn note_ids(effects: &[Effect]) -> Vec<u64> {
+        effects
+            .iter()
+            .map(|effect| match effect {
+                Effect::FetchNotes { mr_id, .. } => *mr_id,
+                other => panic!("Expected FetchNotes, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_fans_out_in_order() {
+        let nested = Effect::Batch(vec![
+            fetch_notes(0),
+            Effect::Batch(vec![fetch_notes(1), fetch_notes(2)]),
+            fetch_notes(3),
+        ]);
+        assert_eq!(nested.api_request_count(), 4);
+        assert_eq!(note_ids(&nested.into_effects()), vec![0, 1, 2, 3]);
+
+        assert_eq!(note_ids(&fetch_notes(5).into_effects()), vec![5]);
+    }
+
+    #[test]
+    fn test_batch_of_zero_or_one_effect() {
+        assert!(Effect::batch(vec![]).is_none());
+        assert!(Effect::batch(vec![Effect::Batch(vec![])]).is_none());
+        assert!(matches!(Effect::batch(vec![fetch_notes(0)]), Some(Effect::FetchNotes { .. })));
+
+        let api_counts = Effect::batch(vec![
+            Effect::FetchPipelines { mr_id: 1, project_id: 1, mr_iid: 1 },
+            Effect::OpenUrl("https://gitlab.com".to_string()),
+        ])
+        .map(|effect| effect.api_request_count());
+        assert_eq!(api_counts, Some(2));
+    }
 }
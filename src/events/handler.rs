@@ -1,14 +1,19 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::time::Duration;
 use tokio::sync::mpsc;
 
 #[derive(Debug)]
 pub enum AppEvent {
     Input(KeyEvent),
+    Mouse(MouseEvent), // Wheel and button events; pointer motion isn't forwarded
     Tick,
     Resize,
+    Redraw, // Repaint only, so the status bar's refresh countdown keeps moving between ticks
 }
 
+/// How often the screen is repainted without input, whatever the tick rate
+const REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<AppEvent>,
     _tx: mpsc::UnboundedSender<AppEvent>, // Keep alive for senders
@@ -24,18 +29,18 @@ impl EventHandler {
             loop {
                 // Poll with very short timeout for responsive input
                 if let Ok(true) = event::poll(Duration::from_millis(16)) {
-                    match event::read() {
-                        Ok(Event::Key(key)) => {
-                            if input_tx.send(AppEvent::Input(key)).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(Event::Resize(_, _)) => {
-                            if input_tx.send(AppEvent::Resize).is_err() {
-                                break;
-                            }
+                    let app_event = match event::read() {
+                        Ok(Event::Key(key)) => AppEvent::Input(key),
+                        Ok(Event::Mouse(mouse))
+                            if !matches!(mouse.kind, MouseEventKind::Moved | MouseEventKind::Drag(_)) =>
+                        {
+                            AppEvent::Mouse(mouse)
                         }
-                        _ => {}
+                        Ok(Event::Resize(_, _)) => AppEvent::Resize,
+                        _ => continue,
+                    };
+                    if input_tx.send(app_event).is_err() {
+                        break;
                     }
                 }
             }
@@ -53,6 +58,18 @@ impl EventHandler {
             }
         });
 
+        // Spawn redraw handler
+        let redraw_tx = tx.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(REDRAW_INTERVAL);
+            loop {
+                interval.tick().await;
+                if redraw_tx.send(AppEvent::Redraw).is_err() {
+                    break;
+                }
+            }
+        });
+
         Self { rx, _tx: tx }
     }
 
@@ -63,44 +80,177 @@ impl EventHandler {
 
 use crate::app::{App, AppMode};
 use crate::events::actions::Action;
+use crate::ui::layout::ClickTarget;
 
 pub fn map_event_to_action(event: AppEvent, app: &App) -> Action {
+    // User remappings from [keybindings] take precedence over the built-in keys
+    if let AppEvent::Input(key) = &event {
+        if let Some(action) = app.keymap.lookup(key, app) {
+            return action;
+        }
+    }
+
+    // Second key of a `y` chord; anything else abandons the copy
+    if let AppEvent::Input(key) = &event {
+        if app.pending_yank && app.mode == AppMode::Normal {
+            return match key.code {
+                KeyCode::Char('u') => Action::CopyMrUrl,
+                KeyCode::Char('b') => Action::CopyMrBranch,
+                _ => Action::CancelYank,
+            };
+        }
+    }
+
     match event {
         AppEvent::Input(key) => match app.mode {
             AppMode::Normal => match key.code {
                 KeyCode::Char('q') => Action::Quit,
                 KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    Action::Quit
+                    Action::ForceQuit
                 }
                 KeyCode::Char('?') => Action::ShowHelp,
                 KeyCode::Char('c') => Action::ToggleCommentsView,
                 KeyCode::Left | KeyCode::Char('h') => Action::PrevMr,
                 KeyCode::Right | KeyCode::Char('l') => Action::NextMr,
+                KeyCode::Char('S') => Action::CycleMrSort,
+                KeyCode::Char('M') => Action::CycleMrState,
+                KeyCode::F(5) => Action::ReloadConfig,
                 KeyCode::Up | KeyCode::Char('k') => Action::PrevJob,
                 KeyCode::Down | KeyCode::Char('j') => Action::NextJob,
+                KeyCode::Char('N') => Action::NextFailedJob,
+                KeyCode::Char('P') => Action::PrevFailedJob,
                 KeyCode::Char('[') => Action::PrevPipeline,
                 KeyCode::Char(']') => Action::NextPipeline,
                 KeyCode::Enter => Action::OpenSelectedJobLog,
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::RefreshCurrentMr
+                }
                 KeyCode::Char('r') => Action::Refresh,
                 KeyCode::Char('d') => Action::RemoveCurrentMr,
+                KeyCode::Char('u') => Action::RestoreRemovedMr,
                 KeyCode::Char('o') => Action::OpenMrInBrowser,
+                KeyCode::Char('w') => Action::OpenJobInBrowser,
+                KeyCode::Char('b') => Action::ToggleBranchFocus,
+                KeyCode::Char('O') => Action::OpenPipelineInBrowser,
+                KeyCode::Char('y') => Action::StartYank,
+                KeyCode::Char('/') => Action::StartGlobalSearch,
+                KeyCode::Char('i') => Action::ToggleDescriptionView,
+                KeyCode::Char('v') => Action::ViewMrChanges,
+                KeyCode::Char('e') => Action::ShowFailureSummary,
+                KeyCode::Char('D') => Action::DownloadArtifacts,
+                KeyCode::Char('R') => Action::RequestRetryPipeline,
+                KeyCode::Char('A') => Action::ToggleApproval,
+                KeyCode::Char('p') => Action::ToggleAutoRefreshPause,
+                KeyCode::Char('F') => Action::CycleJobStatusFilter,
+                KeyCode::Char('f') => Action::ToggleFailedJobsFilter,
+                KeyCode::Char('z') => Action::ToggleCollapsePassedJobs,
+                KeyCode::Char('g') => Action::ToggleProjectPipelines,
+                _ => Action::None,
+            },
+            AppMode::ViewingProjectPipelines => match key.code {
+                KeyCode::Char('q') => Action::Quit,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Char('?') => Action::ShowHelp,
+                KeyCode::Char('g') | KeyCode::Esc => Action::ToggleProjectPipelines,
+                KeyCode::Up | KeyCode::Char('k') => Action::PrevJob,
+                KeyCode::Down | KeyCode::Char('j') => Action::NextJob,
+                KeyCode::Char('N') => Action::NextFailedJob,
+                KeyCode::Char('P') => Action::PrevFailedJob,
+                KeyCode::Char('[') => Action::PrevPipeline,
+                KeyCode::Char(']') => Action::NextPipeline,
+                KeyCode::Enter => Action::OpenSelectedJobLog,
+                KeyCode::Char('r') => Action::RefreshProjectPipelines,
+                KeyCode::Char('b') => Action::ToggleProjectPipelinesRef,
+                KeyCode::Char('w') => Action::OpenJobInBrowser,
+                KeyCode::Char('O') => Action::OpenPipelineInBrowser,
+                KeyCode::Char('D') => Action::DownloadArtifacts,
+                KeyCode::Char('p') => Action::ToggleAutoRefreshPause,
+                KeyCode::Char('F') => Action::CycleJobStatusFilter,
+                KeyCode::Char('f') => Action::ToggleFailedJobsFilter,
+                KeyCode::Char('z') => Action::ToggleCollapsePassedJobs,
+                _ => Action::None,
+            },
+            AppMode::ViewingDescription => match key.code {
+                KeyCode::Char('q') => Action::Quit,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Char('?') => Action::ShowHelp,
+                KeyCode::Char('i') | KeyCode::Esc => Action::ToggleDescriptionView,
+                KeyCode::Left | KeyCode::Char('h') => Action::PrevMr,
+                KeyCode::Right | KeyCode::Char('l') => Action::NextMr,
+                KeyCode::Char('S') => Action::CycleMrSort,
+                KeyCode::Char('M') => Action::CycleMrState,
+                KeyCode::F(5) => Action::ReloadConfig,
+                KeyCode::Up | KeyCode::Char('k') => Action::ScrollDescriptionUp,
+                KeyCode::Down | KeyCode::Char('j') => Action::ScrollDescriptionDown,
+                KeyCode::Char('o') => Action::OpenMrInBrowser,
+                KeyCode::Char('b') => Action::ToggleBranchFocus,
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::RefreshCurrentMr
+                }
+                _ => Action::None,
+            },
+            // Comment input: Enter posts, Alt+Enter adds a line break
+            AppMode::ViewingComments if app.is_composing_note => match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Esc => Action::CancelNote,
+                KeyCode::Enter if key.modifiers.contains(KeyModifiers::ALT) => {
+                    let mut draft = app.note_draft.clone();
+                    draft.push('\n');
+                    Action::UpdateNoteDraft(draft)
+                }
+                KeyCode::Enter => Action::SubmitNote,
+                KeyCode::Char(c) => {
+                    let mut draft = app.note_draft.clone();
+                    draft.push(c);
+                    Action::UpdateNoteDraft(draft)
+                }
+                KeyCode::Backspace => {
+                    let mut draft = app.note_draft.clone();
+                    draft.pop();
+                    Action::UpdateNoteDraft(draft)
+                }
                 _ => Action::None,
             },
             AppMode::ViewingComments => match key.code {
                 KeyCode::Char('q') => Action::Quit,
                 KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    Action::Quit
+                    Action::ForceQuit
                 }
                 KeyCode::Char('?') => Action::ShowHelp,
                 KeyCode::Char('c') => Action::ToggleCommentsView,
                 KeyCode::Left | KeyCode::Char('h') => Action::PrevMr,
                 KeyCode::Right | KeyCode::Char('l') => Action::NextMr,
+                KeyCode::Char('S') => Action::CycleMrSort,
+                KeyCode::Char('M') => Action::CycleMrState,
+                KeyCode::F(5) => Action::ReloadConfig,
                 KeyCode::Up | KeyCode::Char('k') => Action::PrevNote,
                 KeyCode::Down | KeyCode::Char('j') => Action::NextNote,
+                KeyCode::Char('t') => Action::NextThread,
+                KeyCode::Char('T') => Action::PrevThread,
+                KeyCode::Char('K') => Action::ScrollCommentsUp,
+                KeyCode::Char('J') => Action::ScrollCommentsDown,
+                KeyCode::Char('z') => Action::ToggleCompactComments,
+                KeyCode::Char('s') => Action::ToggleSystemNotes,
+                KeyCode::Enter => Action::OpenSelectedNote,
+                KeyCode::Char('a') => Action::StartNote,
+                KeyCode::Char('A') => Action::ToggleApproval,
+                KeyCode::Char('i') => Action::ToggleDescriptionView,
+                KeyCode::Char('b') => Action::ToggleBranchFocus,
                 KeyCode::Char('[') => Action::PrevPipeline,
                 KeyCode::Char(']') => Action::NextPipeline,
+                KeyCode::Char('O') => Action::OpenPipelineInBrowser,
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::RefreshCurrentMr
+                }
                 KeyCode::Char('r') => Action::Refresh,
                 KeyCode::Char('d') => Action::RemoveCurrentMr,
+                KeyCode::Char('p') => Action::ToggleAutoRefreshPause,
                 _ => Action::None,
             },
             AppMode::ViewingLog => {
@@ -109,6 +259,10 @@ pub fn map_event_to_action(event: AppEvent, app: &App) -> Action {
                     match key.code {
                         KeyCode::Esc => Action::CancelSearch,
                         KeyCode::Enter => Action::ExecuteSearch,
+                        // Plain `c` is part of the query while typing
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::ALT) => {
+                            Action::ToggleSearchCaseSensitive
+                        }
                         KeyCode::Char(c) => {
                             let mut query = app.search_query.clone();
                             query.push(c);
@@ -126,7 +280,7 @@ pub fn map_event_to_action(event: AppEvent, app: &App) -> Action {
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Esc => Action::CloseLogViewer,
                         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                            Action::Quit
+                            Action::ForceQuit
                         }
                         KeyCode::Up | KeyCode::Char('k') => Action::ScrollLogUp,
                         KeyCode::Down | KeyCode::Char('j') => Action::ScrollLogDown,
@@ -135,24 +289,280 @@ pub fn map_event_to_action(event: AppEvent, app: &App) -> Action {
                         KeyCode::Home => Action::ScrollLogHome,
                         KeyCode::End => Action::ScrollLogEnd,
                         KeyCode::Char('t') => Action::ToggleTimestampMode,
+                        KeyCode::Char('R') => Action::ToggleRawLog,
+                        KeyCode::Char('w') => Action::ToggleLogWrap,
+                        KeyCode::Char('H') | KeyCode::Left => Action::ScrollLogLeft,
+                        KeyCode::Char('L') | KeyCode::Right => Action::ScrollLogRight,
                         KeyCode::Char('/') => Action::StartSearch,
                         KeyCode::Char('n') => Action::NextSearchResult,
                         KeyCode::Char('N') => Action::PrevSearchResult,
+                        KeyCode::Char('d') => Action::ToggleLogDeltaMode,
+                        KeyCode::Char('c') => Action::ToggleSearchCaseSensitive,
+                        KeyCode::Char('o') => Action::OpenJobInBrowser,
+                        _ => Action::None,
+                    }
+                }
+            },
+            AppMode::ViewingDiff => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('v') => Action::CloseLogViewer,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Up | KeyCode::Char('k') => Action::ScrollLogUp,
+                KeyCode::Down | KeyCode::Char('j') => Action::ScrollLogDown,
+                KeyCode::PageUp => Action::ScrollLogPageUp,
+                KeyCode::PageDown => Action::ScrollLogPageDown,
+                KeyCode::Home => Action::ScrollLogHome,
+                KeyCode::End => Action::ScrollLogEnd,
+                KeyCode::Char('o') => Action::OpenMrInBrowser,
+                _ => Action::None,
+            },
+            AppMode::ViewingFailureSummary => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('e') => Action::CloseLogViewer,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Up | KeyCode::Char('k') => Action::ScrollLogUp,
+                KeyCode::Down | KeyCode::Char('j') => Action::ScrollLogDown,
+                KeyCode::PageUp => Action::ScrollLogPageUp,
+                KeyCode::PageDown => Action::ScrollLogPageDown,
+                KeyCode::Home => Action::ScrollLogHome,
+                KeyCode::End => Action::ScrollLogEnd,
+                KeyCode::Char('O') => Action::OpenPipelineInBrowser,
+                _ => Action::None,
+            },
+            AppMode::ViewingNote => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => Action::CloseLogViewer,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                KeyCode::Up | KeyCode::Char('k') => Action::ScrollLogUp,
+                KeyCode::Down | KeyCode::Char('j') => Action::ScrollLogDown,
+                KeyCode::PageUp => Action::ScrollLogPageUp,
+                KeyCode::PageDown => Action::ScrollLogPageDown,
+                KeyCode::Home => Action::ScrollLogHome,
+                KeyCode::End => Action::ScrollLogEnd,
+                _ => Action::None,
+            },
+            AppMode::GlobalSearch => {
+                if app.is_global_searching {
+                    match key.code {
+                        KeyCode::Esc => Action::CloseGlobalSearch,
+                        KeyCode::Enter => Action::ExecuteGlobalSearch,
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            Action::ForceQuit
+                        }
+                        KeyCode::Char(c) => {
+                            let mut query = app.global_search_query.clone();
+                            query.push(c);
+                            Action::UpdateGlobalSearchQuery(query)
+                        }
+                        KeyCode::Backspace => {
+                            let mut query = app.global_search_query.clone();
+                            query.pop();
+                            Action::UpdateGlobalSearchQuery(query)
+                        }
+                        _ => Action::None,
+                    }
+                } else {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => Action::CloseGlobalSearch,
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            Action::ForceQuit
+                        }
+                        KeyCode::Up | KeyCode::Char('k') => Action::PrevGlobalSearchResult,
+                        KeyCode::Down | KeyCode::Char('j') => Action::NextGlobalSearchResult,
+                        KeyCode::Enter => Action::OpenGlobalSearchResult,
+                        KeyCode::Char('/') => Action::StartGlobalSearch,
                         _ => Action::None,
                     }
                 }
+            }
+            AppMode::ConfirmingRetry => match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => Action::ConfirmRetryPipeline,
+                KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => Action::CancelRetryPipeline,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                _ => Action::None,
             },
             AppMode::SelectingMr => match key.code {
                 KeyCode::Esc => Action::None, // Exit selection mode
                 KeyCode::Char('q') => Action::Quit,
                 _ => Action::None,
             },
+            AppMode::ConfirmingQuit => match key.code {
+                KeyCode::Char('y') | KeyCode::Enter | KeyCode::Char('q') => Action::ForceQuit,
+                KeyCode::Char('n') | KeyCode::Esc => Action::CancelQuit,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::ForceQuit
+                }
+                _ => Action::None,
+            },
             AppMode::ShowingHelp => match key.code {
                 KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => Action::HideHelp,
                 _ => Action::None,
             },
         },
+        AppEvent::Mouse(mouse) => map_mouse_event(mouse, app),
         AppEvent::Tick => Action::Tick,
-        AppEvent::Resize => Action::None,
+        AppEvent::Resize | AppEvent::Redraw => Action::None,
+    }
+}
+
+/// The wheel scrolls whatever the current view scrolls with the arrow keys; a left
+/// click selects an MR tab or opens a job's log
+fn map_mouse_event(mouse: MouseEvent, app: &App) -> Action {
+    let down = match mouse.kind {
+        MouseEventKind::ScrollDown => true,
+        MouseEventKind::ScrollUp => false,
+        MouseEventKind::Down(MouseButton::Left) => return map_click(mouse.column, mouse.row, app),
+        _ => return Action::None,
+    };
+
+    match (&app.mode, down) {
+        (_, true) if app.is_viewing_lines() => Action::ScrollLogDown,
+        (_, false) if app.is_viewing_lines() => Action::ScrollLogUp,
+        (AppMode::ViewingComments, true) => Action::ScrollCommentsDown,
+        (AppMode::ViewingComments, false) => Action::ScrollCommentsUp,
+        (AppMode::ViewingDescription, true) => Action::ScrollDescriptionDown,
+        (AppMode::ViewingDescription, false) => Action::ScrollDescriptionUp,
+        (AppMode::Normal | AppMode::ViewingProjectPipelines, true) => Action::NextJob,
+        (AppMode::Normal | AppMode::ViewingProjectPipelines, false) => Action::PrevJob,
+        (AppMode::GlobalSearch, true) => Action::NextGlobalSearchResult,
+        (AppMode::GlobalSearch, false) => Action::PrevGlobalSearchResult,
+        _ => Action::None,
+    }
+}
+
+/// Clicks only apply to the main views, not through popups and viewers on top
+fn map_click(column: u16, row: u16, app: &App) -> Action {
+    let main_view = matches!(
+        app.mode,
+        AppMode::Normal
+            | AppMode::ViewingComments
+            | AppMode::ViewingDescription
+            | AppMode::ViewingProjectPipelines
+    );
+    if !main_view || app.is_composing_note {
+        return Action::None;
+    }
+
+    match app.click_areas.hit_test(column, row) {
+        Some(ClickTarget::MrTab(mr_index)) => Action::SelectMrTab(mr_index),
+        Some(ClickTarget::JobRow(job_index))
+            if matches!(app.mode, AppMode::Normal | AppMode::ViewingProjectPipelines) =>
+        {
+            Action::OpenJobLogAt(job_index)
+        }
+        _ => Action::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mouse(kind: MouseEventKind) -> AppEvent {
+        click_at(kind, 10, 10)
+    }
+
+    fn click_at(kind: MouseEventKind, column: u16, row: u16) -> AppEvent {
+        AppEvent::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn app_in(mode: AppMode) -> App {
+        let mut app = App::new(123, None, false, 1);
+        app.mode = mode;
+        app
+    }
+
+    fn key(code: KeyCode) -> AppEvent {
+        AppEvent::Input(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_yank_chord_picks_second_key() {
+        let mut app = app_in(AppMode::Normal);
+        assert!(matches!(map_event_to_action(key(KeyCode::Char('y')), &app), Action::StartYank));
+
+        app.pending_yank = true;
+        assert!(matches!(map_event_to_action(key(KeyCode::Char('u')), &app), Action::CopyMrUrl));
+        assert!(matches!(map_event_to_action(key(KeyCode::Char('b')), &app), Action::CopyMrBranch));
+        assert!(matches!(map_event_to_action(key(KeyCode::Char('j')), &app), Action::CancelYank));
+    }
+
+    #[test]
+    fn test_ctrl_c_quits_while_composing_note() {
+        let mut app = app_in(AppMode::ViewingComments);
+        app.is_composing_note = true;
+        let ctrl_c = AppEvent::Input(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(map_event_to_action(ctrl_c, &app), Action::ForceQuit));
+        // Without Ctrl the letter goes into the draft
+        assert!(matches!(
+            map_event_to_action(key(KeyCode::Char('c')), &app),
+            Action::UpdateNoteDraft(draft) if draft == "c"
+        ));
+    }
+
+    #[test]
+    fn test_scroll_down_maps_per_mode() {
+        let cases = [
+            (AppMode::ViewingLog, "ScrollLogDown"),
+            (AppMode::ViewingDiff, "ScrollLogDown"),
+            (AppMode::ViewingFailureSummary, "ScrollLogDown"),
+            (AppMode::ViewingNote, "ScrollLogDown"),
+            (AppMode::ViewingComments, "ScrollCommentsDown"),
+            (AppMode::ViewingDescription, "ScrollDescriptionDown"),
+            (AppMode::Normal, "NextJob"),
+            (AppMode::ViewingProjectPipelines, "NextJob"),
+            (AppMode::ShowingHelp, "None"),
+        ];
+        for (mode, expected) in cases {
+            let action = map_event_to_action(mouse(MouseEventKind::ScrollDown), &app_in(mode.clone()));
+            assert_eq!(format!("{:?}", action), expected, "in {:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_scroll_up_in_log_and_other_mouse_events() {
+        let app = app_in(AppMode::ViewingLog);
+        assert!(matches!(
+            map_event_to_action(mouse(MouseEventKind::ScrollUp), &app),
+            Action::ScrollLogUp
+        ));
+        assert!(matches!(
+            map_event_to_action(mouse(MouseEventKind::Moved), &app),
+            Action::None
+        ));
+    }
+
+    #[test]
+    fn test_left_click_on_tab_and_job_row() {
+        use crate::ui::layout::{ClickAreas, JobRowsArea};
+        use ratatui::layout::Rect;
+
+        let mut app = app_in(AppMode::Normal);
+        app.click_areas = ClickAreas {
+            mr_tabs: vec![(Rect::new(1, 1, 10, 1), 1)],
+            job_rows: Some(JobRowsArea { area: Rect::new(1, 16, 40, 5), offset: 0, count: 3 }),
+        };
+        let left = MouseEventKind::Down(MouseButton::Left);
+
+        assert!(matches!(map_event_to_action(click_at(left, 4, 1), &app), Action::SelectMrTab(1)));
+        assert!(matches!(map_event_to_action(click_at(left, 4, 17), &app), Action::OpenJobLogAt(1)));
+        assert!(matches!(map_event_to_action(click_at(left, 4, 30), &app), Action::None));
+
+        // Job rows aren't on screen in the comments view; the log viewer covers everything
+        app.mode = AppMode::ViewingComments;
+        assert!(matches!(map_event_to_action(click_at(left, 4, 17), &app), Action::None));
+        assert!(matches!(map_event_to_action(click_at(left, 4, 1), &app), Action::SelectMrTab(1)));
+        app.mode = AppMode::ViewingLog;
+        assert!(matches!(map_event_to_action(click_at(left, 4, 1), &app), Action::None));
     }
 }
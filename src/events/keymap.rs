@@ -0,0 +1,291 @@
+use crate::app::{App, AppMode};
+use crate::events::actions::Action;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// Modes in which the main views' remappable actions apply
+const MAIN_VIEWS: &[AppMode] = &[
+    AppMode::Normal,
+    AppMode::ViewingComments,
+    AppMode::ViewingDescription,
+];
+const JOBS_VIEW: &[AppMode] = &[AppMode::Normal];
+/// The MR's jobs and the project pipelines view's, which share the jobs table
+const ALL_JOBS_VIEWS: &[AppMode] = &[AppMode::Normal, AppMode::ViewingProjectPipelines];
+const COMMENTS_VIEW: &[AppMode] = &[AppMode::ViewingComments];
+const LOG_VIEW: &[AppMode] = &[AppMode::ViewingLog];
+const JOBS_AND_LOG_VIEWS: &[AppMode] = &[
+    AppMode::Normal,
+    AppMode::ViewingProjectPipelines,
+    AppMode::ViewingLog,
+];
+const DIFF_VIEW: &[AppMode] = &[AppMode::ViewingDiff];
+/// Full-screen comments reuse the log viewer's close and scroll keys
+const LOG_AND_NOTE_VIEWS: &[AppMode] = &[AppMode::ViewingLog, AppMode::ViewingNote];
+const SCROLLABLE_VIEWS: &[AppMode] = &[AppMode::ViewingLog, AppMode::ViewingDiff, AppMode::ViewingNote];
+
+/// Action for a `[keybindings]` name, with the modes it can be triggered from
+fn action_for_name(name: &str) -> Option<(Action, &'static [AppMode])> {
+    let binding = match name {
+        "quit" => (Action::Quit, MAIN_VIEWS),
+        "show_help" => (Action::ShowHelp, MAIN_VIEWS),
+        "next_mr" => (Action::NextMr, MAIN_VIEWS),
+        "prev_mr" => (Action::PrevMr, MAIN_VIEWS),
+        "cycle_mr_sort" => (Action::CycleMrSort, MAIN_VIEWS),
+        "cycle_mr_state" => (Action::CycleMrState, MAIN_VIEWS),
+        "reload_config" => (Action::ReloadConfig, MAIN_VIEWS),
+        "refresh" => (Action::Refresh, MAIN_VIEWS),
+        "refresh_mr" => (Action::RefreshCurrentMr, MAIN_VIEWS),
+        "toggle_comments" => (Action::ToggleCommentsView, MAIN_VIEWS),
+        "toggle_description" => (Action::ToggleDescriptionView, MAIN_VIEWS),
+        "open_in_browser" => (Action::OpenMrInBrowser, MAIN_VIEWS),
+        "toggle_approval" => (Action::ToggleApproval, MAIN_VIEWS),
+        "pause_refresh" => (Action::ToggleAutoRefreshPause, MAIN_VIEWS),
+        "toggle_branch_focus" => (Action::ToggleBranchFocus, MAIN_VIEWS),
+        "next_job" => (Action::NextJob, ALL_JOBS_VIEWS),
+        "prev_job" => (Action::PrevJob, ALL_JOBS_VIEWS),
+        "next_failed_job" => (Action::NextFailedJob, ALL_JOBS_VIEWS),
+        "prev_failed_job" => (Action::PrevFailedJob, ALL_JOBS_VIEWS),
+        "next_pipeline" => (Action::NextPipeline, ALL_JOBS_VIEWS),
+        "prev_pipeline" => (Action::PrevPipeline, ALL_JOBS_VIEWS),
+        "open_log" => (Action::OpenSelectedJobLog, ALL_JOBS_VIEWS),
+        "remove_mr" => (Action::RemoveCurrentMr, JOBS_VIEW),
+        "restore_mr" => (Action::RestoreRemovedMr, JOBS_VIEW),
+        "retry_pipeline" => (Action::RequestRetryPipeline, JOBS_VIEW),
+        "cycle_job_filter" => (Action::CycleJobStatusFilter, ALL_JOBS_VIEWS),
+        "failed_jobs_only" => (Action::ToggleFailedJobsFilter, ALL_JOBS_VIEWS),
+        "collapse_passed_jobs" => (Action::ToggleCollapsePassedJobs, ALL_JOBS_VIEWS),
+        "open_job_in_browser" => (Action::OpenJobInBrowser, JOBS_AND_LOG_VIEWS),
+        "open_pipeline_in_browser" => (Action::OpenPipelineInBrowser, ALL_JOBS_VIEWS),
+        "global_search" => (Action::StartGlobalSearch, JOBS_VIEW),
+        "copy_mr_url" => (Action::CopyMrUrl, JOBS_VIEW),
+        "copy_mr_branch" => (Action::CopyMrBranch, JOBS_VIEW),
+        "view_changes" => (Action::ViewMrChanges, JOBS_VIEW),
+        "failure_summary" => (Action::ShowFailureSummary, JOBS_VIEW),
+        "download_artifacts" => (Action::DownloadArtifacts, ALL_JOBS_VIEWS),
+        "project_pipelines" => (Action::ToggleProjectPipelines, ALL_JOBS_VIEWS),
+        "close_changes" => (Action::CloseLogViewer, DIFF_VIEW),
+        "next_note" => (Action::NextNote, COMMENTS_VIEW),
+        "prev_note" => (Action::PrevNote, COMMENTS_VIEW),
+        "next_thread" => (Action::NextThread, COMMENTS_VIEW),
+        "prev_thread" => (Action::PrevThread, COMMENTS_VIEW),
+        "add_comment" => (Action::StartNote, COMMENTS_VIEW),
+        "toggle_compact_comments" => (Action::ToggleCompactComments, COMMENTS_VIEW),
+        "toggle_system_notes" => (Action::ToggleSystemNotes, COMMENTS_VIEW),
+        "open_note" => (Action::OpenSelectedNote, COMMENTS_VIEW),
+        "close_log" => (Action::CloseLogViewer, LOG_AND_NOTE_VIEWS),
+        "scroll_log_down" => (Action::ScrollLogDown, SCROLLABLE_VIEWS),
+        "scroll_log_up" => (Action::ScrollLogUp, SCROLLABLE_VIEWS),
+        "search" => (Action::StartSearch, LOG_VIEW),
+        "next_match" => (Action::NextSearchResult, LOG_VIEW),
+        "prev_match" => (Action::PrevSearchResult, LOG_VIEW),
+        "toggle_timestamps" => (Action::ToggleTimestampMode, LOG_VIEW),
+        "toggle_raw_log" => (Action::ToggleRawLog, LOG_VIEW),
+        "toggle_log_wrap" => (Action::ToggleLogWrap, LOG_VIEW),
+        "scroll_log_left" => (Action::ScrollLogLeft, LOG_VIEW),
+        "scroll_log_right" => (Action::ScrollLogRight, LOG_VIEW),
+        _ => return None,
+    };
+    Some(binding)
+}
+
+/// Modifiers that distinguish bindings; Shift is part of the character itself (`R` vs `r`)
+fn significant_modifiers(modifiers: KeyModifiers) -> KeyModifiers {
+    modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+/// Parse a key string such as `l`, `R`, `ctrl-r`, `alt-enter` or `pagedown`
+pub fn parse_key(key: &str) -> Result<(KeyCode, KeyModifiers), String> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = key;
+
+    loop {
+        let lower = rest.to_ascii_lowercase();
+        if let Some(stripped) = lower.strip_prefix("ctrl-") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = &rest[rest.len() - stripped.len()..];
+        } else if let Some(stripped) = lower.strip_prefix("alt-") {
+            modifiers |= KeyModifiers::ALT;
+            rest = &rest[rest.len() - stripped.len()..];
+        } else {
+            break;
+        }
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_ascii_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            other => match other.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => return Err(format!("Unknown key '{}'", key)),
+            },
+        },
+    };
+
+    Ok((code, modifiers))
+}
+
+/// A remapped action and the modes it applies in
+type Binding = (Action, &'static [AppMode]);
+
+/// User key remappings from `[keybindings]`, consulted before the built-in keys
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Vec<Binding>>,
+}
+
+impl Keymap {
+    /// Build the keymap from `action name -> key` pairs
+    pub fn from_config(keybindings: &HashMap<String, String>) -> Result<Self, String> {
+        let mut keymap = Keymap::default();
+
+        for (name, key) in keybindings {
+            let (action, modes) = action_for_name(name)
+                .ok_or_else(|| format!("Unknown action '{}' in [keybindings]", name))?;
+            let (code, modifiers) =
+                parse_key(key).map_err(|e| format!("{} for '{}' in [keybindings]", e, name))?;
+            keymap
+                .bindings
+                .entry((code, modifiers))
+                .or_default()
+                .push((action, modes));
+        }
+
+        // When one key is bound in several views, the most specific binding wins
+        for bindings in keymap.bindings.values_mut() {
+            bindings.sort_by_key(|(_, modes)| modes.len());
+        }
+
+        Ok(keymap)
+    }
+
+    /// Remapped action for `key` in the app's current mode, if any.
+    ///
+    /// Text entry (log search, comment input) always gets raw keys.
+    pub fn lookup(&self, key: &KeyEvent, app: &App) -> Option<Action> {
+        if app.is_searching || app.is_global_searching || app.is_composing_note || app.pending_yank {
+            return None;
+        }
+
+        self.bindings
+            .get(&(key.code, significant_modifiers(key.modifiers)))?
+            .iter()
+            .find(|(_, modes)| modes.contains(&app.mode))
+            .map(|(action, _)| action.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bindings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, key)| (name.to_string(), key.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(parse_key("l"), Ok((KeyCode::Char('l'), KeyModifiers::NONE)));
+        assert_eq!(parse_key("R"), Ok((KeyCode::Char('R'), KeyModifiers::NONE)));
+        assert_eq!(parse_key("ctrl-r"), Ok((KeyCode::Char('r'), KeyModifiers::CONTROL)));
+        assert_eq!(
+            parse_key("Ctrl-Alt-x"),
+            Ok((KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT))
+        );
+        assert_eq!(parse_key("pagedown"), Ok((KeyCode::PageDown, KeyModifiers::NONE)));
+        assert_eq!(parse_key("f5"), Ok((KeyCode::F(5), KeyModifiers::NONE)));
+        assert!(parse_key("hyper-q").is_err());
+        assert!(parse_key("").is_err());
+    }
+
+    #[test]
+    fn test_remapped_key_resolves_to_action() {
+        let keymap = Keymap::from_config(&bindings(&[
+            ("next_mr", "n"),
+            ("refresh", "ctrl-r"),
+            ("next_note", "n"),
+        ]))
+        .unwrap();
+        let mut app = App::new(123, None, false, 1);
+
+        let n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(matches!(keymap.lookup(&n, &app), Some(Action::NextMr)));
+
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert!(matches!(keymap.lookup(&ctrl_r, &app), Some(Action::Refresh)));
+
+        // Same key, different mode
+        app.mode = AppMode::ViewingComments;
+        assert!(matches!(keymap.lookup(&n, &app), Some(Action::NextNote)));
+
+        // Unmapped keys fall through to the built-ins
+        let plain_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+        assert!(keymap.lookup(&plain_r, &app).is_none());
+
+        // Typing a comment is never remapped
+        app.is_composing_note = true;
+        assert!(keymap.lookup(&n, &app).is_none());
+    }
+
+    #[test]
+    fn test_log_viewer_bindings_apply_to_full_screen_note() {
+        let keymap = Keymap::from_config(&bindings(&[
+            ("close_log", "x"),
+            ("scroll_log_down", "n"),
+            ("search", "s"),
+        ]))
+        .unwrap();
+        let mut app = App::new(123, None, false, 1);
+        app.mode = AppMode::ViewingNote;
+
+        let x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert!(matches!(keymap.lookup(&x, &app), Some(Action::CloseLogViewer)));
+        let n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(matches!(keymap.lookup(&n, &app), Some(Action::ScrollLogDown)));
+        // A note can't be searched like a log
+        let s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        assert!(keymap.lookup(&s, &app).is_none());
+    }
+
+    #[test]
+    fn test_keybinding_table_from_config() {
+        let toml = r#"
+            next_mr = "L"
+            open_log = "o"
+        "#;
+        let table: HashMap<String, String> = toml::from_str(toml).unwrap();
+        let keymap = Keymap::from_config(&table).unwrap();
+        let app = App::new(123, None, false, 1);
+
+        let key = KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT);
+        assert!(matches!(keymap.lookup(&key, &app), Some(Action::NextMr)));
+        let key = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE);
+        assert!(matches!(keymap.lookup(&key, &app), Some(Action::OpenSelectedJobLog)));
+    }
+
+    #[test]
+    fn test_invalid_keybindings_rejected() {
+        let err = Keymap::from_config(&bindings(&[("fly", "f")])).unwrap_err();
+        assert!(err.contains("fly"));
+
+        let err = Keymap::from_config(&bindings(&[("refresh", "meta-r")])).unwrap_err();
+        assert!(err.contains("refresh"));
+    }
+}
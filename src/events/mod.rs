@@ -1,5 +1,6 @@
 pub mod actions;
 pub mod handler;
+pub mod keymap;
 
 pub use actions::{Action, Effect};
 pub use handler::{EventHandler, map_event_to_action};
@@ -19,36 +19,117 @@ impl GitLabProject {
     }
 }
 
-/// Detect GitLab project from git remote URL
-pub fn detect_project_from_git() -> Result<GitLabProject> {
+/// Hosts that are known not to be GitLab instances
+const NON_GITLAB_HOSTS: &[&str] = &[
+    "github.com",
+    "bitbucket.org",
+    "codeberg.org",
+    "dev.azure.com",
+    "ssh.dev.azure.com",
+    "git.sr.ht",
+];
+
+/// Detect GitLab project from the repository's remotes, trying `origin` first and
+/// skipping remotes that aren't on the configured GitLab instance
+pub fn detect_project_from_git(instance_url: &str) -> Result<GitLabProject> {
     let repo = Repository::open(".")
         .map_err(|e| PeeplabError::Config(format!("Not a git repository: {}", e)))?;
 
-    let remote = repo
-        .find_remote("origin")
-        .map_err(|e| PeeplabError::Config(format!("No 'origin' remote found: {}", e)))?;
+    let names = repo
+        .remotes()
+        .map_err(|e| PeeplabError::Config(format!("Failed to list git remotes: {}", e)))?;
 
-    let url = remote
-        .url()
-        .ok_or_else(|| PeeplabError::Config("Remote URL is not valid UTF-8".to_string()))?;
+    let mut remotes = Vec::new();
+    for name in names.iter().flatten() {
+        if let Ok(remote) = repo.find_remote(name) {
+            if let Some(url) = remote.url() {
+                remotes.push((name.to_string(), url.to_string()));
+            }
+        }
+    }
+    remotes.sort_by_key(|(name, _)| name != "origin");
+
+    let instance_host = Url::parse(instance_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default();
+
+    select_gitlab_remote(&remotes, &instance_host)
+}
+
+/// Pick the first `(name, url)` remote that is a GitLab project on `instance_host`.
+/// When none qualify, the error lists each remote and why it was skipped.
+fn select_gitlab_remote(remotes: &[(String, String)], instance_host: &str) -> Result<GitLabProject> {
+    if remotes.is_empty() {
+        return Err(PeeplabError::Config("No git remotes configured".to_string()));
+    }
+
+    let mut skipped = Vec::new();
+    for (name, url) in remotes {
+        let reason = match parse_gitlab_url(url) {
+            Err(e) => e.to_string(),
+            Ok(project) if NON_GITLAB_HOSTS.contains(&project.host.as_str()) => {
+                format!("{} is not a GitLab host", project.host)
+            }
+            Ok(project) if !hosts_match(&project.host, instance_host) => format!(
+                "host '{}' does not match configured instance '{}'",
+                project.host, instance_host
+            ),
+            Ok(project) => return Ok(project),
+        };
+        skipped.push(format!("  - {} ({}): {}", name, url, reason));
+    }
+
+    Err(PeeplabError::Config(format!(
+        "No GitLab remote found. Skipped remotes:\n{}",
+        skipped.join("\n")
+    )))
+}
 
-    parse_gitlab_url(url)
+/// Same host, or a subdomain of the instance's so e.g. `ssh.gitlab.example.com` matches
+/// `gitlab.example.com`; `notgitlab.com` doesn't match `gitlab.com`
+fn hosts_match(remote_host: &str, instance_host: &str) -> bool {
+    let remote = remote_host.to_ascii_lowercase();
+    let instance = instance_host.to_ascii_lowercase();
+    !instance.is_empty()
+        && (remote == instance || remote.strip_suffix(&instance).is_some_and(|sub| sub.ends_with('.')))
+}
+
+/// Warning for `verify_git_project` when the repository's GitLab project (`git_path`,
+/// resolved to `git_project_id`) is not the configured `default_project_id`
+pub fn project_mismatch_warning(configured_id: u64, git_path: &str, git_project_id: u64) -> Option<String> {
+    (configured_id != git_project_id).then(|| {
+        format!(
+            "default_project_id {} differs from the git remote's project {} (ID {}); watching project {}",
+            configured_id, git_path, git_project_id, configured_id
+        )
+    })
 }
 
 /// Get the current git branch name
-pub fn get_current_branch() -> Result<String> {
+/// Branch checked out in the working directory, or `None` when HEAD is detached
+/// (CI checkouts, rebases, bisects), in which case there is no branch to focus on
+pub fn get_current_branch() -> Result<Option<String>> {
     let repo = Repository::open(".")
         .map_err(|e| PeeplabError::Config(format!("Not a git repository: {}", e)))?;
 
+    current_branch_of(&repo)
+}
+
+fn current_branch_of(repo: &Repository) -> Result<Option<String>> {
     let head = repo.head()
         .map_err(|e| PeeplabError::Config(format!("Failed to get HEAD: {}", e)))?;
 
-    let branch_name = head
-        .shorthand()
-        .ok_or_else(|| PeeplabError::Config("Could not determine branch name".to_string()))?
-        .to_string();
+    let name = head
+        .name()
+        .ok_or_else(|| PeeplabError::Config("Could not determine branch name".to_string()))?;
 
-    Ok(branch_name)
+    Ok(branch_from_head_ref(name).map(str::to_string))
+}
+
+/// `refs/heads/feature/x` -> `feature/x`; a detached HEAD's reference is just `HEAD`
+fn branch_from_head_ref(name: &str) -> Option<&str> {
+    name.strip_prefix("refs/heads/")
 }
 
 fn parse_gitlab_url(git_url: &str) -> Result<GitLabProject> {
@@ -189,14 +270,112 @@ mod tests {
         assert!(parse_https_url(url).is_err());
     }
 
+    fn remotes(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter()
+            .map(|(name, url)| (name.to_string(), url.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_select_remote_skips_github_origin() {
+        let remotes = remotes(&[
+            ("origin", "git@github.com:myorg/myproject.git"),
+            ("gitlab", "git@gitlab.com:myorg/myproject.git"),
+        ]);
+
+        let project = select_gitlab_remote(&remotes, "gitlab.com").unwrap();
+        assert_eq!(project.host, "gitlab.com");
+        assert_eq!(project.path(), "myorg/myproject");
+    }
+
+    #[test]
+    fn test_select_remote_skips_other_instance() {
+        let remotes = remotes(&[
+            ("origin", "https://gitlab.com/public/mirror.git"),
+            ("work", "https://gitlab.example.com/team/app.git"),
+        ]);
+
+        let project = select_gitlab_remote(&remotes, "gitlab.example.com").unwrap();
+        assert_eq!(project.host, "gitlab.example.com");
+        assert_eq!(project.path(), "team/app");
+    }
+
+    #[test]
+    fn test_select_remote_error_lists_skipped() {
+        let remotes = remotes(&[
+            ("origin", "https://github.com/myorg/myproject.git"),
+            ("mirror", "file:///srv/git/myproject.git"),
+            ("other", "git@gitlab.other.org:myorg/myproject.git"),
+        ]);
+
+        let err = select_gitlab_remote(&remotes, "gitlab.com").unwrap_err().to_string();
+        assert!(err.contains("origin (https://github.com/myorg/myproject.git): github.com is not a GitLab host"));
+        assert!(err.contains("mirror (file:///srv/git/myproject.git): "));
+        assert!(err.contains("Unsupported git remote URL format"));
+        assert!(err.contains("host 'gitlab.other.org' does not match configured instance 'gitlab.com'"));
+    }
+
+    #[test]
+    fn test_hosts_match_on_label_boundary() {
+        assert!(hosts_match("gitlab.com", "gitlab.com"));
+        assert!(hosts_match("ssh.gitlab.example.com", "gitlab.example.com"));
+        assert!(hosts_match("GitLab.com", "gitlab.com"));
+        assert!(!hosts_match("evilgitlab.com", "gitlab.com"));
+        assert!(!hosts_match("notgitlab.com", "gitlab.com"));
+        assert!(!hosts_match("example.com", "gitlab.example.com"));
+        assert!(!hosts_match("gitlab.com", ""));
+
+        let remotes = remotes(&[("origin", "git@evilgitlab.com:myorg/myproject.git")]);
+        assert!(select_gitlab_remote(&remotes, "gitlab.com").is_err());
+    }
+
+    #[test]
+    fn test_select_remote_without_remotes() {
+        assert!(select_gitlab_remote(&[], "gitlab.com").is_err());
+    }
+
     #[test]
     fn test_get_current_branch() {
         // This test only works if we're in a git repo
         // We'll make it optional
-        if let Ok(branch) = get_current_branch() {
+        if let Ok(Some(branch)) = get_current_branch() {
             assert!(!branch.is_empty());
             // Branch name should not contain slashes at the start
             assert!(!branch.starts_with('/'));
         }
     }
+
+    #[test]
+    fn test_branch_from_head_ref() {
+        assert_eq!(branch_from_head_ref("refs/heads/main"), Some("main"));
+        assert_eq!(branch_from_head_ref("refs/heads/feature/login"), Some("feature/login"));
+        assert_eq!(branch_from_head_ref("HEAD"), None);
+    }
+
+    #[test]
+    fn test_project_mismatch_warning() {
+        assert_eq!(project_mismatch_warning(42, "group/app", 42), None);
+
+        let warning = project_mismatch_warning(42, "group/other", 7).unwrap();
+        assert!(warning.contains("default_project_id 42"));
+        assert!(warning.contains("group/other (ID 7)"));
+    }
+
+    #[test]
+    fn test_current_branch_of_detached_head() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let commit = repo
+            .commit(Some("refs/heads/feature/login"), &signature, &signature, "init", &tree, &[])
+            .unwrap();
+
+        repo.set_head("refs/heads/feature/login").unwrap();
+        assert_eq!(current_branch_of(&repo).unwrap().as_deref(), Some("feature/login"));
+
+        repo.set_head_detached(commit).unwrap();
+        assert_eq!(current_branch_of(&repo).unwrap(), None);
+    }
 }
@@ -1,5 +1,5 @@
 pub mod client;
 pub mod models;
 
-pub use client::GitLabClient;
-pub use models::{Job, JobStatus, MergeRequest, Note, Pipeline, PipelineStatus};
+pub use client::{ClientOptions, GitLabClient, MrListFilter, MrStateFilter};
+pub use models::{Approvals, DiffFile, Discussion, Job, JobStatus, MergeRequest, Note, Pipeline, PipelineStatus};
@@ -8,6 +8,8 @@ pub struct Project {
     pub path: String,
     pub path_with_namespace: String,
     pub web_url: String,
+    #[serde(default)]
+    pub default_branch: Option<String>, // None for a project without any commits
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -15,13 +17,56 @@ pub struct MergeRequest {
     pub id: u64,
     pub iid: u64,
     pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub source_branch: String,
+    #[serde(default)]
+    pub target_branch: String,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub detailed_merge_status: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<u64>,
+    #[serde(default)]
+    pub labels: Vec<String>,
     pub author: User,
+    #[serde(default)]
+    pub assignees: Vec<User>,
+    #[serde(default)]
+    pub reviewers: Vec<User>,
     pub state: String,
     pub web_url: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl MergeRequest {
+    /// Project path (`namespace/project`) taken from the MR's web URL
+    pub fn project_path(&self) -> Option<String> {
+        let url = url::Url::parse(&self.web_url).ok()?;
+        let (path, _) = url.path().split_once("/-/")?;
+        Some(path.trim_start_matches('/').to_string())
+    }
+
+    /// Whether the MR carries every one of `required` (ignoring case); true when empty
+    pub fn has_labels(&self, required: &[String]) -> bool {
+        required
+            .iter()
+            .all(|label| self.labels.iter().any(|own| own.eq_ignore_ascii_case(label)))
+    }
+
+    /// Whether GitLab reports something that prevents merging regardless of approvals/CI
+    /// (conflicts, a required rebase, or a blocking dependency)
+    pub fn is_merge_blocked(&self) -> bool {
+        matches!(
+            self.detailed_merge_status.as_deref(),
+            Some("conflict" | "broken_status" | "need_rebase" | "blocked_status")
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Pipeline {
     pub id: u64,
@@ -61,6 +106,38 @@ impl PipelineStatus {
             _ => "•",
         }
     }
+
+    /// Status as the API spells it, e.g. `waiting_for_resource`
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            PipelineStatus::Created => "created",
+            PipelineStatus::WaitingForResource => "waiting_for_resource",
+            PipelineStatus::Preparing => "preparing",
+            PipelineStatus::Pending => "pending",
+            PipelineStatus::Running => "running",
+            PipelineStatus::Success => "success",
+            PipelineStatus::Failed => "failed",
+            PipelineStatus::Canceled => "canceled",
+            PipelineStatus::Skipped => "skipped",
+            PipelineStatus::Manual => "manual",
+        }
+    }
+
+    /// Status as shown in the UI, e.g. `waiting for resource`
+    pub fn label(&self) -> &'static str {
+        match self {
+            PipelineStatus::Created => "created",
+            PipelineStatus::WaitingForResource => "waiting for resource",
+            PipelineStatus::Preparing => "preparing",
+            PipelineStatus::Pending => "pending",
+            PipelineStatus::Running => "running",
+            PipelineStatus::Success => "success",
+            PipelineStatus::Failed => "failed",
+            PipelineStatus::Canceled => "canceled",
+            PipelineStatus::Skipped => "skipped",
+            PipelineStatus::Manual => "manual",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -74,6 +151,37 @@ pub struct Job {
     pub finished_at: Option<DateTime<Utc>>,
     pub duration: Option<f64>,
     pub web_url: String,
+    #[serde(default)]
+    pub artifacts: Vec<JobArtifact>,
+    #[serde(default)]
+    pub artifacts_expire_at: Option<DateTime<Utc>>,
+}
+
+impl Job {
+    /// Total size in bytes of the downloadable artifacts (the job log itself is excluded)
+    pub fn artifacts_size(&self) -> Option<u64> {
+        let downloadable: Vec<&JobArtifact> = self
+            .artifacts
+            .iter()
+            .filter(|artifact| artifact.file_type != "trace")
+            .collect();
+        if downloadable.is_empty() {
+            None
+        } else {
+            Some(downloadable.iter().map(|artifact| artifact.size).sum())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JobArtifact {
+    pub file_type: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub filename: String,
+    #[serde(default)]
+    pub file_format: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -101,6 +209,20 @@ impl JobStatus {
             JobStatus::Manual => "⊙",
         }
     }
+
+    /// Status as shown in the UI, e.g. `failed`
+    pub fn label(&self) -> &'static str {
+        match self {
+            JobStatus::Created => "created",
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Success => "success",
+            JobStatus::Failed => "failed",
+            JobStatus::Canceled => "canceled",
+            JobStatus::Skipped => "skipped",
+            JobStatus::Manual => "manual",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -147,6 +269,90 @@ pub struct Note {
     pub internal: bool,
     #[serde(default)]
     pub position: Option<Position>,
+    /// Thread the note belongs to; set by `thread_notes`, `None` for standalone comments
+    #[serde(default)]
+    pub discussion_id: Option<String>,
+}
+
+/// A thread of notes: a diff comment or a reply chain. Standalone comments come back
+/// as single-note discussions with `individual_note` set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Discussion {
+    pub id: String,
+    #[serde(default)]
+    pub individual_note: bool,
+    #[serde(default)]
+    pub notes: Vec<Note>,
+}
+
+/// Flatten discussions into notes grouped by thread, each tagged with its thread ID.
+/// Thread order is kept; replies follow the note they answer.
+pub fn thread_notes(discussions: Vec<Discussion>) -> Vec<Note> {
+    discussions
+        .into_iter()
+        .flat_map(|discussion| {
+            let thread_id = (!discussion.individual_note).then_some(discussion.id);
+            discussion.notes.into_iter().map(move |mut note| {
+                note.discussion_id = thread_id.clone();
+                note
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Approver {
+    pub user: User,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Approvals {
+    pub approved: bool,
+    #[serde(default)]
+    pub approvals_required: u32,
+    #[serde(default)]
+    pub approvals_left: u32,
+    #[serde(default)]
+    pub approved_by: Vec<Approver>,
+    #[serde(default)]
+    pub user_has_approved: bool, // Whether the token's user is among the approvers
+}
+
+impl Approvals {
+    /// Short summary such as `2/2 approved`
+    pub fn summary(&self) -> String {
+        if self.approvals_required == 0 {
+            match self.approved_by.len() {
+                0 => "no approvals".to_string(),
+                n => format!("{} approved", n),
+            }
+        } else {
+            let given = self.approvals_required.saturating_sub(self.approvals_left);
+            format!("{}/{} approved", given, self.approvals_required)
+        }
+    }
+}
+
+/// One changed file of an MR, as returned by the `/changes` endpoint
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiffFile {
+    pub old_path: String,
+    pub new_path: String,
+    #[serde(default)]
+    pub diff: String, // Unified diff hunks, without the `---`/`+++` file header
+    #[serde(default)]
+    pub new_file: bool,
+    #[serde(default)]
+    pub renamed_file: bool,
+    #[serde(default)]
+    pub deleted_file: bool,
+}
+
+/// Body of `/merge_requests/:iid/changes`: the MR itself plus its `changes`; only those are kept
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MrChanges {
+    #[serde(default)]
+    pub changes: Vec<DiffFile>,
 }
 
 #[cfg(test)]
@@ -193,6 +399,53 @@ mod tests {
         assert_eq!(status, JobStatus::Running);
     }
 
+    #[test]
+    fn test_job_artifacts_deserialization() {
+        let json = r#"{
+            "id": 7,
+            "name": "build",
+            "status": "success",
+            "stage": "build",
+            "created_at": "2024-01-01T10:00:00Z",
+            "started_at": null,
+            "finished_at": null,
+            "duration": 12.5,
+            "web_url": "https://gitlab.com/test/-/jobs/7",
+            "artifacts": [
+                {"file_type": "archive", "size": 12000000, "filename": "artifacts.zip", "file_format": "zip"},
+                {"file_type": "metadata", "size": 500, "filename": "metadata.gz", "file_format": "gzip"},
+                {"file_type": "trace", "size": 3000, "filename": "job.log", "file_format": null}
+            ],
+            "artifacts_expire_at": "2024-01-04T10:00:00Z"
+        }"#;
+
+        let job: Job = serde_json::from_str(json).unwrap();
+        assert_eq!(job.artifacts.len(), 3);
+        assert_eq!(job.artifacts[0].filename, "artifacts.zip");
+        assert_eq!(job.artifacts_size(), Some(12000500));
+        assert_eq!(
+            job.artifacts_expire_at.unwrap().to_rfc3339(),
+            "2024-01-04T10:00:00+00:00"
+        );
+
+        // Jobs without artifacts metadata still deserialize
+        let json = r#"{
+            "id": 8,
+            "name": "lint",
+            "status": "success",
+            "stage": "test",
+            "created_at": "2024-01-01T10:00:00Z",
+            "started_at": null,
+            "finished_at": null,
+            "duration": null,
+            "web_url": "https://gitlab.com/test/-/jobs/8"
+        }"#;
+        let job: Job = serde_json::from_str(json).unwrap();
+        assert!(job.artifacts.is_empty());
+        assert!(job.artifacts_expire_at.is_none());
+        assert_eq!(job.artifacts_size(), None);
+    }
+
     #[test]
     fn test_job_status_symbols() {
         assert_eq!(JobStatus::Success.symbol(), "✓");
@@ -204,6 +457,25 @@ mod tests {
         assert_eq!(JobStatus::Manual.symbol(), "⊙");
     }
 
+    #[test]
+    fn test_project_deserialization() {
+        let json = r#"{
+            "id": 42,
+            "name": "app",
+            "path": "app",
+            "path_with_namespace": "group/app",
+            "web_url": "https://gitlab.com/group/app",
+            "default_branch": "main"
+        }"#;
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.default_branch.as_deref(), Some("main"));
+
+        // Empty repositories have no default branch
+        let json = json.replace(r#""main""#, "null");
+        let project: Project = serde_json::from_str(&json).unwrap();
+        assert!(project.default_branch.is_none());
+    }
+
     #[test]
     fn test_merge_request_deserialization() {
         let json = r#"{
@@ -227,6 +499,192 @@ mod tests {
         assert_eq!(mr.title, "Test MR");
         assert_eq!(mr.author.username, "testuser");
         assert_eq!(mr.state, "opened");
+        assert!(mr.description.is_none());
+        assert!(mr.source_branch.is_empty());
+        assert!(!mr.draft);
+        assert!(mr.detailed_merge_status.is_none());
+        assert!(!mr.is_merge_blocked());
+        assert!(mr.labels.is_empty());
+        assert!(mr.assignees.is_empty() && mr.reviewers.is_empty());
+    }
+
+    #[test]
+    fn test_merge_request_with_labels() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "labels": ["bug", "UI", "priority::high"],
+            "author": {"id": 1, "username": "testuser", "name": "Test User"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(mr.labels, vec!["bug", "UI", "priority::high"]);
+    }
+
+    #[test]
+    fn test_merge_request_has_labels() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "labels": ["bug", "UI"],
+            "author": {"id": 1, "username": "testuser", "name": "Test User"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        let labels = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert!(mr.has_labels(&[]));
+        assert!(mr.has_labels(&labels(&["bug"])));
+        assert!(mr.has_labels(&labels(&["ui", "BUG"])));
+        assert!(!mr.has_labels(&labels(&["bug", "backend"])));
+    }
+
+    #[test]
+    fn test_merge_request_draft_and_merge_status() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Draft: Test MR",
+            "draft": true,
+            "detailed_merge_status": "conflict",
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert!(mr.draft);
+        assert_eq!(mr.detailed_merge_status.as_deref(), Some("conflict"));
+        assert!(mr.is_merge_blocked());
+    }
+
+    #[test]
+    fn test_merge_request_mergeable_status_not_blocked() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "detailed_merge_status": "mergeable",
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert!(!mr.draft);
+        assert!(!mr.is_merge_blocked());
+    }
+
+    #[test]
+    fn test_merge_request_project_fields() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "project_id": 99,
+            "title": "Test MR",
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/sub/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(mr.project_id, Some(99));
+        assert_eq!(mr.project_path().as_deref(), Some("group/sub/repo"));
+    }
+
+    #[test]
+    fn test_merge_request_with_branches() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "source_branch": "feature/login",
+            "target_branch": "main",
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(mr.source_branch, "feature/login");
+        assert_eq!(mr.target_branch, "main");
+    }
+
+    #[test]
+    fn test_merge_request_with_description() {
+        let json = r###"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "description": "## Summary\n\n- Adds a feature",
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"###;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(mr.description.as_deref(), Some("## Summary\n\n- Adds a feature"));
+    }
+
+    #[test]
+    fn test_merge_request_with_null_description() {
+        let json = r#"{
+            "id": 123,
+            "iid": 45,
+            "title": "Test MR",
+            "description": null,
+            "author": {
+                "id": 1,
+                "username": "testuser",
+                "name": "Test User"
+            },
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/repo/-/merge_requests/45",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }"#;
+
+        let mr: MergeRequest = serde_json::from_str(json).unwrap();
+        assert!(mr.description.is_none());
     }
 
     #[test]
@@ -381,4 +839,152 @@ mod tests {
         assert!(!note.internal);
         assert!(note.resolvable);
     }
+
+    #[test]
+    fn test_job_status_labels() {
+        let cases = [
+            (JobStatus::Created, "created"),
+            (JobStatus::Pending, "pending"),
+            (JobStatus::Running, "running"),
+            (JobStatus::Success, "success"),
+            (JobStatus::Failed, "failed"),
+            (JobStatus::Canceled, "canceled"),
+            (JobStatus::Skipped, "skipped"),
+            (JobStatus::Manual, "manual"),
+        ];
+        for (status, label) in cases {
+            assert_eq!(status.label(), label);
+        }
+    }
+
+    #[test]
+    fn test_pipeline_status_labels() {
+        let cases = [
+            ("created", "created"),
+            ("waiting_for_resource", "waiting for resource"),
+            ("preparing", "preparing"),
+            ("pending", "pending"),
+            ("running", "running"),
+            ("success", "success"),
+            ("failed", "failed"),
+            ("canceled", "canceled"),
+            ("skipped", "skipped"),
+            ("manual", "manual"),
+        ];
+        for (api_name, label) in cases {
+            let status: PipelineStatus = serde_json::from_value(serde_json::json!(api_name)).unwrap();
+            assert_eq!(status.label(), label);
+            assert_eq!(status.api_name(), api_name);
+        }
+    }
+
+    #[test]
+    fn test_discussion_with_diff_position_deserialization() {
+        let json = r#"[
+            {
+                "id": "6a9c1750b37d513a43987b574953fceb50b03ce7",
+                "individual_note": false,
+                "notes": [
+                    {
+                        "id": 1126,
+                        "type": "DiffNote",
+                        "body": "Should this be `u64`?",
+                        "author": {"id": 1, "username": "reviewer", "name": "Reviewer"},
+                        "created_at": "2024-01-01T10:00:00Z",
+                        "updated_at": "2024-01-01T10:00:00Z",
+                        "system": false,
+                        "noteable_id": 123,
+                        "noteable_type": "MergeRequest",
+                        "project_id": 456,
+                        "noteable_iid": 10,
+                        "resolvable": true,
+                        "position": {
+                            "base_sha": "b5d6e7b1613fca24d250fa8e5bc7bcc3dd6002ef",
+                            "start_sha": "7c9c2ead8a320fb7ba0b4e234bd9529a2614e306",
+                            "head_sha": "4803c71e6b1833ca72b8b26ef2ecd5adc8a38031",
+                            "old_path": "src/app.rs",
+                            "new_path": "src/app.rs",
+                            "position_type": "text",
+                            "old_line": null,
+                            "new_line": 142
+                        }
+                    },
+                    {
+                        "id": 1127,
+                        "type": "DiffNote",
+                        "body": "Good catch, fixed",
+                        "author": {"id": 2, "username": "author", "name": "Author"},
+                        "created_at": "2024-01-01T11:00:00Z",
+                        "updated_at": "2024-01-01T11:00:00Z",
+                        "system": false,
+                        "noteable_id": 123,
+                        "noteable_type": "MergeRequest",
+                        "project_id": 456,
+                        "noteable_iid": 10,
+                        "resolvable": true
+                    }
+                ]
+            },
+            {
+                "id": "87805b7c09016a7058e91bdbe7b29d1f284a39e6",
+                "individual_note": true,
+                "notes": [
+                    {
+                        "id": 1128,
+                        "body": "LGTM",
+                        "author": {"id": 1, "username": "reviewer", "name": "Reviewer"},
+                        "created_at": "2024-01-01T12:00:00Z",
+                        "updated_at": "2024-01-01T12:00:00Z",
+                        "system": false,
+                        "noteable_id": 123,
+                        "noteable_type": "MergeRequest",
+                        "project_id": 456,
+                        "noteable_iid": 10,
+                        "resolvable": false
+                    }
+                ]
+            }
+        ]"#;
+
+        let discussions: Vec<Discussion> = serde_json::from_str(json).unwrap();
+        assert_eq!(discussions.len(), 2);
+        assert!(!discussions[0].individual_note);
+        assert_eq!(discussions[0].notes.len(), 2);
+        let position = discussions[0].notes[0].position.as_ref().unwrap();
+        assert_eq!(position.new_path.as_deref(), Some("src/app.rs"));
+        assert_eq!(position.new_line, Some(142));
+        assert_eq!(position.old_line, None);
+        assert!(discussions[0].notes[1].position.is_none());
+
+        let notes = thread_notes(discussions);
+        let ids: Vec<u64> = notes.iter().map(|note| note.id).collect();
+        assert_eq!(ids, vec![1126, 1127, 1128]);
+        let thread = Some("6a9c1750b37d513a43987b574953fceb50b03ce7".to_string());
+        assert_eq!(notes[0].discussion_id, thread);
+        assert_eq!(notes[1].discussion_id, thread);
+        // Standalone comments aren't part of a thread
+        assert_eq!(notes[2].discussion_id, None);
+    }
+
+    #[test]
+    fn test_approvals_deserialization_and_summary() {
+        let json = r#"{
+            "id": 5,
+            "iid": 45,
+            "approved": false,
+            "approvals_required": 2,
+            "approvals_left": 1,
+            "approved_by": [
+                {"user": {"id": 1, "username": "alice", "name": "Alice"}}
+            ]
+        }"#;
+
+        let approvals: Approvals = serde_json::from_str(json).unwrap();
+        assert!(!approvals.approved);
+        assert_eq!(approvals.approved_by[0].user.username, "alice");
+        assert_eq!(approvals.summary(), "1/2 approved");
+
+        let optional: Approvals = serde_json::from_str(r#"{"approved": true}"#).unwrap();
+        assert_eq!(optional.summary(), "no approvals");
+    }
 }
@@ -0,0 +1,198 @@
+use crate::config::settings::HooksConfig;
+use crate::gitlab::{MergeRequest, Pipeline};
+use std::process::{Command, Stdio};
+
+/// Something that happened in the dashboard that a user hook can react to
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookEvent {
+    PipelineFailed(PipelineEvent),
+    PipelineSucceeded(PipelineEvent),
+    Refresh { project_id: u64, mr_count: usize },
+}
+
+/// MR and pipeline details passed to pipeline hooks
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineEvent {
+    pub project_id: u64,
+    pub mr_iid: u64,
+    pub mr_title: String,
+    pub mr_url: String,
+    pub pipeline_id: u64,
+    pub pipeline_status: String,
+    pub pipeline_url: String,
+    pub ref_name: String,
+}
+
+impl PipelineEvent {
+    pub fn new(project_id: u64, mr: &MergeRequest, pipeline: &Pipeline) -> Self {
+        Self {
+            project_id,
+            mr_iid: mr.iid,
+            mr_title: mr.title.clone(),
+            mr_url: mr.web_url.clone(),
+            pipeline_id: pipeline.id,
+            pipeline_status: pipeline.status.api_name().to_string(),
+            pipeline_url: pipeline.web_url.clone(),
+            ref_name: pipeline.ref_name.clone(),
+        }
+    }
+}
+
+impl HookEvent {
+    /// Event name, as found in `PEEPLAB_EVENT`
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::PipelineFailed(_) => "pipeline_failed",
+            HookEvent::PipelineSucceeded(_) => "pipeline_succeeded",
+            HookEvent::Refresh { .. } => "refresh",
+        }
+    }
+
+    /// Environment variables describing the event (the contract documented in the README)
+    pub fn env(&self) -> Vec<(String, String)> {
+        let mut env = vec![("PEEPLAB_EVENT".to_string(), self.name().to_string())];
+
+        match self {
+            HookEvent::PipelineFailed(event) | HookEvent::PipelineSucceeded(event) => {
+                env.extend([
+                    ("PEEPLAB_PROJECT_ID".to_string(), event.project_id.to_string()),
+                    ("PEEPLAB_MR_IID".to_string(), event.mr_iid.to_string()),
+                    ("PEEPLAB_MR_TITLE".to_string(), event.mr_title.clone()),
+                    ("PEEPLAB_MR_URL".to_string(), event.mr_url.clone()),
+                    ("PEEPLAB_PIPELINE_ID".to_string(), event.pipeline_id.to_string()),
+                    ("PEEPLAB_PIPELINE_STATUS".to_string(), event.pipeline_status.clone()),
+                    ("PEEPLAB_PIPELINE_URL".to_string(), event.pipeline_url.clone()),
+                    ("PEEPLAB_REF".to_string(), event.ref_name.clone()),
+                ]);
+            }
+            HookEvent::Refresh { project_id, mr_count } => {
+                env.extend([
+                    ("PEEPLAB_PROJECT_ID".to_string(), project_id.to_string()),
+                    ("PEEPLAB_MR_COUNT".to_string(), mr_count.to_string()),
+                ]);
+            }
+        }
+
+        env
+    }
+
+    /// Command configured for this event, if any
+    pub fn command<'a>(&self, hooks: &'a HooksConfig) -> Option<&'a str> {
+        match self {
+            HookEvent::PipelineFailed(_) => hooks.on_pipeline_failed.as_deref(),
+            HookEvent::PipelineSucceeded(_) => hooks.on_pipeline_succeeded.as_deref(),
+            HookEvent::Refresh { .. } => hooks.on_refresh.as_deref(),
+        }
+    }
+}
+
+/// Run the hook configured for `event` through `sh -c`, without blocking the UI.
+///
+/// Output is discarded so it can't draw over the TUI; failures are ignored.
+pub fn run_hook(hooks: &HooksConfig, event: &HookEvent) {
+    let command = match event.command(hooks) {
+        Some(command) if !command.trim().is_empty() => command.to_string(),
+        _ => return,
+    };
+    let env = event.env();
+
+    tokio::task::spawn_blocking(move || {
+        let _ = Command::new("sh")
+            .arg("-c")
+            .arg(&command)
+            .envs(env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pipeline_event() -> PipelineEvent {
+        PipelineEvent {
+            project_id: 123,
+            mr_iid: 10,
+            mr_title: "Fix login".to_string(),
+            mr_url: "https://gitlab.com/test/-/merge_requests/10".to_string(),
+            pipeline_id: 456,
+            pipeline_status: "failed".to_string(),
+            pipeline_url: "https://gitlab.com/test/-/pipelines/456".to_string(),
+            ref_name: "fix-login".to_string(),
+        }
+    }
+
+    fn env_value<'a>(env: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        env.iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_pipeline_hook_env() {
+        let env = HookEvent::PipelineFailed(pipeline_event()).env();
+
+        assert_eq!(env_value(&env, "PEEPLAB_EVENT"), Some("pipeline_failed"));
+        assert_eq!(env_value(&env, "PEEPLAB_PROJECT_ID"), Some("123"));
+        assert_eq!(env_value(&env, "PEEPLAB_MR_IID"), Some("10"));
+        assert_eq!(env_value(&env, "PEEPLAB_MR_TITLE"), Some("Fix login"));
+        assert_eq!(env_value(&env, "PEEPLAB_PIPELINE_ID"), Some("456"));
+        assert_eq!(env_value(&env, "PEEPLAB_PIPELINE_STATUS"), Some("failed"));
+        assert_eq!(env_value(&env, "PEEPLAB_REF"), Some("fix-login"));
+        assert_eq!(env_value(&env, "PEEPLAB_MR_COUNT"), None);
+    }
+
+    #[test]
+    fn test_pipeline_event_uses_api_status_name() {
+        let mr: MergeRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 10,
+            "title": "Fix login",
+            "author": {"id": 1, "username": "dev", "name": "Dev"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/-/merge_requests/10",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }))
+        .unwrap();
+        let pipeline: Pipeline = serde_json::from_value(serde_json::json!({
+            "id": 456,
+            "iid": 78,
+            "status": "waiting_for_resource",
+            "ref": "fix-login",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z",
+            "web_url": "https://gitlab.com/test/-/pipelines/456"
+        }))
+        .unwrap();
+
+        let event = PipelineEvent::new(123, &mr, &pipeline);
+        assert_eq!(event.pipeline_status, "waiting_for_resource");
+    }
+
+    #[test]
+    fn test_refresh_hook_env() {
+        let env = HookEvent::Refresh { project_id: 123, mr_count: 3 }.env();
+
+        assert_eq!(env_value(&env, "PEEPLAB_EVENT"), Some("refresh"));
+        assert_eq!(env_value(&env, "PEEPLAB_PROJECT_ID"), Some("123"));
+        assert_eq!(env_value(&env, "PEEPLAB_MR_COUNT"), Some("3"));
+        assert_eq!(env_value(&env, "PEEPLAB_MR_IID"), None);
+    }
+
+    #[test]
+    fn test_hook_command_lookup() {
+        let hooks = HooksConfig {
+            on_pipeline_failed: Some("notify-send failed".to_string()),
+            ..HooksConfig::default()
+        };
+
+        let failed = HookEvent::PipelineFailed(pipeline_event());
+        let succeeded = HookEvent::PipelineSucceeded(pipeline_event());
+        assert_eq!(failed.command(&hooks), Some("notify-send failed"));
+        assert_eq!(succeeded.command(&hooks), None);
+    }
+}
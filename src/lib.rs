@@ -1,10 +1,20 @@
 // Library interface for peeplab - enables testing of internal modules
 pub mod app;
+pub mod browser;
+pub mod cli;
+pub mod clipboard;
 pub mod config;
+pub mod diff_processor;
 pub mod editor;
 pub mod error;
 pub mod events;
 pub mod git;
 pub mod gitlab;
+pub mod hooks;
+pub mod log_cache;
 pub mod log_processor;
+pub mod logging;
+pub mod state;
+pub mod summary;
 pub mod ui;
+pub mod util;
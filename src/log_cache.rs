@@ -0,0 +1,141 @@
+/// Job logs kept per MR, least recently used first out once over either limit.
+///
+/// The most recent entry is always kept, even when it alone exceeds `max_bytes`, so the
+/// log being viewed never has to be refetched.
+#[derive(Debug, Clone)]
+pub struct LruLogCache {
+    entries: Vec<(u64, String)>, // job_id -> log, least recently used first
+    total_bytes: usize,
+    max_entries: usize,
+    max_bytes: usize,
+}
+
+/// Defaults for `log_cache_max_entries` / `log_cache_max_mb`
+pub const DEFAULT_MAX_ENTRIES: usize = 20;
+pub const DEFAULT_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+impl Default for LruLogCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES, DEFAULT_MAX_BYTES)
+    }
+}
+
+impl LruLogCache {
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            total_bytes: 0,
+            max_entries: max_entries.max(1),
+            max_bytes,
+        }
+    }
+
+    /// The cached log of `job_id`, marking it as the most recently used
+    pub fn get(&mut self, job_id: u64) -> Option<&String> {
+        let index = self.entries.iter().position(|(id, _)| *id == job_id)?;
+        let entry = self.entries.remove(index);
+        self.entries.push(entry);
+        self.entries.last().map(|(_, log)| log)
+    }
+
+    /// The cached log of `job_id` without touching the eviction order
+    pub fn peek(&self, job_id: u64) -> Option<&String> {
+        self.entries.iter().find(|(id, _)| *id == job_id).map(|(_, log)| log)
+    }
+
+    pub fn contains_key(&self, job_id: u64) -> bool {
+        self.peek(job_id).is_some()
+    }
+
+    /// Cache a log as the most recently used, evicting the oldest entries over the limits
+    pub fn insert(&mut self, job_id: u64, log: String) {
+        if let Some(index) = self.entries.iter().position(|(id, _)| *id == job_id) {
+            let (_, old) = self.entries.remove(index);
+            self.total_bytes -= old.len();
+        }
+        self.total_bytes += log.len();
+        self.entries.push((job_id, log));
+
+        while self.entries.len() > 1
+            && (self.entries.len() > self.max_entries || self.total_bytes > self.max_bytes)
+        {
+            let (_, evicted) = self.entries.remove(0);
+            self.total_bytes -= evicted.len();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.total_bytes = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(bytes: usize) -> String {
+        "x".repeat(bytes)
+    }
+
+    #[test]
+    fn test_insert_beyond_capacity_evicts_least_recently_used() {
+        let mut cache = LruLogCache::new(2, usize::MAX);
+        cache.insert(1, log(1));
+        cache.insert(2, log(1));
+        // Reading job 1 makes job 2 the oldest
+        assert!(cache.get(1).is_some());
+
+        cache.insert(3, log(1));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains_key(1));
+        assert!(!cache.contains_key(2));
+        assert!(cache.contains_key(3));
+    }
+
+    #[test]
+    fn test_byte_limit_evicts_oldest_but_keeps_newest() {
+        let mut cache = LruLogCache::new(10, 100);
+        cache.insert(1, log(40));
+        cache.insert(2, log(40));
+        cache.insert(3, log(40));
+        assert!(!cache.contains_key(1));
+        assert_eq!(cache.total_bytes(), 80);
+
+        // A single log over the limit is still kept on its own
+        cache.insert(4, log(500));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.peek(4).map(String::len), Some(500));
+    }
+
+    #[test]
+    fn test_reinsert_replaces_and_peek_keeps_order() {
+        let mut cache = LruLogCache::new(2, usize::MAX);
+        cache.insert(1, log(10));
+        cache.insert(2, log(10));
+        cache.insert(1, log(30));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.total_bytes(), 40);
+
+        // Peeking job 2 doesn't save it: it's still the oldest
+        assert!(cache.peek(2).is_some());
+        cache.insert(3, log(1));
+        assert!(!cache.contains_key(2));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.total_bytes(), 0);
+    }
+}
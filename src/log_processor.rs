@@ -65,29 +65,140 @@ fn process_log_line(line: &str, mode: &TimestampDisplayMode) -> String {
     }
 }
 
+/// Parse the ANSI escape sequences of one line into styled spans
+fn ansi_line(line: &str) -> Line<'static> {
+    match ansi_to_tui::IntoText::into_text(&line) {
+        Ok(text) => {
+            // Convert ratatui Text to Line
+            if text.lines.is_empty() {
+                Line::from("").to_owned()
+            } else {
+                text.lines[0].clone().to_owned()
+            }
+        }
+        Err(_) => {
+            // If parsing fails, show raw text
+            Line::from(line.to_string()).to_owned()
+        }
+    }
+}
+
 /// Process all log lines: strip prefixes, format timestamps, parse ANSI codes
 pub fn process_log_content(content: &str, mode: &TimestampDisplayMode) -> Vec<Line<'static>> {
     content
         .lines()
         .map(|line| {
-            // First, process the timestamp based on display mode
-            let processed_line = process_log_line(line, mode);
-
-            // Then parse ANSI escape sequences
-            match ansi_to_tui::IntoText::into_text(&processed_line) {
-                Ok(text) => {
-                    // Convert ratatui Text to Line
-                    if text.lines.is_empty() {
-                        Line::from("").to_owned()
-                    } else {
-                        text.lines[0].clone().to_owned()
-                    }
-                }
-                Err(_) => {
-                    // If parsing fails, show raw text
-                    Line::from(processed_line).to_owned()
-                }
+            // First, process the timestamp based on display mode, then parse ANSI escape sequences
+            ansi_line(&process_log_line(line, mode))
+        })
+        .collect()
+}
+
+/// One log line as plain text: prefixes, timestamps and ANSI codes removed
+pub fn plain_log_line(line: &str) -> String {
+    ansi_line(&process_log_line(line, &TimestampDisplayMode::Hidden))
+        .to_string()
+        .trim()
+        .to_string()
+}
+
+/// Process log lines as GitLab sent them: only ANSI codes are parsed, prefixes,
+/// section markers and timestamps are kept
+pub fn process_raw_log_content(content: &str) -> Vec<Line<'static>> {
+    content.lines().map(ansi_line).collect()
+}
+
+/// Line index where output that wasn't in a previously seen trace begins.
+///
+/// `previous_len` is the byte length of the trace the last time it was viewed.
+/// Returns `None` when there is no previous trace or nothing new was appended.
+pub fn new_content_boundary(previous_len: usize, trace: &str) -> Option<usize> {
+    if previous_len == 0 || previous_len >= trace.len() {
+        return None;
+    }
+
+    // Fall back to no boundary if the old length doesn't land on a char boundary,
+    // which means the trace was rewritten rather than appended to
+    let old_content = trace.get(..previous_len)?;
+    let boundary = old_content.lines().count();
+
+    // A trailing partial line is continued by the new output, so it counts as new
+    if old_content.ends_with('\n') {
+        Some(boundary)
+    } else {
+        Some(boundary.saturating_sub(1))
+    }
+}
+
+/// Indices of the processed lines whose text, as shown, contains `query`: hidden prefixes,
+/// section markers and ANSI codes don't match, and styling doesn't split words
+pub fn find_matching_display_lines(lines: &[Line], query: &str, case_sensitive: bool) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query_lower = query.to_lowercase();
+    lines
+        .iter()
+        .map(|line| line.to_string())
+        .enumerate()
+        .filter(|(_, line)| {
+            if case_sensitive {
+                line.contains(query)
+            } else {
+                line.to_lowercase().contains(&query_lower)
             }
         })
+        .map(|(idx, _)| idx)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_content_boundary_after_appended_lines() {
+        let old = "line 1\nline 2\n";
+        let new = "line 1\nline 2\nline 3\nline 4\n";
+        assert_eq!(new_content_boundary(old.len(), new), Some(2));
+    }
+
+    #[test]
+    fn test_new_content_boundary_with_partial_last_line() {
+        // "line 2" was still being written during the previous fetch
+        let old = "line 1\nline 2";
+        let new = "line 1\nline 2 done\nline 3\n";
+        assert_eq!(new_content_boundary(old.len(), new), Some(1));
+    }
+
+    #[test]
+    fn test_new_content_boundary_without_new_output() {
+        let trace = "line 1\nline 2\n";
+        assert_eq!(new_content_boundary(trace.len(), trace), None);
+        assert_eq!(new_content_boundary(trace.len() + 10, trace), None);
+    }
+
+    #[test]
+    fn test_new_content_boundary_first_view() {
+        assert_eq!(new_content_boundary(0, "line 1\n"), None);
+    }
+
+    #[test]
+    fn test_find_matching_lines_case_sensitivity() {
+        let lines = process_raw_log_content("ERROR: build failed\nerror: retrying\nok\nError in test");
+        assert_eq!(find_matching_display_lines(&lines, "ERROR", false), vec![0, 1, 3]);
+        assert_eq!(find_matching_display_lines(&lines, "ERROR", true), vec![0]);
+        assert_eq!(find_matching_display_lines(&lines, "error", true), vec![1]);
+        assert!(find_matching_display_lines(&lines, "", false).is_empty());
+    }
+
+    #[test]
+    fn test_find_matching_display_lines_ignores_hidden_text() {
+        let content = "\x1b[0Ksection_start:1700000000:build\r\x1b[0K\n00E \x1b[31mbuild\x1b[0m failed\n";
+        let lines = process_log_content(content, &TimestampDisplayMode::Hidden);
+        assert_eq!(find_matching_display_lines(&lines, "build failed", false), vec![1]);
+        assert!(find_matching_display_lines(&lines, "section_start", false).is_empty());
+        assert!(find_matching_display_lines(&lines, "00E", false).is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::error::{PeeplabError, Result};
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing_subscriber::EnvFilter;
+
+/// `peeplab.log` next to the config file; logs can't go to stdout while the TUI owns it
+pub fn get_log_path() -> Result<PathBuf> {
+    Ok(crate::config::app_config_dir()?.join("peeplab.log"))
+}
+
+/// Log to a file when `-v`/`--verbose` is given or `RUST_LOG` is set, returning its path.
+/// `RUST_LOG` picks the filter; `--verbose` alone logs peeplab at debug level.
+pub fn init_logging(verbose: bool) -> Result<Option<PathBuf>> {
+    let filter = match std::env::var("RUST_LOG") {
+        Ok(directives) if !directives.is_empty() => EnvFilter::new(directives),
+        _ if verbose => EnvFilter::new("peeplab=debug"),
+        _ => return Ok(None),
+    };
+
+    let path = get_log_path()?;
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .try_init()
+        .map_err(|e| PeeplabError::Config(format!("Could not set up logging: {}", e)))?;
+
+    Ok(Some(path))
+}
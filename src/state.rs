@@ -0,0 +1,179 @@
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Which MRs the user tracks or has dismissed, per project ID, kept across restarts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TrackedState {
+    #[serde(default)]
+    pub projects: BTreeMap<u64, ProjectTracking>,
+    #[serde(default)]
+    pub removed: Vec<RemovedMr>, // Across projects so `last_removed` works in group mode; most recent last
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProjectTracking {
+    #[serde(default)]
+    pub tracked_iids: Vec<u64>,
+}
+
+/// An MR the user dismissed, by its project since IIDs are only unique within one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RemovedMr {
+    pub project_id: u64,
+    pub iid: u64,
+}
+
+impl TrackedState {
+    pub fn tracked_iids(&self, project_id: u64) -> &[u64] {
+        self.projects
+            .get(&project_id)
+            .map(|p| p.tracked_iids.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn is_removed(&self, project_id: u64, iid: u64) -> bool {
+        self.removed.contains(&RemovedMr { project_id, iid })
+    }
+
+    /// Record that an MR is tracked; returns whether the state changed
+    pub fn mark_tracked(&mut self, project_id: u64, iid: u64) -> bool {
+        let was_removed = self.unmark_removed(project_id, iid);
+        let project = self.projects.entry(project_id).or_default();
+        if project.tracked_iids.contains(&iid) {
+            return was_removed;
+        }
+        project.tracked_iids.push(iid);
+        true
+    }
+
+    /// Forget a tracked MR, e.g. one merged or closed since; returns whether it was saved
+    pub fn untrack(&mut self, project_id: u64, iid: u64) -> bool {
+        self.projects
+            .get_mut(&project_id)
+            .is_some_and(|project| remove_iid(&mut project.tracked_iids, iid))
+    }
+
+    /// Record that the user dismissed an MR so later refreshes don't bring it back. Only
+    /// the latest `MAX_REMOVED_MRS` are kept; older ones are most likely merged or closed.
+    pub fn mark_removed(&mut self, project_id: u64, iid: u64) {
+        self.untrack(project_id, iid);
+        // Most recent last, so `last_removed` finds it
+        self.unmark_removed(project_id, iid);
+        self.removed.push(RemovedMr { project_id, iid });
+        let excess = self.removed.len().saturating_sub(MAX_REMOVED_MRS);
+        self.removed.drain(..excess);
+    }
+
+    /// The MR dismissed most recently, in any project
+    pub fn last_removed(&self) -> Option<RemovedMr> {
+        self.removed.last().copied()
+    }
+
+    fn unmark_removed(&mut self, project_id: u64, iid: u64) -> bool {
+        let len = self.removed.len();
+        self.removed.retain(|removed| *removed != RemovedMr { project_id, iid });
+        self.removed.len() != len
+    }
+}
+
+/// Removed MRs remembered, over all projects
+const MAX_REMOVED_MRS: usize = 100;
+
+fn remove_iid(iids: &mut Vec<u64>, iid: u64) -> bool {
+    let len = iids.len();
+    iids.retain(|&i| i != iid);
+    iids.len() != len
+}
+
+/// `state.json` next to the config file
+pub fn get_state_path() -> Result<PathBuf> {
+    Ok(crate::config::app_config_dir()?.join("state.json"))
+}
+
+pub fn save_tracked_state(path: &Path, state: &TrackedState) -> Result<()> {
+    let content = serde_json::to_string_pretty(state)?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Load the saved state; a missing file is an empty state
+pub fn load_tracked_state(path: &Path) -> Result<TrackedState> {
+    if !path.exists() {
+        return Ok(TrackedState::default());
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracked_state_round_trip() {
+        let mut state = TrackedState::default();
+        state.mark_tracked(123, 1);
+        state.mark_tracked(123, 2);
+        state.mark_removed(123, 2);
+        state.mark_tracked(456, 7);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        save_tracked_state(&path, &state).unwrap();
+
+        let loaded = load_tracked_state(&path).unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(loaded.tracked_iids(123), &[1]);
+        assert!(loaded.is_removed(123, 2));
+        assert_eq!(loaded.tracked_iids(456), &[7]);
+    }
+
+    #[test]
+    fn test_load_missing_state_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = load_tracked_state(&dir.path().join("state.json")).unwrap();
+        assert_eq!(state, TrackedState::default());
+    }
+
+    #[test]
+    fn test_state_json_format() {
+        let state: TrackedState = serde_json::from_str(
+            r#"{"projects": {"123": {"tracked_iids": [4]}}, "removed": [{"project_id": 123, "iid": 5}]}"#,
+        )
+        .unwrap();
+        assert_eq!(state.tracked_iids(123), &[4]);
+        assert!(state.is_removed(123, 5));
+        assert!(!state.is_removed(999, 5));
+    }
+
+    #[test]
+    fn test_mark_tracked_undoes_removal() {
+        let mut state = TrackedState::default();
+        state.mark_removed(123, 3);
+        assert!(state.mark_tracked(123, 3));
+        assert!(!state.is_removed(123, 3));
+        assert!(!state.mark_tracked(123, 3));
+    }
+
+    #[test]
+    fn test_removed_mrs_keep_the_latest() {
+        let mut state = TrackedState::default();
+        assert_eq!(state.last_removed(), None);
+        for iid in 1..=MAX_REMOVED_MRS as u64 + 5 {
+            state.mark_removed(123, iid);
+        }
+        assert!(!state.is_removed(123, 5));
+        assert!(state.is_removed(123, 6));
+        let latest = RemovedMr { project_id: 123, iid: MAX_REMOVED_MRS as u64 + 5 };
+        assert_eq!(state.last_removed(), Some(latest));
+
+        // Removing an MR again makes it the latest, whatever its project
+        state.mark_removed(123, 6);
+        assert_eq!(state.last_removed(), Some(RemovedMr { project_id: 123, iid: 6 }));
+        state.mark_removed(456, 6);
+        assert!(!state.is_removed(789, 6));
+        assert_eq!(state.last_removed(), Some(RemovedMr { project_id: 456, iid: 6 }));
+    }
+}
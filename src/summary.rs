@@ -0,0 +1,116 @@
+use crate::error::Result;
+use crate::gitlab::{GitLabClient, PipelineStatus};
+use tokio::task::JoinSet;
+
+/// Counts of open MRs by the status of their latest pipeline
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub failed: usize,
+    pub running: usize,
+    pub pending: usize,
+    pub success: usize,
+}
+
+impl StatusSummary {
+    /// Aggregate the latest pipeline status of each MR; MRs without a pipeline
+    /// (`None`) and canceled/skipped pipelines aren't counted
+    pub fn from_statuses<'a>(statuses: impl IntoIterator<Item = Option<&'a PipelineStatus>>) -> Self {
+        let mut summary = Self::default();
+        for status in statuses.into_iter().flatten() {
+            match status {
+                PipelineStatus::Failed => summary.failed += 1,
+                PipelineStatus::Running => summary.running += 1,
+                PipelineStatus::Pending
+                | PipelineStatus::Created
+                | PipelineStatus::Preparing
+                | PipelineStatus::WaitingForResource => summary.pending += 1,
+                PipelineStatus::Success => summary.success += 1,
+                _ => {}
+            }
+        }
+        summary
+    }
+
+    /// One short line for a tmux/prompt status line, e.g. `peeplab ✗2 ⟳1 ✓5`.
+    /// Zero counts are left out; with nothing to report it is `peeplab -`.
+    pub fn status_line(&self) -> String {
+        let parts: Vec<String> = [
+            (PipelineStatus::Failed, self.failed),
+            (PipelineStatus::Running, self.running),
+            (PipelineStatus::Pending, self.pending),
+            (PipelineStatus::Success, self.success),
+        ]
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(status, count)| format!("{}{}", status.symbol(), count))
+        .collect();
+
+        if parts.is_empty() {
+            "peeplab -".to_string()
+        } else {
+            format!("peeplab {}", parts.join(" "))
+        }
+    }
+}
+
+/// Fetch the open MRs of a project (or group) and summarize their latest pipelines
+pub async fn fetch_summary(
+    client: &GitLabClient,
+    project_id: u64,
+    group_id: Option<u64>,
+) -> Result<StatusSummary> {
+    let mrs = match group_id {
+        Some(group_id) => client.get_group_merge_requests(group_id, None).await?,
+        None => client.get_merge_requests(project_id).await?,
+    };
+
+    let mut tasks = JoinSet::new();
+    for mr in mrs {
+        let client = client.clone();
+        let mr_project_id = mr.project_id.unwrap_or(project_id);
+        tasks.spawn(async move { client.get_mr_pipelines(mr_project_id, mr.iid).await });
+    }
+
+    let mut latest = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        // A task only fails to join if it panicked; count that MR as having no pipeline
+        if let Ok(pipelines) = joined {
+            latest.push(pipelines?.into_iter().next().map(|p| p.status));
+        }
+    }
+
+    Ok(StatusSummary::from_statuses(latest.iter().map(Option::as_ref)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_line_counts() {
+        let statuses = [
+            Some(PipelineStatus::Failed),
+            Some(PipelineStatus::Success),
+            Some(PipelineStatus::Running),
+            Some(PipelineStatus::Failed),
+            Some(PipelineStatus::Success),
+            Some(PipelineStatus::Canceled),
+            None,
+        ];
+
+        let summary = StatusSummary::from_statuses(statuses.iter().map(Option::as_ref));
+        assert_eq!(
+            summary,
+            StatusSummary { failed: 2, running: 1, pending: 0, success: 2 }
+        );
+        assert_eq!(summary.status_line(), "peeplab ✗2 ⟳1 ✓2");
+    }
+
+    #[test]
+    fn test_status_line_pending_and_empty() {
+        let pending = StatusSummary::from_statuses([Some(&PipelineStatus::Created)]);
+        assert_eq!(pending.status_line(), "peeplab ○1");
+
+        assert_eq!(StatusSummary::default().status_line(), "peeplab -");
+    }
+}
@@ -1,28 +1,273 @@
-use crate::app::App;
-use chrono::Utc;
+use crate::app::{is_thread_reply, App};
+use crate::gitlab::models::{Note, Position};
+use crate::ui::markdown::{code_style, is_code_fence, markdown_line, MarkdownLine};
+use crate::ui::text::{text_width, wrap_spans};
+use crate::util::format_timestamp;
+use std::collections::HashSet;
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-fn format_relative_time(dt: chrono::DateTime<chrono::Utc>) -> String {
-    let duration = Utc::now().signed_duration_since(dt);
+/// Compute the list offset so that the selected item is fully visible.
+///
+/// Starts from the stored `offset` and only moves it as far as needed: up when the
+/// selection is above the viewport, down while the items from the offset through the
+/// selection don't fit in `viewport_height` lines.
+fn compute_scroll_offset(
+    item_heights: &[usize],
+    selected: usize,
+    offset: usize,
+    viewport_height: usize,
+) -> usize {
+    if item_heights.is_empty() {
+        return 0;
+    }
+
+    let selected = selected.min(item_heights.len() - 1);
+    let mut offset = offset.min(selected);
+
+    while offset < selected
+        && item_heights[offset..=selected].iter().sum::<usize>() > viewport_height
+    {
+        offset += 1;
+    }
+
+    offset
+}
+
+/// Where a review comment points: `src/app.rs:142`, `old.rs → new.rs:7` for a renamed
+/// file, or just the path when the comment isn't on a line. Comments on removed lines
+/// only have the old line number.
+fn position_label(position: &Position) -> Option<String> {
+    let path = match (&position.old_path, &position.new_path) {
+        (Some(old), Some(new)) if old != new => format!("{} → {}", old, new),
+        (_, Some(path)) | (Some(path), None) => path.clone(),
+        (None, None) => return None,
+    };
+    Some(match position.new_line.or(position.old_line) {
+        Some(line) => format!("{}:{}", path, line),
+        None => path,
+    })
+}
+
+/// Body indent of a thread's first note; replies are indented one step further
+const BODY_INDENT: &str = "  ";
+const REPLY_INDENT: &str = "    ";
+
+/// Indented, wrapped body lines of a note. User comments are rendered as markdown;
+/// system notes are plain generated text and stay as they are.
+fn body_lines(note: &Note, body_style: Style, indent: &str, content_width: usize) -> Vec<Line<'static>> {
+    let mut in_code_block = false;
+    let mut lines = Vec::new();
+
+    for line in note.body.lines() {
+        let fence = is_code_fence(line);
+        let styled = if note.system {
+            markdown_plain(line, body_style)
+        } else if in_code_block || fence {
+            markdown_plain(line, code_style(body_style))
+        } else {
+            markdown_line(line, body_style)
+        };
+        if fence && !note.system {
+            in_code_block = !in_code_block;
+        }
+
+        // Wrap by display width, so wide (CJK) glyphs don't overflow
+        // `content_width` already leaves room for the base indent
+        let extra_indent = indent.len().saturating_sub(BODY_INDENT.len());
+        let width = content_width.saturating_sub(extra_indent + text_width(&styled.prefix));
+        let wrapped = wrap_spans(&styled.spans, width);
+        if wrapped.is_empty() {
+            lines.push(Line::from(format!("{}{}", indent, styled.prefix))); // Empty line
+            continue;
+        }
+        for (index, piece) in wrapped.into_iter().enumerate() {
+            let marker = if index == 0 { &styled.prefix } else { &styled.continuation };
+            let mut spans = vec![Span::raw(format!("{}{}", indent, marker))];
+            spans.extend(piece);
+            lines.push(Line::from(spans));
+        }
+    }
+
+    lines
+}
 
-    if duration.num_days() > 0 {
-        format!("{}d ago", duration.num_days())
-    } else if duration.num_hours() > 0 {
-        format!("{}h ago", duration.num_hours())
-    } else if duration.num_minutes() > 0 {
-        format!("{}m ago", duration.num_minutes())
+/// A line shown verbatim, without markdown parsing
+fn markdown_plain(line: &str, style: Style) -> MarkdownLine {
+    MarkdownLine {
+        prefix: String::new(),
+        continuation: String::new(),
+        spans: vec![Span::styled(line.to_string(), style)],
+    }
+}
+
+/// Lines of a comment opened full-screen: where it points, author and time, then the
+/// whole rendered body. Left unwrapped; the viewer wraps them to its width.
+pub fn full_note_lines(app: &App, note: &Note) -> Vec<Line<'static>> {
+    let muted = Style::default().fg(Color::DarkGray);
+    let author_style = Style::default()
+        .fg(if app.is_highlighted_author(&note.author.username) { Color::Magenta } else { Color::Cyan })
+        .add_modifier(Modifier::BOLD);
+    let body_style = if note.system {
+        Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)
     } else {
-        "just now".to_string()
+        Style::default()
+    };
+
+    let mut lines = Vec::new();
+    if let Some(location) = note.position.as_ref().and_then(position_label) {
+        lines.push(Line::styled(location, muted));
+    }
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} (@{})", note.author.name, note.author.username), author_style),
+        Span::raw(" • "),
+        Span::styled(format_timestamp(note.created_at, app.relative_timestamps), muted),
+    ]));
+    lines.push(Line::from(""));
+    lines.extend(body_lines(note, body_style, "", usize::MAX));
+    lines
+}
+
+/// Number of body lines shown for a collapsed note in compact mode
+const COMPACT_BODY_LINES: usize = 2;
+
+/// Build the rendered lines for each note.
+///
+/// In compact mode every note except the selected one is collapsed to its first
+/// few body lines, so moving the selection reveals each comment in full. Notes in
+/// `new_note_ids` get a "new" badge. Replies are indented under the note that
+/// started their thread.
+fn build_note_lines<'a>(
+    app: &App,
+    notes: &[&'a Note],
+    new_note_ids: &HashSet<u64>,
+    selected: usize,
+    content_width: usize,
+) -> Vec<Vec<Line<'a>>> {
+    notes
+        .iter()
+        .enumerate()
+        .map(|(idx, note)| {
+            let expanded = !app.compact_comments || idx == selected;
+            let reply = is_thread_reply(notes, idx);
+            let highlighted = app.is_highlighted_author(&note.author.username);
+
+            let author_style = Style::default()
+                .fg(if highlighted { Color::Magenta } else { Color::Cyan })
+                .add_modifier(Modifier::BOLD);
+            // System notes (label changes, approvals, ...) are dimmed
+            let body_style = if note.system {
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)
+            } else {
+                Style::default()
+            };
+
+            let created = format_timestamp(note.created_at, app.relative_timestamps);
+
+            let mut header_spans = Vec::new();
+            if reply {
+                header_spans.push(Span::styled("  ↳ ", Style::default().fg(Color::DarkGray)));
+            }
+            if new_note_ids.contains(&note.id) {
+                header_spans.push(Span::styled(
+                    "new ",
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                header_spans.push(Span::raw(" "));
+            }
+            if highlighted {
+                header_spans.push(Span::styled("★ ", author_style));
+            }
+            header_spans.extend([
+                Span::styled(&note.author.name, author_style),
+                Span::raw(" • "),
+                Span::styled(created, Style::default().fg(Color::DarkGray)),
+            ]);
+
+            let header = Line::from(header_spans);
+
+            let indent = if reply { REPLY_INDENT } else { BODY_INDENT };
+            let mut body_lines = body_lines(note, body_style, indent, content_width);
+            if !expanded && body_lines.len() > COMPACT_BODY_LINES {
+                let hidden = body_lines.len() - COMPACT_BODY_LINES;
+                body_lines.truncate(COMPACT_BODY_LINES);
+                body_lines.push(Line::from(Span::styled(
+                    format!("{}… {} more line{}", indent, hidden, if hidden == 1 { "" } else { "s" }),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+
+            // Review comments open with the code they're on; replies share their thread's
+            let mut lines = Vec::new();
+            if let Some(location) = note.position.as_ref().filter(|_| !reply).and_then(position_label) {
+                lines.push(Line::styled(
+                    format!("  {}", location),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            lines.push(header);
+            lines.extend(body_lines);
+            lines.push(Line::from("")); // Separator
+
+            lines
+        })
+        .collect()
+}
+
+/// Maximum number of draft lines shown in the comment input before it scrolls
+const NOTE_INPUT_MAX_LINES: usize = 6;
+
+fn render_note_input(f: &mut Frame, app: &App, area: Rect) {
+    let mut lines: Vec<Line> = app.note_draft.split('\n').map(Line::from).collect();
+    if let Some(last) = lines.last_mut() {
+        last.spans.push(Span::styled(
+            "█",
+            Style::default().fg(Color::White).add_modifier(Modifier::SLOW_BLINK),
+        ));
     }
+
+    // Keep the cursor line in view for long drafts
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let scroll = lines.len().saturating_sub(visible_height) as u16;
+
+    let input = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" New comment: Enter to post, Alt+Enter for new line, Esc to cancel ")
+                .style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+
+    f.render_widget(input, area);
 }
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // The comment input sits below the list, whatever state the list is in
+    let area = if app.is_composing_note {
+        let draft_lines = app.note_draft.split('\n').count().min(NOTE_INPUT_MAX_LINES);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(draft_lines as u16 + 2),
+            ])
+            .split(area);
+        render_note_input(f, app, chunks[1]);
+        chunks[0]
+    } else {
+        area
+    };
+
     let selected_mr = match app.get_selected_mr() {
         Some(mr) => mr,
         None => {
@@ -44,10 +289,8 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    let notes = &selected_mr.notes;
-
-    // Filter out system notes
-    let user_notes: Vec<_> = notes.iter().filter(|note| !note.system).collect();
+    // Same filtered list that navigation indexes into
+    let user_notes = selected_mr.visible_notes(app.show_system_notes);
 
     if user_notes.is_empty() {
         let block = Block::default()
@@ -61,81 +304,28 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Calculate available width for text wrapping
     let content_width = area.width.saturating_sub(4) as usize; // Account for borders and padding
 
-    let items: Vec<ListItem> = user_notes
-        .iter()
-        .map(|note| {
-            let author_style = Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD);
-
-            let time_ago = format_relative_time(note.created_at);
-
-            // Build header line with optional file/line info
-            let mut header_spans = vec![
-                Span::styled(&note.author.name, author_style),
-                Span::raw(" • "),
-                Span::styled(time_ago, Style::default().fg(Color::DarkGray)),
-            ];
-
-            // Add file and line information if available
-            if let Some(position) = &note.position {
-                if let Some(new_path) = &position.new_path {
-                    header_spans.push(Span::raw(" • "));
-                    header_spans.push(Span::styled(
-                        new_path.clone(),
-                        Style::default().fg(Color::Yellow),
-                    ));
-
-                    if let Some(new_line) = position.new_line {
-                        header_spans.push(Span::styled(
-                            format!(":{}", new_line),
-                            Style::default().fg(Color::Yellow),
-                        ));
-                    }
-                }
-            }
-
-            let header = Line::from(header_spans);
-
-            // Process body - handle multi-line and wrap
-            let body_lines: Vec<Line> = note
-                .body
-                .lines()
-                .flat_map(|line| {
-                    // Wrap long lines
-                    let chars: Vec<char> = line.chars().collect();
-                    let mut wrapped_lines = Vec::new();
-
-                    for chunk in chars.chunks(content_width) {
-                        let chunk_str: String = chunk.iter().collect();
-                        wrapped_lines.push(Line::from(vec![
-                            Span::raw("  "), // Indent body
-                            Span::raw(chunk_str),
-                        ]));
-                    }
-
-                    if wrapped_lines.is_empty() {
-                        vec![Line::from("  ")] // Empty line
-                    } else {
-                        wrapped_lines
-                    }
-                })
-                .collect();
-
-            // Combine header and body
-            let mut lines = vec![header];
-            lines.extend(body_lines);
-            lines.push(Line::from("")); // Separator
+    // Clamp the selected index to the number of user notes
+    let clamped_index = selected_mr.selected_note_index.min(user_notes.len().saturating_sub(1));
 
-            ListItem::new(lines)
-        })
-        .collect();
+    let note_lines = build_note_lines(
+        app,
+        &user_notes,
+        &selected_mr.new_note_ids,
+        clamped_index,
+        content_width,
+    );
+    let item_heights: Vec<usize> = note_lines.iter().map(|lines| lines.len()).collect();
+    let items: Vec<ListItem> = note_lines.into_iter().map(ListItem::new).collect();
 
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Comments (press 'c' to toggle view)"),
+                .title(format!(
+                    "Comments{}{} (press 'c' to toggle view, 'a' add comment, 'z' compact, 's' system notes)",
+                    if app.compact_comments { " [compact]" } else { "" },
+                    if app.show_system_notes { " [+system]" } else { "" },
+                )),
         )
         .highlight_style(
             Style::default()
@@ -144,10 +334,296 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         )
         .highlight_symbol("> ");
 
-    let mut state = ListState::default();
-    // Clamp the selected index to the number of user notes
-    let clamped_index = selected_mr.selected_note_index.min(user_notes.len().saturating_sub(1));
+    let viewport_height = area.height.saturating_sub(2) as usize; // Account for borders
+    let offset = compute_scroll_offset(
+        &item_heights,
+        clamped_index,
+        selected_mr.comments_scroll_offset,
+        viewport_height,
+    );
+
+    let mut state = ListState::default().with_offset(offset);
     state.select(Some(clamped_index));
 
     f.render_stateful_widget(list, area, &mut state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gitlab::models::User;
+    use chrono::Utc;
+
+    fn create_test_note(id: u64, body: &str) -> Note {
+        Note {
+            id,
+            body: body.to_string(),
+            author: User {
+                id: 1,
+                username: "testuser".to_string(),
+                name: "Test User".to_string(),
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            system: false,
+            noteable_id: 1,
+            noteable_type: "MergeRequest".to_string(),
+            project_id: 123,
+            noteable_iid: 10,
+            resolvable: false,
+            confidential: false,
+            internal: false,
+            position: None,
+            discussion_id: None,
+        }
+    }
+
+    #[test]
+    fn test_compact_mode_expands_only_selected_note() {
+        let long_body = "line 1\nline 2\nline 3\nline 4\nline 5";
+        let notes: Vec<Note> = (0..3).map(|i| create_test_note(i, long_body)).collect();
+        let note_refs: Vec<&Note> = notes.iter().collect();
+
+        let mut app = App::new(123, None, false, 1);
+        app.compact_comments = true;
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 1, 80);
+
+        // header + 5 body lines + separator
+        assert_eq!(lines[1].len(), 7);
+        // header + 2 body lines + "more lines" marker + separator
+        assert_eq!(lines[0].len(), 5);
+        assert_eq!(lines[2].len(), 5);
+    }
+
+    #[test]
+    fn test_non_compact_mode_expands_all_notes() {
+        let notes: Vec<Note> = (0..3).map(|i| create_test_note(i, "a\nb\nc\nd")).collect();
+        let note_refs: Vec<&Note> = notes.iter().collect();
+
+        let app = App::new(123, None, false, 1);
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert!(lines.iter().all(|item| item.len() == 6));
+    }
+
+    #[test]
+    fn test_body_wraps_by_display_width() {
+        // Six double-width glyphs take 12 columns: two lines at width 6, one at width 12
+        let notes = [create_test_note(1, "日本語日本語")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let narrow = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 6);
+        assert_eq!(narrow[0].len(), 4); // header + 2 body lines + separator
+        assert_eq!(narrow[0][1].to_string(), "  日本語");
+
+        let wide = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 12);
+        assert_eq!(wide[0].len(), 3);
+
+        // ASCII of the same character count fits on one line at width 6
+        let ascii = [create_test_note(2, "abcdef")];
+        let ascii_refs: Vec<&Note> = ascii.iter().collect();
+        assert_eq!(build_note_lines(&app, &ascii_refs, &HashSet::new(), 0, 6)[0].len(), 3);
+    }
+
+    #[test]
+    fn test_compact_mode_keeps_short_notes_intact() {
+        let notes = [create_test_note(1, "short")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+
+        let mut app = App::new(123, None, false, 1);
+        app.compact_comments = true;
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 5, 80);
+        assert_eq!(lines[0].len(), 3);
+    }
+
+    #[test]
+    fn test_scroll_offset_keeps_selection_near_end_visible() {
+        // 50 comments of 4 lines each in a 20-line viewport: 5 fit at a time
+        let heights = vec![4; 50];
+        let offset = compute_scroll_offset(&heights, 48, 0, 20);
+        assert_eq!(offset, 44);
+        assert!(heights[offset..=48].iter().sum::<usize>() <= 20);
+
+        // Last item ends up at the bottom of the viewport
+        assert_eq!(compute_scroll_offset(&heights, 49, 0, 20), 45);
+    }
+
+    #[test]
+    fn test_scroll_offset_unchanged_when_selection_visible() {
+        let heights = vec![3; 10];
+        assert_eq!(compute_scroll_offset(&heights, 5, 4, 12), 4);
+    }
+
+    #[test]
+    fn test_scroll_offset_moves_up_to_selection() {
+        let heights = vec![3; 10];
+        assert_eq!(compute_scroll_offset(&heights, 2, 6, 12), 2);
+    }
+
+    #[test]
+    fn test_scroll_offset_tall_item_and_empty_list() {
+        // An item taller than the viewport is shown from its top
+        assert_eq!(compute_scroll_offset(&[2, 30, 2], 1, 0, 10), 1);
+        assert_eq!(compute_scroll_offset(&[], 3, 2, 10), 0);
+    }
+
+    #[test]
+    fn test_header_time_follows_relative_timestamps() {
+        let notes = [create_test_note(1, "hello")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let mut app = App::new(123, None, false, 1);
+
+        let header = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80)[0][0].to_string();
+        assert!(header.contains("just now"));
+
+        app.relative_timestamps = false;
+        let header = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80)[0][0].to_string();
+        assert!(header.contains(&crate::util::format_absolute_time(notes[0].created_at)));
+        assert!(!header.contains("just now"));
+    }
+
+    #[test]
+    fn test_highlighted_author_gets_marker() {
+        let notes = [create_test_note(1, "hello")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+
+        let mut app = App::new(123, None, false, 1);
+        let plain = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert_ne!(plain[0][0].spans[0].content, "★ ");
+
+        app.highlight_authors = vec!["testuser".to_string()];
+        let highlighted = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert_eq!(highlighted[0][0].spans[0].content, "★ ");
+    }
+
+    #[test]
+    fn test_body_renders_markdown_and_code_blocks() {
+        let notes = [create_test_note(1, "> quoted\n- **fix** it\n```\n- not a list\n```")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        let text: Vec<String> = lines[0][1..6].iter().map(|line| line.to_string()).collect();
+        assert_eq!(text, vec!["  │ quoted", "  • fix it", "  ```", "  - not a list", "  ```"]);
+        assert!(lines[0][2].spans[1].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(lines[0][4].spans[1].style.fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_wrapped_list_item_keeps_indent() {
+        let notes = [create_test_note(1, "- abcdefgh")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 6);
+        let text: Vec<String> = lines[0][1..3].iter().map(|line| line.to_string()).collect();
+        assert_eq!(text, vec!["  • abcd", "    efgh"]);
+    }
+
+    #[test]
+    fn test_system_note_body_is_not_parsed() {
+        let mut note = create_test_note(1, "changed the description *again*");
+        note.system = true;
+        let notes = [note];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert_eq!(lines[0][1].to_string(), "  changed the description *again*");
+    }
+
+    fn position(old_path: &str, new_path: &str, old_line: Option<u32>, new_line: Option<u32>) -> Position {
+        Position {
+            base_sha: None,
+            start_sha: None,
+            head_sha: None,
+            old_path: Some(old_path.to_string()),
+            new_path: Some(new_path.to_string()),
+            old_line,
+            new_line,
+        }
+    }
+
+    #[test]
+    fn test_position_label() {
+        let label = |p: Position| position_label(&p);
+        assert_eq!(label(position("src/app.rs", "src/app.rs", None, Some(142))).as_deref(), Some("src/app.rs:142"));
+        // A removed line only exists in the old file
+        assert_eq!(label(position("src/app.rs", "src/app.rs", Some(12), None)).as_deref(), Some("src/app.rs:12"));
+        assert_eq!(label(position("src/app.rs", "src/app.rs", None, None)).as_deref(), Some("src/app.rs"));
+        assert_eq!(label(position("old.rs", "new.rs", Some(6), Some(7))).as_deref(), Some("old.rs → new.rs:7"));
+        assert_eq!(label(position("old.rs", "new.rs", None, None)).as_deref(), Some("old.rs → new.rs"));
+
+        let mut no_paths = position("a", "a", None, Some(1));
+        no_paths.old_path = None;
+        no_paths.new_path = None;
+        assert_eq!(position_label(&no_paths), None);
+    }
+
+    #[test]
+    fn test_position_header_is_dim_line_above_author() {
+        let mut note = create_test_note(1, "nit");
+        note.position = Some(position("src/lib.rs", "src/lib.rs", None, Some(3)));
+        let notes = [note];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert_eq!(lines[0].len(), 4); // location + header + body + separator
+        assert_eq!(lines[0][0].to_string(), "  src/lib.rs:3");
+        assert_eq!(lines[0][0].style.fg, Some(Color::DarkGray));
+        assert!(!lines[0][1].to_string().contains("src/lib.rs"));
+    }
+
+    #[test]
+    fn test_full_note_lines_show_whole_body_unwrapped() {
+        let long_line = "word ".repeat(40);
+        let mut note = create_test_note(1, &format!("# Title\n{}\n- item", long_line.trim_end()));
+        note.position = Some(position("src/lib.rs", "src/lib.rs", None, Some(3)));
+        let mut app = App::new(123, None, false, 1);
+        app.compact_comments = true;
+
+        let lines = full_note_lines(&app, &note);
+        assert_eq!(lines.len(), 6); // location + header + blank + 3 body lines
+        assert_eq!(lines[0].to_string(), "src/lib.rs:3");
+        assert!(lines[1].to_string().starts_with("Test User (@testuser) • "));
+        assert_eq!(lines[3].to_string(), "Title");
+        assert_eq!(lines[4].to_string(), long_line.trim_end());
+        assert_eq!(lines[5].to_string(), "• item");
+    }
+
+    #[test]
+    fn test_replies_are_indented_under_thread() {
+        let mut first = create_test_note(1, "question");
+        first.discussion_id = Some("a".to_string());
+        first.position = Some(position("src/app.rs", "src/app.rs", None, Some(142)));
+        let mut reply = create_test_note(2, "answer");
+        reply.discussion_id = Some("a".to_string());
+        reply.position = first.position.clone();
+        let notes = [first, reply, create_test_note(3, "standalone")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+        let app = App::new(123, None, false, 1);
+
+        let lines = build_note_lines(&app, &note_refs, &HashSet::new(), 0, 80);
+        assert_eq!(lines[0][0].to_string(), "  src/app.rs:142");
+        assert_eq!(lines[0][2].to_string(), "  question");
+
+        let reply_header = lines[1][0].to_string();
+        assert!(reply_header.starts_with("  ↳ Test User"));
+        assert!(!reply_header.contains("src/app.rs"));
+        assert_eq!(lines[1][1].to_string(), "    answer");
+
+        assert_eq!(lines[2][1].to_string(), "  standalone");
+    }
+
+    #[test]
+    fn test_new_note_gets_badge() {
+        let notes = [create_test_note(1, "old"), create_test_note(2, "new")];
+        let note_refs: Vec<&Note> = notes.iter().collect();
+
+        let app = App::new(123, None, false, 1);
+        let lines = build_note_lines(&app, &note_refs, &HashSet::from([2]), 0, 80);
+        assert_ne!(lines[0][0].spans[0].content, "new ");
+        assert_eq!(lines[1][0].spans[0].content, "new ");
+    }
+}
@@ -0,0 +1,178 @@
+use crate::app::{App, TrackedMergeRequest};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Style a single markdown line: headings, list items and code fences
+fn markdown_line(line: &str, in_code_block: bool) -> Line<'static> {
+    let trimmed = line.trim_start();
+
+    if in_code_block || trimmed.starts_with("```") {
+        return Line::from(Span::styled(
+            line.to_string(),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
+    if trimmed.starts_with('#') {
+        let heading = trimmed.trim_start_matches('#').trim_start();
+        return Line::from(Span::styled(
+            heading.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if let Some(item) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+    {
+        let indent = &line[..line.len() - trimmed.len()];
+        return Line::from(vec![
+            Span::raw(format!("{}• ", indent)),
+            Span::raw(item.to_string()),
+        ]);
+    }
+
+    Line::from(line.to_string())
+}
+
+/// Convert an MR description into styled lines
+fn description_lines(description: &str) -> Vec<Line<'static>> {
+    let mut in_code_block = false;
+    description
+        .lines()
+        .map(|line| {
+            let is_fence = line.trim_start().starts_with("```");
+            let styled = markdown_line(line, in_code_block);
+            if is_fence {
+                in_code_block = !in_code_block;
+            }
+            styled
+        })
+        .collect()
+}
+
+/// Approval summary plus who approved, e.g. `2/2 approved (by @alice, @bob)`
+fn approvals_text(tracked_mr: &TrackedMergeRequest) -> String {
+    match &tracked_mr.approvals {
+        Some(approvals) if approvals.approved_by.is_empty() => approvals.summary(),
+        Some(approvals) => {
+            let approvers: Vec<String> = approvals
+                .approved_by
+                .iter()
+                .map(|approver| format!("@{}", approver.user.username))
+                .collect();
+            format!("{} (by {})", approvals.summary(), approvers.join(", "))
+        }
+        None => "loading…".to_string(),
+    }
+}
+
+/// Labels as compact chips: ` bug `  ` ui `
+fn label_chips(labels: &[String]) -> Vec<Span<'static>> {
+    let chip_style = Style::default().fg(Color::Black).bg(Color::Gray);
+    let mut spans = Vec::new();
+    for (index, label) in labels.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::raw(" "));
+        }
+        spans.push(Span::styled(format!(" {} ", label), chip_style));
+    }
+    spans
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let selected_mr = match app.get_selected_mr() {
+        Some(mr) => mr,
+        None => {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title("Description");
+            f.render_widget(block, area);
+            return;
+        }
+    };
+
+    let mr = &selected_mr.mr;
+    let label_style = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("!{} {}", mr.iid, mr.title),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled("Author: ", label_style),
+            Span::styled(
+                format!("{} (@{})", mr.author.name, mr.author.username),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Branch: ", label_style),
+            Span::styled(mr.source_branch.clone(), Style::default().fg(Color::Green)),
+            Span::raw(" → "),
+            Span::styled(mr.target_branch.clone(), Style::default().fg(Color::Green)),
+        ]),
+        Line::from(vec![
+            Span::styled("State: ", label_style),
+            Span::raw(mr.state.clone()),
+            Span::raw(if mr.draft { " (draft)" } else { "" }),
+            Span::raw(
+                mr.detailed_merge_status
+                    .as_deref()
+                    .map(|status| format!(" • merge status: {}", status))
+                    .unwrap_or_default(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Approvals: ", label_style),
+            Span::raw(approvals_text(selected_mr)),
+        ]),
+        Line::from(if mr.labels.is_empty() {
+            vec![Span::styled("Labels: ", label_style), Span::raw("none")]
+        } else {
+            std::iter::once(Span::styled("Labels: ", label_style))
+                .chain(label_chips(&mr.labels))
+                .collect()
+        }),
+        Line::from(vec![
+            Span::styled("Created: ", label_style),
+            Span::raw(mr.created_at.format("%Y-%m-%d %H:%M").to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("URL: ", label_style),
+            Span::raw(mr.web_url.clone()),
+        ]),
+        Line::from(""),
+    ];
+
+    match mr.description.as_deref() {
+        Some(description) if !description.trim().is_empty() => {
+            lines.extend(description_lines(description));
+        }
+        _ => lines.push(Line::from(Span::styled(
+            "(no description)",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        ))),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Description (press 'i' to close, j/k to scroll)"),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.description_scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
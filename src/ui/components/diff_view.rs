@@ -0,0 +1,51 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::help::centered_rect;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Same placement as the log viewer, whose scroll state the diff shares
+    let diff_area = centered_rect(90, 90, area);
+    f.render_widget(Clear, diff_area);
+
+    let lines = &app.log_processed_lines;
+    let content_height = diff_area.height.saturating_sub(2) as usize; // Account for borders
+    let total_lines = lines.len();
+    let max_offset = total_lines.saturating_sub(content_height);
+    let scroll_offset = app.log_scroll_offset.min(max_offset);
+    let end = (scroll_offset + content_height).min(total_lines);
+    let visible_lines: Vec<Line> = lines[scroll_offset..end].to_vec();
+
+    let scroll_indicator = if total_lines > content_height {
+        format!(" [{}/{}]", scroll_offset + 1, max_offset + 1)
+    } else {
+        String::new()
+    };
+    let mr_label = app
+        .get_selected_mr()
+        .map(|tmr| format!("MR #{}: ", tmr.mr.iid))
+        .unwrap_or_default();
+    let file_count = app.diff_files.len();
+    let title = format!(
+        "Changes: {}{} file{}{} (q/Esc close, j/k scroll)",
+        mr_label,
+        file_count,
+        if file_count == 1 { "" } else { "s" },
+        scroll_indicator
+    );
+
+    // No wrapping: diff lines keep their columns aligned
+    let paragraph = Paragraph::new(visible_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default()),
+    );
+    f.render_widget(paragraph, diff_area);
+}
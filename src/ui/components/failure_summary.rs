@@ -0,0 +1,57 @@
+use crate::app::{App, FAILURE_SUMMARY_TAIL_LINES};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::help::centered_rect;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Same placement as the log viewer, whose scroll state the summary shares
+    let summary_area = centered_rect(90, 90, area);
+    f.render_widget(Clear, summary_area);
+
+    let lines = &app.log_processed_lines;
+    let content_height = summary_area.height.saturating_sub(2) as usize; // Account for borders
+    let total_lines = lines.len();
+    let max_offset = total_lines.saturating_sub(content_height);
+    let scroll_offset = app.log_scroll_offset.min(max_offset);
+    let end = (scroll_offset + content_height).min(total_lines);
+    let visible_lines: Vec<Line> = lines[scroll_offset..end].to_vec();
+
+    let scroll_indicator = if total_lines > content_height {
+        format!(" [{}/{}]", scroll_offset + 1, max_offset + 1)
+    } else {
+        String::new()
+    };
+    let pipeline_label = app
+        .get_selected_pipeline()
+        .map(|pipeline| format!(" in pipeline #{}", pipeline.id))
+        .unwrap_or_default();
+    let loading = if app.failure_summary_pending > 0 {
+        format!(", loading {} log(s)...", app.failure_summary_pending)
+    } else {
+        String::new()
+    };
+    let job_count = app.failure_summary_jobs.len();
+    let title = format!(
+        "{} failed job{}{}, last {} lines each{}{} (q/Esc close, j/k scroll)",
+        job_count,
+        if job_count == 1 { "" } else { "s" },
+        pipeline_label,
+        FAILURE_SUMMARY_TAIL_LINES,
+        loading,
+        scroll_indicator
+    );
+
+    let paragraph = Paragraph::new(visible_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default()),
+    );
+    f.render_widget(paragraph, summary_area);
+}
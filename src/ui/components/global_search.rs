@@ -0,0 +1,72 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::help::centered_rect;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let popup_area = centered_rect(90, 90, area);
+    f.render_widget(Clear, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(popup_area);
+
+    let cursor = if app.is_global_searching { "_" } else { "" };
+    let case_hint = if app.search_case_sensitive { " [Aa]" } else { "" };
+    let query = Paragraph::new(format!("{}{}", app.global_search_query, cursor)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Search failed job logs of all MRs{} (Enter search, Esc close)",
+                case_hint
+            ))
+            .border_style(Style::default().fg(if app.is_global_searching {
+                Color::Yellow
+            } else {
+                Color::DarkGray
+            })),
+    );
+    f.render_widget(query, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .global_search_results
+        .iter()
+        .map(|result| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("MR #{}", result.mr_iid), Style::default().fg(Color::Cyan)),
+                Span::raw(" / "),
+                Span::styled(result.job_name.clone(), Style::default().fg(Color::Red)),
+                Span::styled(format!(" :{} ", result.line_number + 1), Style::default().fg(Color::DarkGray)),
+                Span::raw(result.line.clone()),
+            ]))
+        })
+        .collect();
+
+    let loading = if app.global_search_pending > 0 {
+        format!(", loading {} log(s)...", app.global_search_pending)
+    } else {
+        String::new()
+    };
+    let title = format!(
+        "{} match(es){} (j/k select, Enter open log, / edit query)",
+        app.global_search_results.len(),
+        loading
+    );
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut state = ListState::default();
+    if !app.global_search_results.is_empty() {
+        state.select(Some(app.selected_global_search_result));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
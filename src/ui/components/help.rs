@@ -26,7 +26,7 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("q", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             Span::raw(" or "),
             Span::styled("Ctrl+C", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-            Span::raw(" - Quit the application"),
+            Span::raw(" - Quit (q asks first while data is loading)"),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -45,6 +45,21 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("h/l", Style::default().fg(Color::Cyan)),
             Span::raw(" - Switch between MR tabs"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("S", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Sort MR tabs: load order / last update / pipeline status"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("M", Style::default().fg(Color::Cyan)),
+            Span::raw(" - List opened / merged / closed / all MRs"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("F5", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Reload the config file"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
@@ -52,6 +67,21 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("k/j", Style::default().fg(Color::Cyan)),
             Span::raw(" - Navigate jobs"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("N/P", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Next/previous failed job"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("K/J", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Scroll comments"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("t/T", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Next/previous comment thread"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("[/]", Style::default().fg(Color::Cyan)),
@@ -67,26 +97,131 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("Enter", Style::default().fg(Color::Cyan)),
             Span::raw(" - View selected job log"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("f/F", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Show only failed jobs / cycle job status filter"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("z", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Collapse passed jobs into a \"+N passed\" row per stage"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("r", Style::default().fg(Color::Cyan)),
             Span::raw(" - Refresh all data"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("Ctrl+R", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Refresh only the current MR's pipelines (and comments)"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("d", Style::default().fg(Color::Cyan)),
             Span::raw(" - Remove current MR from tracking"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("u", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Track the last removed MR again"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("c", Style::default().fg(Color::Cyan)),
             Span::raw(" - Toggle between jobs and comments view"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("z", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Toggle compact comments (selected comment stays expanded)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("s", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Show/hide system notes in comments view"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Open the selected comment full-screen (q/Esc back)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("a", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Add a comment in comments view (Enter posts, Alt+Enter new line)"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("o", Style::default().fg(Color::Cyan)),
             Span::raw(" - Open current MR in browser"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("w", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Open selected job in browser (o in the log viewer)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("O", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Open selected pipeline in browser"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("y u / y b", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Copy the MR's URL / source branch"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("/", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Search the failed jobs' logs of all MRs (Enter opens a match)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("D", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Download the selected job's artifacts"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("i", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Show MR description/details"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("v", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Show the MR's changes (diff viewer: q/Esc close, k/j scroll)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("e", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Summarize the failed jobs of the selected pipeline with their last log lines"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("R", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Retry failed/canceled jobs of the selected pipeline (asks first)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("A", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Approve the current MR, or revoke your approval"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("p", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Pause/resume auto-refresh"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("b", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Switch between the current branch's MR and all open MRs"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("g", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Show the project's pipelines instead of the MRs' (b: default branch / all refs)"),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Log Viewer:",
@@ -116,6 +251,20 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("t", Style::default().fg(Color::Cyan)),
             Span::raw(" - Toggle timestamp display (hidden/date/full)"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("R", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Toggle the raw log (GitLab prefixes and timestamps kept)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("w", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Toggle line wrapping; unwrapped, "),
+            Span::styled("H/L", Style::default().fg(Color::Cyan)),
+            Span::raw(" or "),
+            Span::styled("←/→", Style::default().fg(Color::Cyan)),
+            Span::raw(" scroll sideways"),
+        ]),
         Line::from(vec![
             Span::raw("  "),
             Span::styled("/", Style::default().fg(Color::Cyan)),
@@ -126,6 +275,16 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("n/N", Style::default().fg(Color::Cyan)),
             Span::raw(" - Next/previous search result"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("c", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Toggle case-sensitive search (Alt+c while typing)"),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled("d", Style::default().fg(Color::Cyan)),
+            Span::raw(" - Jump to output added since the log was last viewed"),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Status Indicators:",
@@ -142,6 +301,14 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("⟳", Style::default().fg(Color::Yellow)),
             Span::raw(" - Running"),
         ]),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::raw("✎"),
+            Span::raw(" - Draft MR"),
+            Span::raw("  "),
+            Span::styled("⚠", Style::default().fg(Color::Yellow)),
+            Span::raw(" - Merge blocked (conflicts, needs rebase, ...)"),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Press Esc or ? to close",
@@ -166,7 +333,7 @@ pub fn render(f: &mut Frame, area: Rect) {
 }
 
 /// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -1,8 +1,10 @@
-use crate::app::App;
-use crate::gitlab::JobStatus;
+use crate::app::{App, JobRow};
+use crate::gitlab::{Job, JobStatus};
+use crate::ui::layout::JobRowsArea;
+use chrono::{DateTime, Utc};
 use ratatui::{
     layout::{Constraint, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
@@ -22,50 +24,171 @@ fn format_duration(duration: Option<f64>) -> String {
     }
 }
 
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let _selected_mr = match app.get_selected_mr() {
-        Some(mr) => mr,
-        None => {
-            let block = Block::default().borders(Borders::ALL).title("Jobs");
-            f.render_widget(block, area);
-            return;
-        }
-    };
+/// Seconds shown in the duration column: running jobs count up from `started_at`
+/// (GitLab leaves `duration` null until they finish), others use `duration`
+fn display_duration(job: &Job, now: DateTime<Utc>) -> Option<f64> {
+    match (&job.status, job.started_at) {
+        (JobStatus::Running, Some(started)) => Some(seconds_between(started, now)),
+        _ => job.duration,
+    }
+}
 
-    let jobs = match app.get_selected_jobs() {
-        Some(jobs) if !jobs.is_empty() => jobs,
-        _ => {
-            let block = Block::default()
-                .borders(Borders::ALL)
-                .title("Jobs")
-                .style(Style::default().fg(Color::Gray));
-            f.render_widget(block, area);
-            return;
+/// Human-readable byte count, e.g. `512B`, `1.2MB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1000 {
+        return format!("{}B", bytes);
+    }
+
+    let mut size = bytes as f64;
+    let mut unit = UNITS[0];
+    for candidate in UNITS {
+        size /= 1000.0;
+        unit = candidate;
+        if size < 1000.0 {
+            break;
         }
+    }
+
+    if size < 10.0 {
+        format!("{:.1}{}", size, unit)
+    } else {
+        format!("{:.0}{}", size, unit)
+    }
+}
+
+fn format_expiry(expire_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let remaining = expire_at.signed_duration_since(now);
+
+    if remaining.num_seconds() <= 0 {
+        "expired".to_string()
+    } else if remaining.num_days() > 0 {
+        format!("expires in {}d", remaining.num_days())
+    } else if remaining.num_hours() > 0 {
+        format!("expires in {}h", remaining.num_hours())
+    } else {
+        format!("expires in {}m", remaining.num_minutes().max(1))
+    }
+}
+
+/// Detail line for the selected job, e.g. `artifacts: 12MB, expires in 3d`
+fn artifacts_detail(job: &Job, now: DateTime<Utc>) -> Option<String> {
+    let size = job.artifacts_size()?;
+    let mut detail = format!("artifacts: {}", format_size(size));
+    if let Some(expire_at) = job.artifacts_expire_at {
+        detail.push_str(&format!(", {}", format_expiry(expire_at, now)));
+    }
+    Some(detail)
+}
+
+fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    to.signed_duration_since(from).num_milliseconds().max(0) as f64 / 1000.0
+}
+
+/// Queue vs run time of a job, e.g. `queued 12s, ran 3m 04s`. Running jobs count up to
+/// `now`; jobs that never queue on their own (manual, skipped) have no timing.
+fn timing_detail(job: &Job, now: DateTime<Utc>) -> Option<String> {
+    match (job.started_at, job.finished_at) {
+        (Some(started), Some(finished)) => Some(format!(
+            "queued {}, ran {}",
+            format_duration(Some(seconds_between(job.created_at, started))),
+            format_duration(Some(job.duration.unwrap_or_else(|| seconds_between(started, finished))))
+        )),
+        (Some(started), None) => Some(format!(
+            "queued {}, running {}",
+            format_duration(Some(seconds_between(job.created_at, started))),
+            format_duration(Some(seconds_between(started, now)))
+        )),
+        (None, Some(finished)) => Some(format!(
+            "queued {}, never started",
+            format_duration(Some(seconds_between(job.created_at, finished)))
+        )),
+        (None, None) => match job.status {
+            JobStatus::Created | JobStatus::Pending => Some(format!(
+                "queued {}",
+                format_duration(Some(seconds_between(job.created_at, now)))
+            )),
+            _ => None,
+        },
+    }
+}
+
+/// Render the jobs table; returns where the job rows ended up, for mouse hit-testing
+pub fn render(f: &mut Frame, app: &App, area: Rect) -> Option<JobRowsArea> {
+    if app.get_selected_mr().is_none() && app.project_pipelines.is_none() {
+        let block = Block::default().borders(Borders::ALL).title("Jobs");
+        f.render_widget(block, area);
+        return None;
+    }
+
+    let mut title = match &app.job_status_filter {
+        Some(status) => format!("Jobs [{} only]", status.label()),
+        None => "Jobs".to_string(),
     };
+    if app.collapse_passed_jobs {
+        title.push_str(" [passed collapsed]");
+    }
+
+    let jobs = app.get_visible_jobs();
+    let job_rows = app.get_job_rows();
+    if job_rows.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(app.theme.muted));
+        f.render_widget(block, area);
+        return None;
+    }
 
-    let rows: Vec<Row> = jobs
+    // Redrawn on every tick, so running jobs' elapsed time stays current
+    let now = Utc::now();
+    let rows: Vec<Row> = job_rows
         .iter()
-        .map(|job| {
-            let (status_color, status_text) = match job.status {
-                JobStatus::Success => (Color::Green, format!("{} success", job.status.symbol())),
-                JobStatus::Failed => (Color::Red, format!("{} failed", job.status.symbol())),
-                JobStatus::Running => (Color::Yellow, format!("{} running", job.status.symbol())),
-                JobStatus::Pending => (Color::Blue, format!("{} pending", job.status.symbol())),
-                JobStatus::Canceled => (Color::Gray, format!("{} canceled", job.status.symbol())),
-                JobStatus::Skipped => (Color::DarkGray, format!("{} skipped", job.status.symbol())),
-                _ => (Color::Gray, format!("{} {:?}", job.status.symbol(), job.status).to_lowercase()),
+        .map(|row| {
+            let job = match row {
+                JobRow::Job(job) => job,
+                JobRow::Passed { stage, count } => {
+                    return Row::new(vec![
+                        Cell::from(stage.to_string()),
+                        Cell::from(format!("+{} passed", count)),
+                        Cell::from(format!("{} {}", JobStatus::Success.symbol(), JobStatus::Success.label())),
+                        Cell::from(""),
+                    ])
+                    .style(Style::default().fg(app.theme.muted));
+                }
             };
+            let status_text = format!("{} {}", job.status.symbol(), job.status.label());
+            let status_color = app.theme.job_status(&job.status);
 
             Row::new(vec![
                 Cell::from(job.stage.clone()),
                 Cell::from(job.name.clone()),
                 Cell::from(status_text).style(Style::default().fg(status_color)),
-                Cell::from(format_duration(job.duration)),
+                Cell::from(format_duration(display_duration(job, now))),
             ])
         })
         .collect();
 
+    let mut block = Block::default().borders(Borders::ALL).title(title);
+    if let Some(job) = jobs.get(app.selected_job_index) {
+        let details: Vec<String> = [timing_detail(job, now), artifacts_detail(job, now)]
+            .into_iter()
+            .flatten()
+            .collect();
+        if !details.is_empty() {
+            block = block.title_bottom(format!(" {} ", details.join(" · ")));
+        }
+    }
+
+    // Rows start below the header line and its one-line margin
+    let inner = block.inner(area);
+    let rows_area = Rect {
+        y: inner.y.saturating_add(2),
+        height: inner.height.saturating_sub(2),
+        ..inner
+    };
+    let job_count = jobs.len();
+
     let table = Table::new(
         rows,
         [
@@ -77,19 +200,105 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     )
     .header(
         Row::new(vec!["Stage", "Job Name", "Status", "Duration"])
-            .style(Style::default().add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(app.theme.header).add_modifier(Modifier::BOLD))
             .bottom_margin(1),
     )
-    .block(Block::default().borders(Borders::ALL).title("Jobs"))
+    .block(block)
     .highlight_style(
         Style::default()
-            .bg(Color::DarkGray)
+            .bg(app.theme.highlight)
             .add_modifier(Modifier::BOLD),
     )
     .highlight_symbol("> ");
 
+    // Summary rows can't be selected; with every job collapsed nothing is
     let mut state = TableState::default();
-    state.select(Some(app.selected_job_index));
+    state.select((!jobs.is_empty()).then_some(app.selected_job_index));
 
     f.render_stateful_widget(table, area, &mut state);
+    Some(JobRowsArea { area: rows_area, offset: state.offset(), count: job_count })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512B");
+        assert_eq!(format_size(1_500), "1.5KB");
+        assert_eq!(format_size(12_000_000), "12MB");
+        assert_eq!(format_size(3_200_000_000), "3.2GB");
+    }
+
+    fn timed_job(status: JobStatus, started_after: Option<i64>, finished_after: Option<i64>) -> Job {
+        let created_at = Utc::now() - Duration::minutes(10);
+        Job {
+            id: 1,
+            name: "test".to_string(),
+            status,
+            stage: "test".to_string(),
+            created_at,
+            started_at: started_after.map(|s| created_at + Duration::seconds(s)),
+            finished_at: finished_after.map(|s| created_at + Duration::seconds(s)),
+            duration: None,
+            web_url: "https://gitlab.com/test/-/jobs/1".to_string(),
+            artifacts: Vec::new(),
+            artifacts_expire_at: None,
+        }
+    }
+
+    #[test]
+    fn test_timing_detail_finished_job() {
+        let job = timed_job(JobStatus::Success, Some(12), Some(196));
+        assert_eq!(timing_detail(&job, Utc::now()).unwrap(), "queued 12s, ran 3m 04s");
+
+        // GitLab's own duration wins over the timestamps
+        let job = Job { duration: Some(61.0), ..job };
+        assert_eq!(timing_detail(&job, Utc::now()).unwrap(), "queued 12s, ran 1m 01s");
+    }
+
+    #[test]
+    fn test_timing_detail_running_and_queued_jobs() {
+        let job = timed_job(JobStatus::Running, Some(30), None);
+        let now = job.created_at + Duration::seconds(100);
+        assert_eq!(timing_detail(&job, now).unwrap(), "queued 30s, running 1m 10s");
+
+        let job = timed_job(JobStatus::Pending, None, None);
+        let now = job.created_at + Duration::seconds(45);
+        assert_eq!(timing_detail(&job, now).unwrap(), "queued 45s");
+
+        let job = timed_job(JobStatus::Canceled, None, Some(20));
+        assert_eq!(timing_detail(&job, Utc::now()).unwrap(), "queued 20s, never started");
+    }
+
+    #[test]
+    fn test_timing_detail_manual_and_skipped_jobs() {
+        assert!(timing_detail(&timed_job(JobStatus::Manual, None, None), Utc::now()).is_none());
+        assert!(timing_detail(&timed_job(JobStatus::Skipped, None, None), Utc::now()).is_none());
+    }
+
+    #[test]
+    fn test_running_job_shows_elapsed_time() {
+        let job = timed_job(JobStatus::Running, Some(30), None);
+        let now = job.created_at + Duration::seconds(155);
+        assert_eq!(display_duration(&job, now), Some(125.0));
+        assert_eq!(format_duration(display_duration(&job, now)), "2m 05s");
+
+        let finished = Job { duration: Some(42.0), ..timed_job(JobStatus::Success, Some(5), Some(47)) };
+        assert_eq!(display_duration(&finished, now), Some(42.0));
+
+        let queued = timed_job(JobStatus::Pending, None, None);
+        assert_eq!(format_duration(display_duration(&queued, now)), "-");
+    }
+
+    #[test]
+    fn test_format_expiry() {
+        let now = Utc::now();
+        assert_eq!(format_expiry(now + Duration::days(3) + Duration::hours(2), now), "expires in 3d");
+        assert_eq!(format_expiry(now + Duration::hours(5), now), "expires in 5h");
+        assert_eq!(format_expiry(now + Duration::seconds(20), now), "expires in 1m");
+        assert_eq!(format_expiry(now - Duration::hours(1), now), "expired");
+    }
 }
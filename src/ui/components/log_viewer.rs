@@ -1,4 +1,5 @@
 use crate::app::{App, TimestampDisplayMode};
+use crate::gitlab::{MergeRequest, Pipeline, PipelineStatus};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -8,16 +9,19 @@ use ratatui::{
 };
 
 /// Highlight search query matches in a line
-fn highlight_search_in_line(line: &Line, query: &str) -> Line<'static> {
+fn highlight_search_in_line(line: &Line, query: &str, case_sensitive: bool) -> Line<'static> {
     // Convert line to plain text for searching
     let line_text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
-    let query_lower = query.to_lowercase();
-    let line_lower = line_text.to_lowercase();
+    let (haystack, needle) = if case_sensitive {
+        (line_text.clone(), query.to_string())
+    } else {
+        (line_text.to_lowercase(), query.to_lowercase())
+    };
 
     // Find all match positions
     let mut matches: Vec<(usize, usize)> = Vec::new();
     let mut start = 0;
-    while let Some(pos) = line_lower[start..].find(&query_lower) {
+    while let Some(pos) = haystack[start..].find(&needle) {
         let match_start = start + pos;
         let match_end = match_start + query.len();
         matches.push((match_start, match_end));
@@ -59,6 +63,31 @@ fn highlight_search_in_line(line: &Line, query: &str) -> Line<'static> {
     Line::from(new_spans)
 }
 
+/// One-line context shown above the log: the MR and the status of the pipeline the job belongs to
+fn context_header(mr: &MergeRequest, pipeline: Option<&Pipeline>) -> String {
+    let mut header = format!("MR #{}: {}", mr.iid, mr.title);
+    if let Some(pipeline) = pipeline {
+        header.push_str(&format!(
+            " • Pipeline #{} {} {}",
+            pipeline.iid,
+            pipeline.status.symbol(),
+            pipeline.status.label()
+        ));
+    }
+    header
+}
+
+/// Context of a project pipeline's job, which belongs to no MR: the pipeline, its ref and status
+fn project_pipeline_header(pipeline: &Pipeline) -> String {
+    format!(
+        "Pipeline #{} on {} {} {}",
+        pipeline.iid,
+        pipeline.ref_name,
+        pipeline.status.symbol(),
+        pipeline.status.label()
+    )
+}
+
 /// Helper function to create a centered rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -87,6 +116,36 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Clear the background to prevent rendering artifacts
     f.render_widget(Clear, log_area);
 
+    // Keep the MR/pipeline context in sight while reading long logs
+    let pipeline = app.get_selected_pipeline();
+    let context = if app.project_pipelines.is_some() {
+        pipeline.map(project_pipeline_header)
+    } else {
+        app.get_selected_mr()
+            .map(|selected_mr| context_header(&selected_mr.mr, pipeline))
+    };
+    let log_area = match context {
+        Some(context) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(log_area);
+
+            let color = match pipeline.map(|p| &p.status) {
+                Some(PipelineStatus::Success) => Color::Green,
+                Some(PipelineStatus::Failed) => Color::Red,
+                Some(PipelineStatus::Running) => Color::Yellow,
+                _ => Color::Gray,
+            };
+            let header = Paragraph::new(context)
+                .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+            f.render_widget(header, chunks[0]);
+
+            chunks[1]
+        }
+        None => log_area,
+    };
+
     let _log_content = match &app.log_content {
         Some(content) => content,
         None => {
@@ -125,10 +184,18 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 let line_number = start + idx;
 
                 // Check if this line has a search match
-                if !app.search_query.is_empty() && app.search_results.contains(&line_number) {
-                    highlight_search_in_line(line, &app.search_query)
+                let line = if !app.search_query.is_empty() && app.search_results.contains(&line_number) {
+                    highlight_search_in_line(line, &app.search_query, app.search_case_sensitive)
                 } else {
                     line.clone()
+                };
+
+                // In delta mode, dim output that was already seen on a previous fetch
+                match app.log_new_content_line {
+                    Some(boundary) if app.log_delta_mode && line_number < boundary => {
+                        line.style(Style::default().fg(Color::DarkGray))
+                    }
+                    _ => line,
                 }
             })
             .collect()
@@ -147,17 +214,20 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     };
 
     let timestamp_indicator = match &app.timestamp_mode {
+        _ if app.raw_log => "[Raw]",
         TimestampDisplayMode::Hidden => "[Timestamps: Hidden]",
         TimestampDisplayMode::DateOnly => "[Timestamps: Date]",
         TimestampDisplayMode::Full => "[Timestamps: Full]",
     };
 
     // Build search indicator
+    let case_indicator = if app.search_case_sensitive { " [case]" } else { "" };
     let search_indicator = if !app.search_results.is_empty() {
         format!(
-            " [Match {}/{}]",
+            " [Match {}/{}]{}",
             app.current_search_result + 1,
-            app.search_results.len()
+            app.search_results.len(),
+            case_indicator
         )
     } else if !app.search_query.is_empty() && !app.is_searching {
         " [No matches]".to_string()
@@ -165,12 +235,30 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         String::new()
     };
 
+    // Build new-output indicator
+    let delta_indicator = match app.log_new_content_line {
+        Some(boundary) => format!(
+            " [{} new lines{}]",
+            total_lines.saturating_sub(boundary),
+            if app.log_delta_mode { ", d: show all" } else { ", d: jump" }
+        ),
+        None => String::new(),
+    };
+
+    let wrap_indicator = match (app.log_wrap, app.log_h_scroll) {
+        (true, _) => String::new(),
+        (false, 0) => " [No wrap]".to_string(),
+        (false, columns) => format!(" [No wrap, →{}]", columns),
+    };
+
     let title = format!(
-        "Job Log: {}{}{}{} (q/Esc close, / search, n/N next/prev, t time)",
+        "Job Log: {}{}{}{}{}{} (q/Esc close, / search, n/N next/prev, t time, R raw, w wrap)",
         job_name,
         if scroll_indicator.is_empty() { " " } else { &scroll_indicator },
         timestamp_indicator,
-        search_indicator
+        wrap_indicator,
+        search_indicator,
+        delta_indicator
     );
 
     // If searching, show search input bar at the bottom
@@ -187,21 +275,26 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         (log_area, None)
     };
 
-    let paragraph = Paragraph::new(visible_lines)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .style(Style::default()),
-        )
-        .wrap(Wrap { trim: false });
+    let paragraph = Paragraph::new(visible_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default()),
+    );
+    // Unwrapped, each log line is one row and long lines scroll sideways
+    let paragraph = if app.log_wrap {
+        paragraph.wrap(Wrap { trim: false })
+    } else {
+        paragraph.scroll((0, app.log_h_scroll.min(u16::MAX as usize) as u16))
+    };
 
     f.render_widget(paragraph, render_area);
 
     // Render search input bar if in search mode
     if let Some(search_area) = search_area {
         let search_line = Line::from(vec![
-            Span::raw("Search: "),
+            Span::styled(case_indicator.trim_start(), Style::default().fg(Color::Magenta)),
+            Span::raw(if app.search_case_sensitive { " Search: " } else { "Search: " }),
             Span::styled(
                 &app.search_query,
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
@@ -215,10 +308,57 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         let search_paragraph = Paragraph::new(search_line).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Enter to search, Esc to cancel ")
+                .title(" Enter to search, Alt+c case sensitivity, Esc to cancel ")
                 .style(Style::default().fg(Color::Cyan)),
         );
 
         f.render_widget(search_paragraph, search_area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_mr() -> MergeRequest {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 12,
+            "title": "Fix login",
+            "author": {"id": 1, "username": "testuser", "name": "Test User"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/test/-/merge_requests/12",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_context_header_with_pipeline() {
+        let pipeline: Pipeline = serde_json::from_value(serde_json::json!({
+            "id": 456,
+            "iid": 45,
+            "status": "failed",
+            "ref": "fix-login",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z",
+            "web_url": "https://gitlab.com/test/-/pipelines/456"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            context_header(&create_test_mr(), Some(&pipeline)),
+            "MR #12: Fix login • Pipeline #45 ✗ failed"
+        );
+        assert_eq!(
+            project_pipeline_header(&pipeline),
+            "Pipeline #45 on fix-login ✗ failed"
+        );
+    }
+
+    #[test]
+    fn test_context_header_without_pipeline() {
+        assert_eq!(context_header(&create_test_mr(), None), "MR #12: Fix login");
+    }
+}
@@ -1,6 +1,16 @@
 pub mod comments_list;
+pub mod description_view;
+pub mod diff_view;
+pub mod failure_summary;
+pub mod global_search;
 pub mod help;
 pub mod job_list;
 pub mod log_viewer;
 pub mod mr_tabs;
+pub mod note_view;
 pub mod pipeline_list;
+pub mod project_pipelines;
+pub mod quit_confirm;
+pub mod retry_confirm;
+pub mod stage_progress;
+pub mod status_bar;
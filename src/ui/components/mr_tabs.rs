@@ -1,50 +1,171 @@
-use crate::app::App;
+use crate::app::{App, MrSort, TrackedMergeRequest};
+use crate::gitlab::client::MrStateFilter;
+use crate::ui::text::{char_width, text_width};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::Line,
-    widgets::{Block, Borders, Tabs},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Tabs},
     Frame,
 };
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
+/// Shorten `s` to at most `max_width` terminal columns, ending in `...` when cut.
+/// Cuts between characters, so multibyte titles (accents, emoji, CJK) never split a glyph.
+fn truncate(s: &str, max_width: usize) -> String {
+    if text_width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let budget = max_width.saturating_sub(3);
+    let mut kept = String::new();
+    let mut width = 0;
+    for c in s.chars() {
+        width += char_width(c);
+        if width > budget {
+            break;
+        }
+        kept.push(c);
+    }
+    format!("{}...", kept)
+}
+
+/// Tab label: pipeline status, failed-request (!), draft (✎) and merge-blocked (⚠)
+/// markers, then the title.
+/// With `show_project` (group scope) the MR reference names its project.
+fn tab_label(tracked_mr: &TrackedMergeRequest, show_project: bool) -> String {
+    let status_indicator = match tracked_mr.pipelines.first() {
+        Some(p) => p.status.symbol(),
+        None if tracked_mr.loading => "⟳",
+        _ => "•",
+    };
+
+    let mut markers = String::new();
+    if tracked_mr.error.is_some() {
+        markers.push_str("! ");
+    }
+    if tracked_mr.mr.draft {
+        markers.push_str("✎ ");
+    }
+    if tracked_mr.mr.is_merge_blocked() {
+        markers.push_str("⚠ ");
+    }
+
+    let reference = match tracked_mr.mr.project_path() {
+        Some(path) if show_project => {
+            let project = path.rsplit('/').next().unwrap_or(&path).to_string();
+            format!("{}!{}", project, tracked_mr.mr.iid)
+        }
+        _ => format!("MR #{}", tracked_mr.mr.iid),
+    };
+
+    format!(
+        "{} {}{}: {}",
+        status_indicator,
+        markers,
+        reference,
+        truncate(&tracked_mr.mr.title, 25)
+    )
+}
+
+/// What to show instead of tabs when no MR is tracked, naming the filters that apply
+fn empty_state_message(app: &App) -> String {
+    if app.refresh_in_flight {
+        return "Loading merge requests...".to_string();
+    }
+
+    let state = match app.mr_state {
+        MrStateFilter::Opened => "open ",
+        MrStateFilter::Merged => "merged ",
+        MrStateFilter::Closed => "closed ",
+        MrStateFilter::All => "",
+    };
+    let labels = if app.label_filter.is_empty() {
+        String::new()
     } else {
-        format!("{}...", &s[..max_len - 3])
+        format!(" labeled {}", app.label_filter.join(", "))
+    };
+    let mrs = format!("No {}merge requests{}", state, labels);
+
+    match (&app.current_branch, app.group_id) {
+        (Some(branch), _) if app.focus_current_branch => format!(
+            "{} for branch '{}' — press r to refresh or b to show all",
+            mrs, branch
+        ),
+        (_, Some(group_id)) => format!("{} in group {} — press r to refresh", mrs, group_id),
+        _ => format!("{} — press r to refresh", mrs),
     }
 }
 
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+/// Where `Tabs` draws each title inside `inner`: one column of padding on either side
+/// and a one-column divider between tabs; tabs that don't fit are left out
+fn tab_rects(inner: Rect, title_widths: &[usize]) -> Vec<Rect> {
+    let mut rects = Vec::new();
+    let mut x = inner.x;
+    for &width in title_widths {
+        if x >= inner.right() {
+            break;
+        }
+        let tab_width = (width as u16 + 2).min(inner.right() - x);
+        rects.push(Rect::new(x, inner.y, tab_width, 1));
+        x = x.saturating_add(tab_width + 1);
+    }
+    rects
+}
+
+/// Render the tabs; returns each tab's rect with the `tracked_mrs` index it shows
+pub fn render(f: &mut Frame, app: &App, area: Rect) -> Vec<(Rect, usize)> {
     if app.tracked_mrs.is_empty() {
         let block = Block::default()
             .borders(Borders::ALL)
             .title("Merge Requests");
-        f.render_widget(block, area);
-        return;
+        let message = Paragraph::new(empty_state_message(app))
+            .style(Style::default().fg(app.theme.muted))
+            .block(block);
+        f.render_widget(message, area);
+        return Vec::new();
     }
 
-    let titles: Vec<Line> = app
-        .tracked_mrs
+    let order = app.mr_display_order();
+    let titles: Vec<Line> = order
         .iter()
-        .map(|tracked_mr| {
-            let status_indicator = match tracked_mr.pipelines.first() {
-                Some(p) => p.status.symbol(),
-                None if tracked_mr.loading => "⟳",
-                _ => "•",
+        .map(|&index| {
+            let tracked_mr = &app.tracked_mrs[index];
+            let label = tab_label(tracked_mr, app.group_id.is_some());
+            let line = if app.is_highlighted_author(&tracked_mr.mr.author.username) {
+                Line::from(vec![
+                    Span::styled("★ ", Style::default().fg(Color::Magenta)),
+                    Span::raw(label),
+                ])
+            } else {
+                Line::from(label)
             };
-            Line::from(format!(
-                "{} MR #{}: {}",
-                status_indicator,
-                tracked_mr.mr.iid,
-                truncate(&tracked_mr.mr.title, 25)
-            ))
+            if tracked_mr.error.is_some() {
+                line.style(Style::default().fg(app.theme.failed))
+            } else {
+                line
+            }
         })
         .collect();
 
+    let mut title = match app.mr_sort {
+        MrSort::LoadOrder => "Merge Requests".to_string(),
+        sort => format!("Merge Requests (by {})", sort.label()),
+    };
+    if !app.label_filter.is_empty() {
+        title.push_str(&format!(" [label: {}]", app.label_filter.join(", ")));
+    }
+    let selected_tab = order.iter().position(|&i| i == app.selected_mr_index).unwrap_or(0);
+
+    let block = Block::default().borders(Borders::ALL).title(title);
+    let title_widths: Vec<usize> = titles.iter().map(Line::width).collect();
+    let click_areas = tab_rects(block.inner(area), &title_widths)
+        .into_iter()
+        .zip(order)
+        .collect();
+
     let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL).title("Merge Requests"))
-        .select(app.selected_mr_index)
+        .block(block)
+        .select(selected_tab)
         .style(Style::default().fg(Color::White))
         .highlight_style(
             Style::default()
@@ -53,4 +174,121 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         );
 
     f.render_widget(tabs, area);
+    click_areas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gitlab::MergeRequest;
+
+    fn create_tracked_mr(draft: bool, detailed_merge_status: Option<&str>) -> TrackedMergeRequest {
+        let mr: MergeRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 10,
+            "title": "Test MR",
+            "draft": draft,
+            "detailed_merge_status": detailed_merge_status,
+            "author": {"id": 1, "username": "testuser", "name": "Test User"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/test/-/merge_requests/10",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }))
+        .unwrap();
+        TrackedMergeRequest::new(mr)
+    }
+
+    #[test]
+    fn test_empty_state_names_branch_filter() {
+        let app = App::new(123, Some("feature-x".to_string()), true, 1);
+        assert_eq!(
+            empty_state_message(&app),
+            "No open merge requests for branch 'feature-x' — press r to refresh or b to show all"
+        );
+    }
+
+    #[test]
+    fn test_empty_state_without_filter() {
+        let mut app = App::new(123, Some("feature-x".to_string()), false, 1);
+        assert_eq!(empty_state_message(&app), "No open merge requests — press r to refresh");
+
+        app.group_id = Some(7);
+        assert_eq!(empty_state_message(&app), "No open merge requests in group 7 — press r to refresh");
+
+        app.group_id = None;
+        app.mr_state = MrStateFilter::Merged;
+        assert_eq!(empty_state_message(&app), "No merged merge requests — press r to refresh");
+
+        app.mr_state = MrStateFilter::All;
+        app.label_filter = vec!["bug".to_string(), "backend".to_string()];
+        assert_eq!(
+            empty_state_message(&app),
+            "No merge requests labeled bug, backend — press r to refresh"
+        );
+
+        app.refresh_in_flight = true;
+        assert_eq!(empty_state_message(&app), "Loading merge requests...");
+    }
+
+    #[test]
+    fn test_tab_rects_match_tabs_widget() {
+        use ratatui::{buffer::Buffer, widgets::Widget};
+
+        let area = Rect::new(0, 0, 30, 1);
+        let rects = tab_rects(area, &[3, 5, 40]);
+        assert_eq!(rects, vec![Rect::new(0, 0, 5, 1), Rect::new(6, 0, 7, 1), Rect::new(14, 0, 16, 1)]);
+
+        // The titles land inside their rects, with the dividers in between
+        let mut buffer = Buffer::empty(area);
+        Tabs::new(vec!["abc", "defgh", "ijk"]).render(area, &mut buffer);
+        let row: String = (0..14).map(|x| buffer[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(row, " abc │ defgh │");
+    }
+
+    #[test]
+    fn test_truncate_multibyte_titles() {
+        assert_eq!(truncate("Short title", 25), "Short title");
+        assert_eq!(truncate("Ajoute la sélection des éléments", 25), "Ajoute la sélection de...");
+        // Emoji take two columns each
+        assert_eq!(truncate("🚀🚀🚀 Launch the rocket pipeline 🎉", 25), "🚀🚀🚀 Launch the rock...");
+        assert_eq!(truncate("修正ログイン画面のレイアウト", 10), "修正ロ...");
+        assert!(text_width(&truncate("修正ログイン画面のレイアウト", 25)) <= 25);
+        // Exactly at the limit nothing is cut
+        assert_eq!(truncate("ééééé", 5), "ééééé");
+        assert_eq!(truncate("éééééé", 5), "éé...");
+    }
+
+    #[test]
+    fn test_tab_label_marks_draft() {
+        let label = tab_label(&create_tracked_mr(true, None), false);
+        assert_eq!(label, "⟳ ✎ MR #10: Test MR");
+    }
+
+    #[test]
+    fn test_tab_label_marks_blocked_merge() {
+        let label = tab_label(&create_tracked_mr(false, Some("conflict")), false);
+        assert!(label.contains("⚠"));
+        assert!(!label.contains("✎"));
+    }
+
+    #[test]
+    fn test_tab_label_plain_mr() {
+        let label = tab_label(&create_tracked_mr(false, Some("mergeable")), false);
+        assert_eq!(label, "⟳ MR #10: Test MR");
+    }
+
+    #[test]
+    fn test_tab_label_marks_failed_request() {
+        let mut tracked_mr = create_tracked_mr(false, None);
+        tracked_mr.loading = false;
+        tracked_mr.error = Some("boom".to_string());
+        assert_eq!(tab_label(&tracked_mr, false), "• ! MR #10: Test MR");
+    }
+
+    #[test]
+    fn test_tab_label_shows_project_in_group_scope() {
+        let label = tab_label(&create_tracked_mr(false, None), true);
+        assert_eq!(label, "⟳ test!10: Test MR");
+    }
 }
@@ -0,0 +1,33 @@
+use crate::app::App;
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::help::centered_rect;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Same placement as the log viewer, whose scroll state the comment shares
+    let note_area = centered_rect(90, 90, area);
+    f.render_widget(Clear, note_area);
+
+    let lines = &app.log_processed_lines;
+    let content_height = note_area.height.saturating_sub(2) as usize; // Account for borders
+    let total_lines = lines.len();
+    let max_offset = total_lines.saturating_sub(content_height);
+    let scroll_offset = app.log_scroll_offset.min(max_offset);
+
+    let scroll_indicator = if total_lines > content_height {
+        format!(" [{}/{}]", scroll_offset + 1, max_offset + 1)
+    } else {
+        String::new()
+    };
+    let title = format!("Comment (q/Esc close, j/k scroll){}", scroll_indicator);
+
+    // Long lines wrap, so the lines from the offset on may take more rows than shown
+    let paragraph = Paragraph::new(lines[scroll_offset..].to_vec())
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, note_area);
+}
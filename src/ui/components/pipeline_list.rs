@@ -1,30 +1,89 @@
-use crate::app::App;
-use crate::gitlab::PipelineStatus;
-use chrono::Utc;
+use crate::app::{App, ProjectPipelines};
+use crate::gitlab::Pipeline;
+use crate::util::format_timestamp;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
 
-fn format_relative_time(dt: &chrono::DateTime<Utc>) -> String {
-    let now = Utc::now();
-    let duration = now.signed_duration_since(*dt);
+/// One pipeline's row; `show_ref` names its branch or tag, for lists spanning several refs
+fn pipeline_item(app: &App, pipeline: &Pipeline, show_ref: bool) -> ListItem<'static> {
+    let status_color = app.theme.pipeline_status(&pipeline.status);
 
-    if duration.num_seconds() < 60 {
-        "just now".to_string()
-    } else if duration.num_minutes() < 60 {
-        format!("{} min ago", duration.num_minutes())
-    } else if duration.num_hours() < 24 {
-        format!("{} hours ago", duration.num_hours())
-    } else {
-        format!("{} days ago", duration.num_days())
+    let mut spans = vec![
+        Span::styled(
+            format!("{} ", pipeline.status.symbol()),
+            Style::default().fg(status_color),
+        ),
+        Span::raw(format!("Pipeline #{} ", pipeline.iid)),
+        Span::styled(
+            format!("({})", pipeline.status.label()),
+            Style::default().fg(status_color),
+        ),
+    ];
+    if show_ref {
+        spans.push(Span::raw(format!(" on {}", pipeline.ref_name)));
+    }
+    spans.push(Span::raw(" - "));
+    spans.push(Span::styled(
+        format_timestamp(pipeline.created_at, app.relative_timestamps),
+        Style::default().fg(app.theme.dim),
+    ));
+
+    ListItem::new(Line::from(spans))
+}
+
+fn render_list(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    items: Vec<ListItem>,
+    title: String,
+    selected: usize,
+) {
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(app.theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    let mut state = ListState::default();
+    state.select(Some(selected));
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// The project's own pipelines, newest first, each naming its ref
+fn render_project_pipelines(f: &mut Frame, app: &App, view: &ProjectPipelines, area: Rect) {
+    if view.pipelines.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Pipelines")
+            .style(Style::default().fg(app.theme.muted));
+        f.render_widget(block, area);
+        return;
     }
+
+    let items = view
+        .pipelines
+        .iter()
+        .map(|pipeline| pipeline_item(app, pipeline, true))
+        .collect();
+    render_list(f, app, area, items, "Pipelines".to_string(), view.selected_pipeline_index);
 }
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(view) = &app.project_pipelines {
+        render_project_pipelines(f, app, view, area);
+        return;
+    }
+
     let selected_mr = match app.get_selected_mr() {
         Some(mr) => mr,
         None => {
@@ -40,55 +99,29 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
             .title("Pipelines")
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(app.theme.muted));
         f.render_widget(block, area);
         return;
     }
 
-    let items: Vec<ListItem> = selected_mr
+    let items = selected_mr
         .pipelines
         .iter()
-        .map(|pipeline| {
-            let status_color = match pipeline.status {
-                PipelineStatus::Success => Color::Green,
-                PipelineStatus::Failed => Color::Red,
-                PipelineStatus::Running => Color::Yellow,
-                PipelineStatus::Canceled => Color::DarkGray,
-                _ => Color::Gray,
-            };
-
-            let line = Line::from(vec![
-                Span::styled(
-                    format!("{} ", pipeline.status.symbol()),
-                    Style::default().fg(status_color),
-                ),
-                Span::raw(format!("Pipeline #{} ", pipeline.iid)),
-                Span::styled(
-                    format!("({:?})", pipeline.status).to_lowercase(),
-                    Style::default().fg(status_color),
-                ),
-                Span::raw(" - "),
-                Span::styled(
-                    format_relative_time(&pipeline.created_at),
-                    Style::default().fg(Color::DarkGray),
-                ),
-            ]);
-
-            ListItem::new(line)
-        })
+        .map(|pipeline| pipeline_item(app, pipeline, false))
         .collect();
 
-    let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Pipelines"))
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
+    // Show where the MR comes from and goes to
+    let mut title = if selected_mr.mr.source_branch.is_empty() {
+        "Pipelines".to_string()
+    } else {
+        format!(
+            "Pipelines ({} → {})",
+            selected_mr.mr.source_branch, selected_mr.mr.target_branch
         )
-        .highlight_symbol("> ");
-
-    let mut state = ListState::default();
-    state.select(Some(selected_mr.selected_pipeline_index));
+    };
+    if let Some(approvals) = &selected_mr.approvals {
+        title.push_str(&format!(" • {}", approvals.summary()));
+    }
 
-    f.render_stateful_widget(list, area, &mut state);
+    render_list(f, app, area, items, title, selected_mr.selected_pipeline_index);
 }
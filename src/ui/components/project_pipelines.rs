@@ -0,0 +1,58 @@
+use crate::app::{App, ProjectPipelines};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Which pipelines the view lists, e.g. `main` or `all refs`
+fn scope_label(view: &ProjectPipelines) -> String {
+    match &view.ref_name {
+        Some(ref_name) => ref_name.clone(),
+        None => "all refs".to_string(),
+    }
+}
+
+/// Header shown in place of the MR tabs while the project pipelines view is open
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(view) = &app.project_pipelines else {
+        return;
+    };
+
+    let summary = if view.loading && view.pipelines.is_empty() {
+        "Loading...".to_string()
+    } else {
+        format!("{} pipelines", view.pipelines.len())
+    };
+    let line = Line::from(vec![
+        Span::styled(
+            format!("Project #{} ", app.project_id),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!("— {} on {}", summary, scope_label(view))),
+        Span::styled(
+            "   b: toggle ref • g: back to MRs",
+            Style::default().fg(app.theme.dim),
+        ),
+    ]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Project Pipelines");
+    f.render_widget(Paragraph::new(line).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_label() {
+        let mut view = ProjectPipelines::default();
+        assert_eq!(scope_label(&view), "all refs");
+        view.ref_name = Some("main".to_string());
+        assert_eq!(scope_label(&view), "main");
+    }
+}
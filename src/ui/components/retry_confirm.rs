@@ -0,0 +1,62 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::help::centered_rect;
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let popup_area = centered_rect(60, 50, area);
+    f.render_widget(Clear, popup_area);
+
+    let pipeline_label = app
+        .get_selected_pipeline()
+        .map(|p| format!("pipeline #{}", p.iid))
+        .unwrap_or_else(|| "pipeline".to_string());
+
+    let jobs = app.get_retry_preview();
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            format!("Retry {}? These jobs will rerun:", pipeline_label),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for job in &jobs {
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!("{} ", job.status.symbol()),
+                Style::default().fg(Color::Red),
+            ),
+            Span::styled(format!("{:<12}", job.stage), Style::default().fg(Color::DarkGray)),
+            Span::raw(job.name.clone()),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("y/Enter", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::raw(" retry   "),
+        Span::styled("n/Esc", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::raw(" cancel"),
+    ]));
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .title(format!(" Retry {} job(s) ", jobs.len()))
+                .title_alignment(Alignment::Center),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,161 @@
+use crate::app::App;
+use crate::gitlab::{Job, JobStatus};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Job counts of one pipeline stage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageSummary {
+    pub stage: String,
+    pub total: usize,
+    pub success: usize,
+    pub failed: usize,
+    pub running: usize,
+    pub pending: usize, // Created or pending, i.e. not started yet
+}
+
+impl StageSummary {
+    fn finished(&self) -> usize {
+        self.total - self.running - self.pending
+    }
+
+    /// Overall status of the stage: any failure wins, then anything in progress
+    pub fn status(&self) -> JobStatus {
+        if self.failed > 0 {
+            JobStatus::Failed
+        } else if self.running > 0 || (self.pending > 0 && self.finished() > 0) {
+            JobStatus::Running
+        } else if self.pending > 0 {
+            JobStatus::Pending
+        } else if self.success > 0 {
+            JobStatus::Success
+        } else {
+            JobStatus::Skipped
+        }
+    }
+
+    /// e.g. `build ✓3`, `test ⟳1/4` (finished/total), `lint ✗1/2` or `deploy ○2`
+    pub fn label(&self) -> String {
+        let status = self.status();
+        let count = match status {
+            JobStatus::Failed => format!("{}/{}", self.failed, self.total),
+            JobStatus::Running => format!("{}/{}", self.finished(), self.total),
+            _ => self.total.to_string(),
+        };
+        format!("{} {}{}", self.stage, status.symbol(), count)
+    }
+}
+
+/// Group jobs by stage, keeping stages in the order they first appear
+pub fn summarize_stages(jobs: &[Job]) -> Vec<StageSummary> {
+    let mut stages: Vec<StageSummary> = Vec::new();
+
+    for job in jobs {
+        let index = match stages.iter().position(|s| s.stage == job.stage) {
+            Some(index) => index,
+            None => {
+                stages.push(StageSummary {
+                    stage: job.stage.clone(),
+                    total: 0,
+                    success: 0,
+                    failed: 0,
+                    running: 0,
+                    pending: 0,
+                });
+                stages.len() - 1
+            }
+        };
+
+        let summary = &mut stages[index];
+        summary.total += 1;
+        match job.status {
+            JobStatus::Success => summary.success += 1,
+            JobStatus::Failed => summary.failed += 1,
+            JobStatus::Running => summary.running += 1,
+            JobStatus::Created | JobStatus::Pending => summary.pending += 1,
+            _ => {}
+        }
+    }
+
+    stages
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let jobs = match app.get_selected_jobs() {
+        Some(jobs) => jobs,
+        None => return,
+    };
+
+    let mut spans = vec![Span::raw(" ")];
+    for summary in summarize_stages(jobs) {
+        spans.push(Span::styled(
+            summary.label(),
+            Style::default().fg(app.theme.job_status(&summary.status())),
+        ));
+        spans.push(Span::raw("  "));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(stage: &str, status: &str) -> Job {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": format!("{}-job", stage),
+            "status": status,
+            "stage": stage,
+            "created_at": "2024-01-01T10:00:00Z",
+            "started_at": null,
+            "finished_at": null,
+            "duration": null,
+            "web_url": "https://gitlab.com/test/-/jobs/1"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_summarize_stages_mixed_statuses() {
+        let jobs = vec![
+            job("build", "success"),
+            job("test", "success"),
+            job("build", "success"),
+            job("test", "running"),
+            job("test", "pending"),
+            job("test", "created"),
+            job("build", "success"),
+            job("deploy", "created"),
+            job("deploy", "created"),
+        ];
+
+        let stages = summarize_stages(&jobs);
+        let labels: Vec<String> = stages.iter().map(StageSummary::label).collect();
+        assert_eq!(labels, vec!["build ✓3", "test ⟳1/4", "deploy ○2"]);
+        assert_eq!(stages[1].running, 1);
+        assert_eq!(stages[1].pending, 2);
+    }
+
+    #[test]
+    fn test_stage_status_aggregation() {
+        let failed = summarize_stages(&[job("test", "failed"), job("test", "running")]);
+        assert_eq!(failed[0].status(), JobStatus::Failed);
+        assert_eq!(failed[0].label(), "test ✗1/2");
+
+        let waiting = summarize_stages(&[job("deploy", "created"), job("deploy", "pending")]);
+        assert_eq!(waiting[0].status(), JobStatus::Pending);
+        assert_eq!(waiting[0].label(), "deploy ○2");
+
+        let skipped = summarize_stages(&[job("cleanup", "skipped")]);
+        assert_eq!(skipped[0].status(), JobStatus::Skipped);
+
+        assert!(summarize_stages(&[]).is_empty());
+    }
+}
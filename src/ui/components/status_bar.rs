@@ -0,0 +1,192 @@
+use crate::app::App;
+use crate::gitlab::MrStateFilter;
+use crate::ui::text::text_width;
+use crate::util::format_relative_time;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Spans for the status bar: a PAUSED badge when auto-refresh is frozen, the MR state
+/// filter unless it is the default (open MRs), then the latest error (in red), the
+/// selected MR's failed request, or the status message
+fn status_spans(app: &App) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+
+    if app.auto_refresh_paused {
+        spans.push(Span::styled(
+            " PAUSED ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(app.theme.running)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    if app.mr_state != MrStateFilter::Opened {
+        spans.push(Span::styled(
+            format!(" {} ", app.mr_state.as_param().to_uppercase()),
+            Style::default()
+                .fg(Color::Black)
+                .bg(app.theme.manual)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    if let Some(error) = &app.error_message {
+        spans.push(Span::styled(
+            format!("Error: {}", error),
+            Style::default().fg(app.theme.failed),
+        ));
+    } else if let Some((iid, error)) = app
+        .get_selected_mr()
+        .and_then(|mr| mr.error.as_ref().map(|error| (mr.mr.iid, error)))
+    {
+        spans.push(Span::styled(
+            format!("Error (MR #{}): {}", iid, error),
+            Style::default().fg(app.theme.failed),
+        ));
+    } else if let Some(status) = &app.status_message {
+        spans.push(Span::styled(
+            status.as_str(),
+            Style::default().fg(app.theme.muted),
+        ));
+    }
+
+    spans
+}
+
+/// e.g. `next refresh in 0:42`, or `refresh paused at 0:42` while it is frozen
+fn refresh_countdown(app: &App) -> String {
+    let seconds = app.time_until_auto_refresh().as_secs();
+    let label = if app.auto_refresh_paused {
+        "refresh paused at"
+    } else {
+        "next refresh in"
+    };
+    format!("{} {}:{:02} ", label, seconds / 60, seconds % 60)
+}
+
+/// Right-hand side of the status bar: how fresh the data is and when it next refreshes,
+/// e.g. `updated 2m ago · next refresh in 0:42 `
+fn refresh_info(app: &App) -> String {
+    let updated = app
+        .last_refresh
+        .map(|at| format!("updated {}", format_relative_time(at)));
+    let countdown = refresh_countdown(app);
+    match updated {
+        Some(updated) => format!("{} · {}", updated, countdown),
+        None => countdown,
+    }
+}
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let info = refresh_info(app);
+    let [status_area, info_area] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(text_width(&info) as u16)]).areas(area);
+
+    f.render_widget(Paragraph::new(Line::from(status_spans(app))), status_area);
+    f.render_widget(
+        Paragraph::new(Span::styled(info, Style::default().fg(app.theme.muted))),
+        info_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paused_badge_shown_only_when_paused() {
+        let mut app = App::new(123, None, false, 1);
+        app.status_message = None;
+        assert!(status_spans(&app).is_empty());
+
+        app.auto_refresh_paused = true;
+        let spans = status_spans(&app);
+        assert_eq!(spans[0].content, " PAUSED ");
+    }
+
+    #[test]
+    fn test_mr_state_badge_shown_unless_opened() {
+        let mut app = App::new(123, None, false, 1);
+        app.status_message = None;
+        assert!(status_spans(&app).is_empty());
+
+        app.mr_state = MrStateFilter::Merged;
+        assert_eq!(status_spans(&app)[0].content, " MERGED ");
+
+        app.auto_refresh_paused = true;
+        app.mr_state = MrStateFilter::All;
+        let spans = status_spans(&app);
+        assert_eq!(spans[0].content, " PAUSED ");
+        assert_eq!(spans[2].content, " ALL ");
+    }
+
+    #[test]
+    fn test_refresh_countdown() {
+        let mut app = App::new(123, None, false, 2);
+        app.last_auto_refresh = std::time::Instant::now() - std::time::Duration::from_secs(30);
+        let countdown = refresh_countdown(&app);
+        assert!(countdown == "next refresh in 1:30 " || countdown == "next refresh in 1:29 ");
+
+        app.last_auto_refresh = std::time::Instant::now() - std::time::Duration::from_secs(600);
+        assert_eq!(refresh_countdown(&app), "next refresh in 0:00 ");
+
+        app.last_auto_refresh = std::time::Instant::now() - std::time::Duration::from_secs(30);
+        app.auto_refresh_paused_at = app.last_auto_refresh + std::time::Duration::from_secs(15);
+        app.auto_refresh_paused = true;
+        assert_eq!(refresh_countdown(&app), "refresh paused at 1:45 ");
+    }
+
+    #[test]
+    fn test_refresh_info_shows_last_update() {
+        let mut app = App::new(123, None, false, 1);
+        app.auto_refresh_paused = true;
+        app.auto_refresh_paused_at = app.last_auto_refresh;
+        assert_eq!(refresh_info(&app), "refresh paused at 1:00 ");
+
+        app.last_refresh = Some(chrono::Utc::now() - chrono::Duration::minutes(2));
+        assert_eq!(refresh_info(&app), "updated 2m ago · refresh paused at 1:00 ");
+
+        app.auto_refresh_paused = false;
+        assert!(refresh_info(&app).starts_with("updated 2m ago · next refresh in "));
+    }
+
+    #[test]
+    fn test_error_takes_precedence_over_status() {
+        let mut app = App::new(123, None, false, 1);
+        app.status_message = Some("Refreshing...".to_string());
+        app.error_message = Some("boom".to_string());
+
+        let spans = status_spans(&app);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].content, "Error: boom");
+    }
+
+    #[test]
+    fn test_selected_mr_error_shown() {
+        let mr: crate::gitlab::MergeRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 10,
+            "title": "Test MR",
+            "author": {"id": 1, "username": "testuser", "name": "Test User"},
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/test/-/merge_requests/10",
+            "created_at": "2024-01-01T10:00:00Z",
+            "updated_at": "2024-01-01T11:00:00Z"
+        }))
+        .unwrap();
+        let mut app = App::new(123, None, false, 1);
+        app.tracked_mrs.push(crate::app::TrackedMergeRequest::new(mr));
+        app.tracked_mrs[0].error = Some("pipelines failed".to_string());
+
+        let spans = status_spans(&app);
+        assert_eq!(spans[0].content, "Error (MR #10): pipelines failed");
+    }
+}
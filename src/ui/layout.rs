@@ -1,38 +1,162 @@
 use crate::app::{App, AppMode};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Position, Rect},
     Frame,
 };
 
 use super::components;
 
-pub fn render(f: &mut Frame, app: &App) {
+/// Rows of the jobs table as last rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobRowsArea {
+    pub area: Rect,    // Where the job rows are drawn (below the header)
+    pub offset: usize, // Visible job shown on the first row
+    pub count: usize,  // Visible jobs in total
+}
+
+/// Clickable regions of the last frame, kept on `App` for hit-testing mouse clicks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClickAreas {
+    pub mr_tabs: Vec<(Rect, usize)>, // Tab rect and the `tracked_mrs` index it shows
+    pub job_rows: Option<JobRowsArea>, // Only while the jobs table is on screen
+}
+
+/// What a click landed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickTarget {
+    MrTab(usize),  // Index into `tracked_mrs`
+    JobRow(usize), // Index into the visible jobs
+}
+
+impl ClickAreas {
+    pub fn hit_test(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        let point = Position::new(column, row);
+        if let Some(&(_, mr_index)) = self.mr_tabs.iter().find(|(rect, _)| rect.contains(point)) {
+            return Some(ClickTarget::MrTab(mr_index));
+        }
+
+        let rows = self.job_rows?;
+        if !rows.area.contains(point) {
+            return None;
+        }
+        let index = rows.offset + (row - rows.area.y) as usize;
+        (index < rows.count).then_some(ClickTarget::JobRow(index))
+    }
+}
+
+pub fn render(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // MR Tabs
             Constraint::Length(10), // Pipeline list
             Constraint::Min(10),    // Jobs table
+            Constraint::Length(1),  // Status bar
         ])
         .split(f.area());
 
-    components::mr_tabs::render(f, app, chunks[0]);
+    let mr_tabs = if app.project_pipelines.is_some() {
+        components::project_pipelines::render(f, app, chunks[0]);
+        Vec::new()
+    } else {
+        components::mr_tabs::render(f, app, chunks[0])
+    };
     components::pipeline_list::render(f, app, chunks[1]);
 
-    // Toggle between jobs and comments view
-    if app.is_viewing_comments() {
+    // Toggle between jobs, comments and description view
+    let job_rows = if app.is_viewing_comments() {
         components::comments_list::render(f, app, chunks[2]);
+        None
+    } else if app.is_viewing_description() {
+        components::description_view::render(f, app, chunks[2]);
+        None
+    } else if app.get_selected_jobs().is_some_and(|jobs| !jobs.is_empty()) {
+        // One-line per-stage progress above the jobs table
+        let job_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(chunks[2]);
+        components::stage_progress::render(f, app, job_chunks[0]);
+        components::job_list::render(f, app, job_chunks[1])
     } else {
-        components::job_list::render(f, app, chunks[2]);
-    }
+        components::job_list::render(f, app, chunks[2])
+    };
+    app.click_areas = ClickAreas { mr_tabs, job_rows };
+
+    components::status_bar::render(f, app, chunks[3]);
 
     // Render help popup on top if in help mode
     if app.mode == AppMode::ShowingHelp {
         components::help::render(f, f.area());
     }
 
+    // Render retry confirmation on top of the jobs it would rerun
+    if app.mode == AppMode::ConfirmingRetry {
+        components::retry_confirm::render(f, app, f.area());
+    }
+
     // Render log viewer on top if in log viewing mode
     if app.mode == AppMode::ViewingLog {
         components::log_viewer::render(f, app, f.area());
     }
+
+    // Render the MR's changes on top in diff viewing mode
+    if app.mode == AppMode::ViewingDiff {
+        components::diff_view::render(f, app, f.area());
+    }
+
+    // Render the failed jobs' log tails on top in failure summary mode
+    if app.mode == AppMode::ViewingFailureSummary {
+        components::failure_summary::render(f, app, f.area());
+    }
+
+    // Render the selected comment full-screen over the comments list
+    if app.mode == AppMode::ViewingNote {
+        components::note_view::render(f, app, f.area());
+    }
+
+    // Render the global log search on top of the dashboard
+    if app.mode == AppMode::GlobalSearch {
+        components::global_search::render(f, app, f.area());
+    }
+
+    // Render quit confirmation while fetches are still in flight
+    if app.mode == AppMode::ConfirmingQuit {
+        components::quit_confirm::render(f, app, f.area());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn click_areas() -> ClickAreas {
+        ClickAreas {
+            mr_tabs: vec![(Rect::new(1, 1, 20, 1), 2), (Rect::new(22, 1, 15, 1), 0)],
+            job_rows: Some(JobRowsArea { area: Rect::new(1, 16, 78, 10), offset: 3, count: 8 }),
+        }
+    }
+
+    #[test]
+    fn test_hit_test_mr_tabs() {
+        let areas = click_areas();
+        assert_eq!(areas.hit_test(1, 1), Some(ClickTarget::MrTab(2)));
+        assert_eq!(areas.hit_test(20, 1), Some(ClickTarget::MrTab(2)));
+        assert_eq!(areas.hit_test(21, 1), None); // The divider between tabs
+        assert_eq!(areas.hit_test(30, 1), Some(ClickTarget::MrTab(0)));
+        assert_eq!(areas.hit_test(30, 0), None); // The tabs' border
+    }
+
+    #[test]
+    fn test_hit_test_job_rows_account_for_scroll() {
+        let areas = click_areas();
+        // First row shows visible job 3 (scrolled), each row below the next job
+        assert_eq!(areas.hit_test(5, 16), Some(ClickTarget::JobRow(3)));
+        assert_eq!(areas.hit_test(5, 20), Some(ClickTarget::JobRow(7)));
+        // Rows past the last job are empty
+        assert_eq!(areas.hit_test(5, 21), None);
+        assert_eq!(areas.hit_test(5, 15), None);
+
+        assert_eq!(ClickAreas::default().hit_test(5, 16), None);
+    }
 }
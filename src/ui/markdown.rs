@@ -0,0 +1,262 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// One line of GitLab markdown split into its block marker and styled content
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownLine {
+    /// Shown before the first wrapped piece, e.g. `• ` for a list item or `│ ` per quote level
+    pub prefix: String,
+    /// Shown before every further wrapped piece, as wide as `prefix`
+    pub continuation: String,
+    pub spans: Vec<Span<'static>>,
+}
+
+/// Inline code and the lines of fenced code blocks
+pub fn code_style(base: Style) -> Style {
+    base.fg(Color::Green)
+}
+
+/// Whether a line opens or closes a fenced code block
+pub fn is_code_fence(line: &str) -> bool {
+    line.trim_start().starts_with("```")
+}
+
+/// Parse the block-level markup of a line (headers, list bullets, blockquotes) and
+/// style the rest with `inline_spans`
+pub fn markdown_line(line: &str, base: Style) -> MarkdownLine {
+    let mut rest = line;
+    let mut quote_depth = 0;
+    while let Some(quoted) = rest.trim_start().strip_prefix('>') {
+        quote_depth += 1;
+        rest = quoted.strip_prefix(' ').unwrap_or(quoted);
+    }
+    let quote = "│ ".repeat(quote_depth);
+    let base = if quote_depth > 0 { base.fg(Color::Gray) } else { base };
+
+    let trimmed = rest.trim_start();
+    let hashes = trimmed.len() - trimmed.trim_start_matches('#').len();
+    if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+        return MarkdownLine {
+            prefix: quote.clone(),
+            continuation: quote,
+            spans: inline_spans(
+                trimmed[hashes..].trim(),
+                base.fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+        };
+    }
+
+    if let Some(item) = ["- ", "* ", "+ "].iter().find_map(|bullet| trimmed.strip_prefix(bullet)) {
+        let indent = " ".repeat(rest.len() - trimmed.len());
+        return MarkdownLine {
+            prefix: format!("{}{}• ", quote, indent),
+            continuation: format!("{}{}  ", quote, indent),
+            spans: inline_spans(item, base),
+        };
+    }
+
+    MarkdownLine {
+        prefix: quote.clone(),
+        continuation: quote,
+        spans: inline_spans(rest, base),
+    }
+}
+
+/// Style `**bold**`/`__bold__`, `*italic*`/`_italic_` and `` `code` `` runs. Markers
+/// without a matching close, and `_` inside words like `snake_case`, stay literal text.
+pub fn inline_spans(text: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut prev = None;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some((styled, after)) = styled_run(rest, base, prev) {
+            if !plain.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut plain), base));
+            }
+            spans.extend(styled);
+            // The closing marker is the last character consumed
+            prev = rest[..rest.len() - after.len()].chars().last();
+            rest = after;
+            continue;
+        }
+        plain.push(c);
+        prev = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, base));
+    }
+    spans
+}
+
+/// The styled run starting at the beginning of `text` and what follows it, if `text`
+/// opens one that is closed later on the line
+fn styled_run(
+    text: &str,
+    base: Style,
+    prev: Option<char>,
+) -> Option<(Vec<Span<'static>>, &str)> {
+    if let Some(body) = text.strip_prefix('`') {
+        let end = body.find('`').filter(|&end| end > 0)?;
+        return Some((
+            vec![Span::styled(body[..end].to_string(), code_style(base))],
+            &body[end + 1..],
+        ));
+    }
+
+    for marker in ["**", "__"] {
+        if let Some(body) = text.strip_prefix(marker) {
+            let end = body.find(marker).filter(|&end| end > 0)?;
+            return Some((
+                inline_spans(&body[..end], base.add_modifier(Modifier::BOLD)),
+                &body[end + marker.len()..],
+            ));
+        }
+    }
+
+    for marker in ['*', '_'] {
+        if let Some(body) = text.strip_prefix(marker) {
+            let intraword = |c: Option<char>| marker == '_' && c.is_some_and(char::is_alphanumeric);
+            if intraword(prev) || body.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let end = body.find(marker).filter(|&end| end > 0)?;
+            let inner = &body[..end];
+            let after = &body[end + 1..];
+            if inner.ends_with(char::is_whitespace) || intraword(after.chars().next()) {
+                return None;
+            }
+            return Some((inline_spans(inner, base.add_modifier(Modifier::ITALIC)), after));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> Span<'static> {
+        Span::raw(text.to_string())
+    }
+
+    fn styled(text: &str, style: Style) -> Span<'static> {
+        Span::styled(text.to_string(), style)
+    }
+
+    fn bold() -> Style {
+        Style::default().add_modifier(Modifier::BOLD)
+    }
+
+    fn italic() -> Style {
+        Style::default().add_modifier(Modifier::ITALIC)
+    }
+
+    #[test]
+    fn test_inline_bold() {
+        assert_eq!(
+            inline_spans("a **big** and __bold__ word", Style::default()),
+            vec![
+                plain("a "),
+                styled("big", bold()),
+                plain(" and "),
+                styled("bold", bold()),
+                plain(" word"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_italic() {
+        assert_eq!(
+            inline_spans("*one* and _two_", Style::default()),
+            vec![styled("one", italic()), plain(" and "), styled("two", italic())]
+        );
+    }
+
+    #[test]
+    fn test_inline_code_keeps_markers_inside() {
+        assert_eq!(
+            inline_spans("run `cargo **test**` now", Style::default()),
+            vec![
+                plain("run "),
+                styled("cargo **test**", Style::default().fg(Color::Green)),
+                plain(" now"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_nested_code_in_bold() {
+        assert_eq!(
+            inline_spans("**see `x`**", Style::default()),
+            vec![styled("see ", bold()), styled("x", bold().fg(Color::Green))]
+        );
+    }
+
+    #[test]
+    fn test_inline_malformed_markers_stay_literal() {
+        for text in ["**unclosed", "a * b * c", "snake_case_name", "``", "`", "*", "__", "2 * 3"] {
+            let spans = inline_spans(text, Style::default());
+            assert_eq!(spans, vec![plain(text)], "{}", text);
+        }
+        // Multi-byte text around markers doesn't split a character
+        assert_eq!(
+            inline_spans("日本 *語*", Style::default()),
+            vec![plain("日本 "), styled("語", italic())]
+        );
+    }
+
+    #[test]
+    fn test_header() {
+        let line = markdown_line("## Review notes", Style::default());
+        assert_eq!(line.prefix, "");
+        assert_eq!(
+            line.spans,
+            vec![styled("Review notes", bold().fg(Color::Yellow))]
+        );
+        // A hash without a space is not a header, e.g. an issue reference
+        assert_eq!(markdown_line("#123", Style::default()).spans, vec![plain("#123")]);
+    }
+
+    #[test]
+    fn test_list_bullets() {
+        let line = markdown_line("- fix **this**", Style::default());
+        assert_eq!(line.prefix, "• ");
+        assert_eq!(line.continuation, "  ");
+        assert_eq!(line.spans, vec![plain("fix "), styled("this", bold())]);
+
+        let nested = markdown_line("  * nested", Style::default());
+        assert_eq!(nested.prefix, "  • ");
+        assert_eq!(nested.continuation, "    ");
+        assert_eq!(nested.spans, vec![plain("nested")]);
+    }
+
+    #[test]
+    fn test_blockquote_indentation() {
+        let gray = Style::default().fg(Color::Gray);
+        let line = markdown_line("> quoted reply", Style::default());
+        assert_eq!(line.prefix, "│ ");
+        assert_eq!(line.continuation, "│ ");
+        assert_eq!(line.spans, vec![styled("quoted reply", gray)]);
+
+        let nested = markdown_line(">> - deeper", Style::default());
+        assert_eq!(nested.prefix, "│ │ • ");
+        assert_eq!(nested.spans, vec![styled("deeper", gray)]);
+
+        assert!(markdown_line(">", Style::default()).spans.is_empty());
+    }
+
+    #[test]
+    fn test_code_fence() {
+        assert!(is_code_fence("```rust"));
+        assert!(is_code_fence("  ```"));
+        assert!(!is_code_fence("`inline`"));
+    }
+}
@@ -1,4 +1,7 @@
 pub mod components;
 pub mod layout;
+pub mod markdown;
+pub mod text;
+pub mod theme;
 
 pub use layout::render;
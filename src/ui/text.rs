@@ -0,0 +1,110 @@
+use ratatui::text::Span;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Terminal columns `s` takes up: CJK and most emoji are two columns wide,
+/// combining marks zero
+pub fn text_width(s: &str) -> usize {
+    s.width()
+}
+
+/// Columns of a single character; control characters count as zero
+pub fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Split a line into pieces of at most `width` columns. A double-width glyph that
+/// doesn't fit at the end of a piece starts the next one instead of straddling it.
+pub fn wrap_to_width(line: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for c in line.chars() {
+        let w = char_width(c);
+        if current_width + w > width && !current.is_empty() {
+            pieces.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        current.push(c);
+        current_width += w;
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// `wrap_to_width` for styled text: split spans into lines of at most `width` columns,
+/// keeping each piece's style
+pub fn wrap_spans(spans: &[Span<'static>], width: usize) -> Vec<Vec<Span<'static>>> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current: Vec<Span<'static>> = Vec::new();
+    let mut current_width = 0;
+
+    for span in spans {
+        let mut piece = String::new();
+        for c in span.content.chars() {
+            let w = char_width(c);
+            if current_width + w > width && current_width > 0 {
+                if !piece.is_empty() {
+                    current.push(Span::styled(std::mem::take(&mut piece), span.style));
+                }
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            piece.push(c);
+            current_width += w;
+        }
+        if !piece.is_empty() {
+            current.push(Span::styled(piece, span.style));
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_width_ascii_vs_cjk() {
+        assert_eq!(text_width("hello"), 5);
+        assert_eq!(text_width("日本語"), 6);
+        assert_eq!(text_width("MR 修正"), 7);
+        assert_eq!(text_width("é"), 1);
+        assert_eq!("日本語".len(), 9); // Bytes are no measure of width
+    }
+
+    #[test]
+    fn test_wrap_respects_double_width_glyphs() {
+        assert_eq!(wrap_to_width("abcdef", 4), vec!["abcd", "ef"]);
+        // Each glyph is two columns: two fit in five, the third wraps
+        assert_eq!(wrap_to_width("日本語の", 5), vec!["日本", "語の"]);
+        // A wide glyph that would straddle the boundary moves to the next piece
+        assert_eq!(wrap_to_width("abc日d", 4), vec!["abc", "日d"]);
+        assert!(wrap_to_width("", 10).is_empty());
+        assert!(wrap_to_width("日本語", 5).iter().all(|piece| text_width(piece) <= 5));
+    }
+
+    #[test]
+    fn test_wrap_spans_keeps_styles_across_breaks() {
+        use ratatui::style::{Modifier, Style};
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let spans = vec![Span::raw("ab"), Span::styled("cdef", bold), Span::raw("g")];
+
+        let lines = wrap_spans(&spans, 4);
+        assert_eq!(
+            lines,
+            vec![
+                vec![Span::raw("ab"), Span::styled("cd", bold)],
+                vec![Span::styled("ef", bold), Span::raw("g")],
+            ]
+        );
+        assert!(wrap_spans(&[], 4).is_empty());
+    }
+}
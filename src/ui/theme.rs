@@ -0,0 +1,143 @@
+use crate::gitlab::{JobStatus, PipelineStatus};
+use ratatui::style::Color;
+
+/// Named color roles used by the UI components, selected by `ui.theme`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub success: Color,
+    pub failed: Color,
+    pub running: Color,
+    pub pending: Color,
+    pub waiting: Color,   // Pipelines waiting for a resource group
+    pub preparing: Color, // Pipelines whose runners are being set up
+    pub manual: Color,
+    pub canceled: Color,
+    pub header: Color,
+    pub highlight: Color,
+    pub dim: Color,
+    pub muted: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            success: Color::Green,
+            failed: Color::Red,
+            running: Color::Yellow,
+            pending: Color::Blue,
+            waiting: Color::Magenta,
+            preparing: Color::LightCyan,
+            manual: Color::LightBlue,
+            canceled: Color::LightRed,
+            header: Color::Cyan,
+            highlight: Color::DarkGray,
+            dim: Color::DarkGray,
+            muted: Color::Gray,
+        }
+    }
+
+    /// Darker foregrounds and a light selection background, so yellow/gray text
+    /// stays readable on a white terminal
+    pub fn light() -> Self {
+        Self {
+            success: Color::Rgb(0, 128, 0),
+            failed: Color::Rgb(175, 0, 0),
+            running: Color::Rgb(175, 95, 0),
+            pending: Color::Rgb(0, 0, 175),
+            waiting: Color::Rgb(135, 0, 135),
+            preparing: Color::Rgb(0, 135, 135),
+            manual: Color::Rgb(0, 95, 175),
+            canceled: Color::Rgb(175, 95, 95),
+            header: Color::Rgb(0, 95, 135),
+            highlight: Color::Rgb(208, 208, 208),
+            dim: Color::Rgb(128, 128, 128),
+            muted: Color::Rgb(88, 88, 88),
+        }
+    }
+
+    /// Look up a preset by its config name; `Settings::validate` rejects unknown names
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    pub fn pipeline_status(&self, status: &PipelineStatus) -> Color {
+        match status {
+            PipelineStatus::Success => self.success,
+            PipelineStatus::Failed => self.failed,
+            PipelineStatus::Running => self.running,
+            PipelineStatus::Pending => self.pending,
+            PipelineStatus::WaitingForResource => self.waiting,
+            PipelineStatus::Preparing => self.preparing,
+            PipelineStatus::Manual => self.manual,
+            PipelineStatus::Canceled => self.canceled,
+            PipelineStatus::Skipped => self.dim,
+            PipelineStatus::Created => self.muted,
+        }
+    }
+
+    pub fn job_status(&self, status: &JobStatus) -> Color {
+        match status {
+            JobStatus::Success => self.success,
+            JobStatus::Failed => self.failed,
+            JobStatus::Running => self.running,
+            JobStatus::Pending => self.pending,
+            JobStatus::Skipped => self.dim,
+            _ => self.muted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_theme_differs_from_dark() {
+        let dark = Theme::from_name("dark").unwrap();
+        let light = Theme::from_name("light").unwrap();
+
+        assert_eq!(dark, Theme::default());
+        assert_ne!(dark.success, light.success);
+        assert_ne!(dark.failed, light.failed);
+        assert_ne!(dark.running, light.running);
+        assert_ne!(dark.header, light.header);
+        assert_ne!(dark.highlight, light.highlight);
+        assert_ne!(dark.dim, light.dim);
+    }
+
+    #[test]
+    fn test_pipeline_statuses_have_distinct_colors() {
+        let statuses = [
+            PipelineStatus::Created,
+            PipelineStatus::WaitingForResource,
+            PipelineStatus::Preparing,
+            PipelineStatus::Pending,
+            PipelineStatus::Running,
+            PipelineStatus::Success,
+            PipelineStatus::Failed,
+            PipelineStatus::Canceled,
+            PipelineStatus::Skipped,
+            PipelineStatus::Manual,
+        ];
+        for theme in [Theme::dark(), Theme::light()] {
+            let colors: std::collections::HashSet<Color> =
+                statuses.iter().map(|status| theme.pipeline_status(status)).collect();
+            assert_eq!(colors.len(), statuses.len(), "{:?}", theme);
+        }
+    }
+
+    #[test]
+    fn test_unknown_theme_name() {
+        assert_eq!(Theme::from_name("solarized"), None);
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Local, Utc};
+
+/// Past this many days a relative time reads worse than the date itself
+const RELATIVE_TIME_MAX_DAYS: i64 = 30;
+
+/// How long ago `dt` was: `just now` under a minute, then `5m ago`, `3h ago`, `2d ago`,
+/// and the plain date (`2024-01-05`) from 30 days on
+pub fn format_relative_time(dt: DateTime<Utc>) -> String {
+    format_relative_time_at(dt, Utc::now())
+}
+
+/// `dt` in local time, e.g. `2024-01-15 10:30`
+pub fn format_absolute_time(dt: DateTime<Utc>) -> String {
+    dt.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+/// A timestamp in list rows: relative, or absolute when `ui.relative_timestamps` is off
+pub fn format_timestamp(dt: DateTime<Utc>, relative: bool) -> String {
+    if relative {
+        format_relative_time(dt)
+    } else {
+        format_absolute_time(dt)
+    }
+}
+
+fn format_relative_time_at(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let duration = now.signed_duration_since(dt);
+
+    if duration.num_minutes() < 1 {
+        "just now".to_string()
+    } else if duration.num_hours() < 1 {
+        format!("{}m ago", duration.num_minutes())
+    } else if duration.num_days() < 1 {
+        format!("{}h ago", duration.num_hours())
+    } else if duration.num_days() < RELATIVE_TIME_MAX_DAYS {
+        format!("{}d ago", duration.num_days())
+    } else {
+        dt.format("%Y-%m-%d").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_format_relative_time_buckets() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let cases = [
+            (Duration::seconds(-5), "just now"), // Clock skew: slightly in the future
+            (Duration::zero(), "just now"),
+            (Duration::seconds(59), "just now"),
+            (Duration::seconds(60), "1m ago"),
+            (Duration::minutes(59) + Duration::seconds(59), "59m ago"),
+            (Duration::minutes(60), "1h ago"),
+            (Duration::hours(23) + Duration::minutes(59), "23h ago"),
+            (Duration::hours(24), "1d ago"),
+            (Duration::days(29) + Duration::hours(23), "29d ago"),
+            (Duration::days(30), "2024-01-31"),
+            (Duration::days(400), "2023-01-26"),
+        ];
+
+        for (ago, expected) in cases {
+            assert_eq!(format_relative_time_at(now - ago, now), expected, "{} ago", ago);
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp_follows_setting() {
+        let dt = Utc::now() - Duration::minutes(5);
+        assert_eq!(format_timestamp(dt, true), "5m ago");
+
+        let absolute = format_timestamp(dt, false);
+        assert_eq!(absolute, dt.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string());
+        assert_eq!(absolute.len(), "2024-01-15 10:30".len());
+    }
+}
@@ -3,7 +3,6 @@
 #[cfg(test)]
 mod tests {
     use std::fs;
-    use tempfile::TempDir;
 
     #[test]
     fn test_example_config_is_valid() {